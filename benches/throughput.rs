@@ -0,0 +1,166 @@
+//! Parse and serialize throughput, in MB/s, on the corpora in `benches/assets` and on
+//! generated documents.
+//!
+//! `cargo bench` measures every corpus for about a second each (`SYNSON_BENCH_SECONDS`
+//! changes that); `cargo bench -- twitter` only runs the corpora whose name contains
+//! `twitter`. Without `--bench`, as under `cargo test --benches`, every corpus is parsed
+//! and serialized once as a smoke test. A corpus that has not been downloaded is listed as
+//! missing instead of measured; `make bench` fetches them first, from the commit and with
+//! the checksums recorded in `benches/assets`.
+
+use std::hint::black_box;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use std::{env, fs};
+
+use synson::{parse_json, serialize_json, JsonValue};
+
+/// The standard corpora from nativejson-benchmark, fetched by `make bench-data`.
+const CORPORA: [&str; 3] = ["twitter.json", "citm_catalog.json", "canada.json"];
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let measure = args.iter().any(|arg| arg == "--bench");
+    let filter = args.iter().find(|arg| !arg.starts_with('-'));
+    let budget = env::var("SYNSON_BENCH_SECONDS")
+        .ok()
+        .and_then(|seconds| seconds.parse().ok())
+        .map_or(Duration::from_secs(1), Duration::from_secs_f64);
+
+    let mut inputs = Vec::new();
+    let mut missing = Vec::new();
+    let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/assets");
+    for name in CORPORA {
+        match fs::read_to_string(assets.join(name)) {
+            Ok(text) => inputs.push((name.to_string(), text)),
+            Err(error) if error.kind() == ErrorKind::NotFound => missing.push(name),
+            Err(error) => panic!("benches/assets/{name}: {error}"),
+        }
+    }
+    let deep = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/assets/large_deep_structure.json");
+    inputs.push((
+        "large_deep_structure.json".to_string(),
+        fs::read_to_string(deep).expect("tests/assets/large_deep_structure.json"),
+    ));
+    inputs.push(("generated/numbers".to_string(), numbers()));
+    inputs.push(("generated/records".to_string(), records()));
+
+    if measure {
+        println!("scan backend: {}", synson::parser::scan::backend());
+        println!(
+            "{:<28} {:>10} {:>12} {:>16}",
+            "corpus", "size", "parse MB/s", "serialize MB/s"
+        );
+    }
+    for name in missing {
+        if filter.is_none_or(|filter| name.contains(filter.as_str())) {
+            println!("{name:<28} missing, run `make bench-data` to download it");
+        }
+    }
+    for (name, text) in &inputs {
+        if filter.is_some_and(|filter| !name.contains(filter.as_str())) {
+            continue;
+        }
+        let value = parse_json(text, None).unwrap_or_else(|error| panic!("{name}: {error:?}"));
+        let output = serialize_json(&value);
+        assert_eq!(parse_json(&output, None).as_ref(), Ok(&value), "{name}");
+        if !measure {
+            continue;
+        }
+
+        let parse = throughput(text.len(), budget, || parse_json(black_box(text), None));
+        let serialize = throughput(output.len(), budget, || serialize_json(black_box(&value)));
+        println!(
+            "{name:<28} {:>10} {parse:>12.1} {serialize:>16.1}",
+            size(text.len())
+        );
+    }
+}
+
+/// Runs `f` repeatedly for about `budget` and returns the MB/s it got through, counting
+/// `bytes` per run.
+fn throughput<T>(bytes: usize, budget: Duration, mut f: impl FnMut() -> T) -> f64 {
+    // One untimed run to warm the caches and the allocator.
+    black_box(f());
+    let start = Instant::now();
+    let mut runs = 0u32;
+    while runs == 0 || start.elapsed() < budget {
+        black_box(f());
+        runs += 1;
+    }
+    bytes as f64 * f64::from(runs) / start.elapsed().as_secs_f64() / 1_000_000.0
+}
+
+fn size(bytes: usize) -> String {
+    if bytes < 1_000 {
+        format!("{bytes} B")
+    } else if bytes < 1_000_000 {
+        format!("{:.1} kB", bytes as f64 / 1_000.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / 1_000_000.0)
+    }
+}
+
+/// A linear congruential generator, so the generated documents are the same on every run.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        self.0 >> 33
+    }
+
+    fn float(&mut self, scale: f64) -> f64 {
+        (self.next() as f64 / (1u64 << 31) as f64 - 0.5) * scale
+    }
+}
+
+/// About 1 MB of coordinate pairs, mostly long floating-point numbers, like canada.json.
+fn numbers() -> String {
+    let mut rng = Lcg(1);
+    let rings = (0..40)
+        .map(|_| {
+            (0..800)
+                .map(|_| JsonValue::Array(vec![rng.float(360.0).into(), rng.float(180.0).into()]))
+                .collect()
+        })
+        .collect();
+    serialize_json(&JsonValue::Array(rings))
+}
+
+/// About 1 MB of pretty-printed records with short strings, escapes, non-ASCII text,
+/// integers and booleans, like twitter.json and citm_catalog.json.
+fn records() -> String {
+    let mut rng = Lcg(2);
+    let words = [
+        "café",
+        "naïve",
+        "line\nbreak",
+        "tab\there",
+        "\"quoted\"",
+        "日本語",
+        "plain",
+    ];
+    let records: Vec<JsonValue> = (0..3_000)
+        .map(|id| {
+            let text: Vec<&str> = (0..8)
+                .map(|_| words[rng.next() as usize % words.len()])
+                .collect();
+            synson::json!({
+                "id": id,
+                "name": format!("user_{}", rng.next()),
+                "text": text.join(" "),
+                "active": rng.next() < 1 << 30,
+                "followers": rng.next() % 100_000,
+                "tags": ["a", "bb", "ccc"],
+                "location": null,
+                "entities": {"urls": [], "mentions": [{"id": rng.next(), "indices": [0, 12]}]}
+            })
+        })
+        .collect();
+    synson::serialize_json_pretty(&JsonValue::Array(records), 2)
+}
@@ -0,0 +1,35 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use synson::model::JsonParseOptions;
+use synson::{parse_json, JsonPushParser};
+
+// Options only ever relax the parser, and the push parser gives the same answer however
+// its input is split into chunks.
+fuzz_target!(|args: (&str, JsonParseOptions, u8)| {
+    let (input, options, chunk_size) = args;
+
+    if let Ok(strict) = parse_json(input, None) {
+        assert_eq!(parse_json(input, Some(&options)), Ok(strict));
+    }
+
+    let whole = push_parse(input.as_bytes(), input.len(), options);
+    assert_eq!(
+        push_parse(input.as_bytes(), usize::from(chunk_size), options),
+        whole
+    );
+});
+
+fn push_parse(
+    input: &[u8],
+    chunk_size: usize,
+    options: JsonParseOptions,
+) -> Result<Vec<synson::JsonEvent>, synson::JsonParseError> {
+    let mut parser = JsonPushParser::with_options(options);
+    let mut events = Vec::new();
+    for chunk in input.chunks(chunk_size.max(1)) {
+        events.extend(parser.feed(chunk)?);
+    }
+    events.extend(parser.finish()?);
+    Ok(events)
+}
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use synson::{parse_json, parse_json_at, parse_json_prefix, parse_many, parse_ndjson};
+
+// Every entry point that takes raw text must return an error rather than panic, including
+// when asked to start in the middle of a multi-byte character.
+fuzz_target!(|input: &str| {
+    let _ = parse_json(input, None);
+    let _ = parse_json_prefix(input);
+    for offset in 0..=input.len() + 1 {
+        let _ = parse_json_at(input, offset);
+    }
+    parse_many(input).take(input.len() + 1).for_each(drop);
+    parse_ndjson(input).for_each(drop);
+});
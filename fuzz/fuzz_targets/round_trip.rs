@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use synson::{parse_json, serialize_canonical, serialize_json, serialize_json_pretty, JsonValue};
+
+// Whatever the serializers print must parse back to the value it was printed from.
+fuzz_target!(|value: JsonValue| {
+    assert_eq!(
+        parse_json(&serialize_json(&value), None).as_ref(),
+        Ok(&value)
+    );
+    assert_eq!(
+        parse_json(&serialize_json_pretty(&value, 2), None).as_ref(),
+        Ok(&value)
+    );
+    let canonical = serialize_canonical(&value).unwrap();
+    assert_eq!(parse_json(&canonical, None).as_ref(), Ok(&value));
+});
@@ -0,0 +1,88 @@
+//! Generates Rust types from JSON sample documents or a JSON Schema.
+//!
+//! ```text
+//! synson-codegen [--schema] [--serde] [--name NAME] [FILE...]
+//! ```
+//!
+//! Every JSON value in the input files (or standard input) is a sample; files may hold
+//! several concatenated values or JSON Lines. With `--schema`, the input is a single
+//! schema document instead. The generated code is written to standard output.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::{env, fs};
+
+use synson::model::{CodegenDerive, JsonCodegenOptions};
+use synson::{generate_rust, infer_shape, parse_json, parse_many, JsonShape};
+
+const USAGE: &str = "usage: synson-codegen [--schema] [--serde] [--name NAME] [FILE...]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => {
+            print!("{code}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("synson-codegen: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<String, String> {
+    let mut options = JsonCodegenOptions::default();
+    let mut schema = false;
+    let mut files = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--schema" => schema = true,
+            "--serde" => options.derive = CodegenDerive::Serde,
+            "--name" => options.root_name = args.next().ok_or(USAGE)?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            flag if flag.starts_with("--") => {
+                return Err(format!("unknown option '{flag}'\n{USAGE}"))
+            }
+            _ => files.push(arg),
+        }
+    }
+
+    let inputs = if files.is_empty() {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("<stdin>: {e}"))?;
+        vec![("<stdin>".to_string(), input)]
+    } else {
+        files
+            .into_iter()
+            .map(|file| {
+                let input = fs::read_to_string(&file).map_err(|e| format!("{file}: {e}"))?;
+                Ok((file, input))
+            })
+            .collect::<Result<_, String>>()?
+    };
+
+    let shape = if schema {
+        let [(name, input)] = inputs.as_slice() else {
+            return Err("--schema takes a single schema document".to_string());
+        };
+        let value = parse_json(input, None)
+            .map_err(|e| format!("{name}:{}:{}: {}", e.line, e.column, e.message))?;
+        JsonShape::from_schema(&value).map_err(|e| format!("{name}: {e}"))?
+    } else {
+        let mut samples = Vec::new();
+        for (name, input) in &inputs {
+            for result in parse_many(input) {
+                let (value, _) =
+                    result.map_err(|e| format!("{name}:{}:{}: {}", e.line, e.column, e.message))?;
+                samples.push(value);
+            }
+        }
+        infer_shape(&samples)
+    };
+
+    Ok(generate_rust(&shape, &options))
+}
@@ -0,0 +1,534 @@
+//! Validates, formats, queries, diffs and patches JSON documents from the command line.
+//!
+//! ```text
+//! synson validate [FILE...]
+//! synson fmt [--compact] [--indent N] [--check | --write] [FILE...]
+//! synson get [--compact] [--raw] POINTER [FILE]
+//! synson query [--compact] [--raw] [--paths] PATH [FILE]
+//! synson diff [--patch] OLD NEW
+//! synson patch [--merge] [--compact] [--write] PATCH [FILE]
+//! ```
+//!
+//! Inputs are read from the named files, or from standard input when no file (or `-`) is
+//! given. Parse errors are reported as `file:line:column: message` followed by the
+//! offending line. The exit status is 0 on success, 1 when the answer is negative (an
+//! invalid or unformatted document, a missing pointer, no query match, differing
+//! documents or a patch that does not apply), and 2 for usage and I/O errors, so the
+//! commands can be used directly in shell scripts and pre-commit hooks.
+//!
+//! Numbers are held as `f64`s, so `fmt` and `patch --write` refuse (with status 1)
+//! documents containing a number they would not write back with the same value, such as
+//! `1e400` or an integer beyond 2^53 like `12345678901234567890`.
+
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+use std::{env, fs};
+
+use synson::diff::{diff_json, render_diff};
+use synson::number::{format_f64, parse_f64};
+use synson::{
+    apply_patch, create_patch, merge_patch, parse_json, parse_patch, serialize_json,
+    serialize_json_pretty, JsonParseError, JsonPath, JsonPointer, JsonValue,
+};
+
+const USAGE: &str = "usage: synson <command> [OPTIONS] [ARGS]
+
+commands:
+  validate [FILE...]                                  check that documents are valid JSON
+  fmt [--compact] [--indent N] [--check | --write] [FILE...]
+                                                      format documents
+  get [--compact] [--raw] POINTER [FILE]              print the value at a JSON Pointer
+  query [--compact] [--raw] [--paths] PATH [FILE]     print the values matching a JSONPath
+  diff [--patch] OLD NEW                              show the changes between two documents
+  patch [--merge] [--compact] [--write] PATCH [FILE]  apply a JSON Patch or Merge Patch
+
+Without FILE (or with '-'), the document is read from standard input.";
+
+/// Why a command stopped early: the message to print and the exit status.
+struct Failure {
+    code: u8,
+    message: String,
+}
+
+impl Failure {
+    /// A negative answer (exit status 1).
+    fn failed(message: impl Into<String>) -> Self {
+        Failure {
+            code: 1,
+            message: message.into(),
+        }
+    }
+
+    /// A usage or I/O error (exit status 2).
+    fn error(message: impl Into<String>) -> Self {
+        Failure {
+            code: 2,
+            message: message.into(),
+        }
+    }
+
+    fn usage(message: impl Into<String>) -> Self {
+        Failure::error(format!("{}\n{USAGE}", message.into()))
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            if !failure.message.is_empty() {
+                eprintln!("{}", failure.message);
+            }
+            ExitCode::from(failure.code)
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Failure> {
+    let Some((command, args)) = args.split_first() else {
+        return Err(Failure::error(USAGE));
+    };
+
+    match command.as_str() {
+        "validate" => validate(Args::parse(args, &[])?),
+        "fmt" => fmt(Args::parse(args, &["--indent"])?),
+        "get" => get(Args::parse(args, &[])?),
+        "query" => query(Args::parse(args, &[])?),
+        "diff" => diff(Args::parse(args, &[])?),
+        "patch" => patch(Args::parse(args, &[])?),
+        "-h" | "--help" | "help" => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(Failure::usage(format!("unknown command '{command}'"))),
+    }
+}
+
+/// The flags and positional arguments of a command.
+struct Args {
+    flags: Vec<(String, Option<String>)>,
+    positional: Vec<String>,
+}
+
+impl Args {
+    /// Splits `args` into flags and positional arguments. Flags listed in `with_value` take
+    /// the next argument as their value; everything after `--` is positional.
+    fn parse(args: &[String], with_value: &[&str]) -> Result<Self, Failure> {
+        let mut parsed = Args {
+            flags: Vec::new(),
+            positional: Vec::new(),
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                parsed.positional.extend(args.by_ref().cloned());
+            } else if arg.starts_with('-') && arg != "-" {
+                let value = if with_value.contains(&arg.as_str()) {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| Failure::usage(format!("{arg} takes a value")))?;
+                    Some(value.clone())
+                } else {
+                    None
+                };
+                parsed.flags.push((arg.clone(), value));
+            } else {
+                parsed.positional.push(arg.clone());
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Rejects any flag not in `known`.
+    fn check(&self, known: &[&str]) -> Result<(), Failure> {
+        match self
+            .flags
+            .iter()
+            .find(|(flag, _)| !known.contains(&flag.as_str()))
+        {
+            Some((flag, _)) => Err(Failure::usage(format!("unknown option '{flag}'"))),
+            None => Ok(()),
+        }
+    }
+
+    fn has(&self, flag: &str) -> bool {
+        self.flags.iter().any(|(f, _)| f == flag)
+    }
+
+    fn value(&self, flag: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .rev()
+            .find(|(f, _)| f == flag)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Returns the positional arguments, checking that there are between `min` and `max`.
+    fn positional(&self, min: usize, max: usize) -> Result<&[String], Failure> {
+        let count = self.positional.len();
+        if count < min {
+            Err(Failure::usage("missing argument"))
+        } else if count > max {
+            Err(Failure::usage(format!(
+                "unexpected argument '{}'",
+                self.positional[max]
+            )))
+        } else {
+            Ok(&self.positional)
+        }
+    }
+}
+
+/// A document read from a file or standard input.
+struct Input {
+    name: String,
+    path: Option<String>,
+    text: String,
+}
+
+impl Input {
+    /// Reads `path`, or standard input if it is `None` or `-`.
+    fn read(path: Option<&str>) -> Result<Self, Failure> {
+        match path {
+            None | Some("-") => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|e| Failure::error(format!("<stdin>: {e}")))?;
+                Ok(Input {
+                    name: "<stdin>".to_string(),
+                    path: None,
+                    text,
+                })
+            }
+            Some(path) => {
+                let text =
+                    fs::read_to_string(path).map_err(|e| Failure::error(format!("{path}: {e}")))?;
+                Ok(Input {
+                    name: path.to_string(),
+                    path: Some(path.to_string()),
+                    text,
+                })
+            }
+        }
+    }
+
+    /// Reads every file in `paths`, or standard input if there are none.
+    fn read_all(paths: &[String]) -> Result<Vec<Self>, Failure> {
+        if paths.is_empty() {
+            return Ok(vec![Input::read(None)?]);
+        }
+        paths.iter().map(|path| Input::read(Some(path))).collect()
+    }
+
+    fn parse(&self) -> Result<JsonValue, Failure> {
+        parse_json(&self.text, None)
+            .map_err(|e| Failure::failed(render_parse_error(&self.name, &self.text, &e)))
+    }
+
+    /// Parses a document that is about to be reformatted, refusing it when one of its
+    /// numbers would not be written back with the same value.
+    fn parse_exact(&self) -> Result<JsonValue, Failure> {
+        let value = self.parse()?;
+        if let Some(index) = inexact_number(&self.text) {
+            let error = JsonParseError::new(
+                "Number cannot be written back without changing its value",
+                index,
+                &self.text,
+            );
+            return Err(Failure::failed(render_parse_error(
+                &self.name, &self.text, &error,
+            )));
+        }
+        Ok(value)
+    }
+
+    /// Replaces the file's contents; standard input cannot be written back.
+    fn write(&self, text: &str) -> Result<(), Failure> {
+        let path = self
+            .path
+            .as_deref()
+            .ok_or_else(|| Failure::usage("--write needs a file, not standard input"))?;
+        fs::write(path, text).map_err(|e| Failure::error(format!("{path}: {e}")))
+    }
+}
+
+/// Renders a parse error as `name:line:column: message`, followed by the offending line
+/// with a caret under the error position.
+fn render_parse_error(name: &str, input: &str, error: &JsonParseError) -> String {
+    let line = input.lines().nth(error.line - 1).unwrap_or("");
+    let number = error.line.to_string();
+    let gutter = " ".repeat(number.len());
+    // Keep tabs so that the caret lines up with the source line.
+    let padding: String = line
+        .chars()
+        .take(error.column - 1)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    format!(
+        "{name}:{}:{}: {}\n{gutter} |\n{number} | {line}\n{gutter} | {padding}^",
+        error.line, error.column, error.message
+    )
+}
+
+/// Returns the offset of the first number in `text`, a valid JSON document, whose value
+/// changes once stored in an `f64` and serialized: one out of range, such as `1e400`, or
+/// with more digits than an `f64` holds, such as `12345678901234567890`.
+fn inexact_number(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while let Some(&byte) = bytes.get(i) {
+        match byte {
+            b'"' => {
+                i += 1;
+                while let Some(&byte) = bytes.get(i) {
+                    i += if byte == b'\\' { 2 } else { 1 };
+                    if byte == b'"' {
+                        break;
+                    }
+                }
+            }
+            b'-' | b'0'..=b'9' => {
+                let start = i;
+                while bytes
+                    .get(i)
+                    .is_some_and(|b| matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'))
+                {
+                    i += 1;
+                }
+                let token = &text[start..i];
+                let exact = parse_f64(token)
+                    .is_some_and(|n| n.is_finite() && decimal(&format_f64(n)) == decimal(token));
+                if !exact {
+                    return Some(start);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Splits a JSON number into its sign, significant digits and power of ten, so that
+/// numbers with the same value compare equal however they are written.
+fn decimal(token: &str) -> (bool, String, i64) {
+    let (negative, unsigned) = match token.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, token),
+    };
+    let (mantissa, exponent) = unsigned.split_once(['e', 'E']).unwrap_or((unsigned, "0"));
+    let (int, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{int}{fraction}");
+    let significant = digits.trim_start_matches('0').trim_end_matches('0');
+    if significant.is_empty() {
+        return (negative, String::new(), 0);
+    }
+    // Out-of-range exponents only occur in numbers that are not finite `f64`s anyway.
+    let exponent = exponent.parse::<i64>().unwrap_or(i64::MAX);
+    let trailing_zeros = digits.len() - digits.trim_end_matches('0').len();
+    let power = exponent
+        .saturating_sub(fraction.len() as i64)
+        .saturating_add(trailing_zeros as i64);
+    (negative, significant.to_string(), power)
+}
+
+/// How `get`, `query` and `patch` print values.
+struct Output {
+    compact: bool,
+    raw: bool,
+}
+
+impl Output {
+    fn from_args(args: &Args) -> Self {
+        Output {
+            compact: args.has("--compact"),
+            raw: args.has("--raw"),
+        }
+    }
+
+    fn render(&self, value: &JsonValue) -> String {
+        match value {
+            JsonValue::String(s) if self.raw => s.clone(),
+            _ if self.compact => serialize_json(value),
+            _ => serialize_json_pretty(value, 2),
+        }
+    }
+}
+
+fn print(text: &str) -> Result<(), Failure> {
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "{text}")
+        .and_then(|()| stdout.flush())
+        .map_err(|e| Failure::error(format!("<stdout>: {e}")))
+}
+
+fn validate(args: Args) -> Result<(), Failure> {
+    args.check(&[])?;
+
+    let mut errors = Vec::new();
+    for input in Input::read_all(args.positional(0, usize::MAX)?)? {
+        if let Err(failure) = input.parse() {
+            errors.push(failure.message);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::failed(errors.join("\n")))
+    }
+}
+
+fn fmt(args: Args) -> Result<(), Failure> {
+    args.check(&["--compact", "--indent", "--check", "--write"])?;
+    let (check, write) = (args.has("--check"), args.has("--write"));
+    if check && write {
+        return Err(Failure::usage("--check and --write cannot be combined"));
+    }
+    let indent = match args.value("--indent") {
+        Some(indent) => indent
+            .parse()
+            .map_err(|_| Failure::usage(format!("invalid indent '{indent}'")))?,
+        None => 2,
+    };
+
+    let mut unformatted = Vec::new();
+    for input in Input::read_all(args.positional(0, usize::MAX)?)? {
+        let value = input.parse_exact()?;
+        let formatted = if args.has("--compact") {
+            serialize_json(&value)
+        } else {
+            serialize_json_pretty(&value, indent)
+        } + "\n";
+
+        if check {
+            if input.text != formatted {
+                unformatted.push(format!("{}: not formatted", input.name));
+            }
+        } else if write {
+            if input.text != formatted {
+                input.write(&formatted)?;
+            }
+        } else {
+            print(formatted.trim_end_matches('\n'))?;
+        }
+    }
+
+    if unformatted.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::failed(unformatted.join("\n")))
+    }
+}
+
+fn get(args: Args) -> Result<(), Failure> {
+    args.check(&["--compact", "--raw"])?;
+    let positional = args.positional(1, 2)?;
+    let pointer = JsonPointer::parse(&positional[0]).map_err(|e| Failure::error(e.to_string()))?;
+    let input = Input::read(positional.get(1).map(String::as_str))?;
+
+    let value = input.parse()?;
+    let found = pointer
+        .resolve(&value)
+        .map_err(|e| Failure::failed(format!("{}: {e}", input.name)))?;
+    print(&Output::from_args(&args).render(found))
+}
+
+fn query(args: Args) -> Result<(), Failure> {
+    args.check(&["--compact", "--raw", "--paths"])?;
+    let positional = args.positional(1, 2)?;
+    let expression = &positional[0];
+    let path = JsonPath::parse(expression)
+        .map_err(|e| Failure::error(render_parse_error("<path>", expression, &e)))?;
+    let input = Input::read(positional.get(1).map(String::as_str))?;
+
+    let value = input.parse()?;
+    let matches = path.query(&value);
+    if matches.is_empty() {
+        return Err(Failure::failed(""));
+    }
+
+    let output = Output::from_args(&args);
+    for found in matches {
+        if args.has("--paths") {
+            print(&found.path.to_string())?;
+        } else {
+            print(&output.render(found.value))?;
+        }
+    }
+    Ok(())
+}
+
+fn diff(args: Args) -> Result<(), Failure> {
+    args.check(&["--patch"])?;
+    let positional = args.positional(2, 2)?;
+    if positional.iter().all(|path| path == "-") {
+        return Err(Failure::usage(
+            "only one document can be read from standard input",
+        ));
+    }
+    let old = Input::read(Some(&positional[0]))?.parse()?;
+    let new = Input::read(Some(&positional[1]))?.parse()?;
+
+    let changes = diff_json(&old, &new, None);
+    if args.has("--patch") {
+        print(&serialize_json_pretty(
+            &create_patch(&old, &new).to_value(),
+            2,
+        ))?;
+    } else if !changes.is_empty() {
+        print(render_diff(&changes).trim_end_matches('\n'))?;
+    }
+
+    if changes.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::failed(""))
+    }
+}
+
+fn patch(args: Args) -> Result<(), Failure> {
+    args.check(&["--merge", "--compact", "--write"])?;
+    let positional = args.positional(1, 2)?;
+    let target_path = positional.get(1).map(String::as_str);
+    if positional[0] == "-" && matches!(target_path, None | Some("-")) {
+        return Err(Failure::usage(
+            "only one document can be read from standard input",
+        ));
+    }
+    let patch_input = Input::read(Some(&positional[0]))?;
+    let input = Input::read(target_path)?;
+
+    let write = args.has("--write");
+    let patch = if write {
+        patch_input.parse_exact()?
+    } else {
+        patch_input.parse()?
+    };
+    let mut value = if write {
+        input.parse_exact()?
+    } else {
+        input.parse()?
+    };
+    if args.has("--merge") {
+        merge_patch(&mut value, &patch);
+    } else {
+        let patch = parse_patch(&patch)
+            .map_err(|e| Failure::failed(format!("{}: {e}", patch_input.name)))?;
+        apply_patch(&mut value, &patch)
+            .map_err(|e| Failure::failed(format!("{}: {e}", input.name)))?;
+    }
+
+    let output = Output {
+        compact: args.has("--compact"),
+        raw: false,
+    };
+    if write {
+        input.write(&(output.render(&value) + "\n"))
+    } else {
+        print(&output.render(&value))
+    }
+}
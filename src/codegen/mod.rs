@@ -0,0 +1,6 @@
+//! Rust type generation from inferred or schema-described [`JsonShape`](crate::JsonShape)s.
+
+mod names;
+pub mod rust;
+
+pub use rust::generate_rust;
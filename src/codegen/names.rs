@@ -0,0 +1,98 @@
+/// Rust keywords, which cannot be used as plain identifiers.
+const KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+];
+
+/// Splits a JSON member name into words at case changes and at characters other than
+/// ASCII letters and digits, e.g. `"HTTPServer_url"` into `HTTP`, `Server` and `url`.
+fn words(name: &str) -> Vec<&str> {
+    let bytes = name.as_bytes();
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, &c) in bytes.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if let Some(s) = start.take() {
+                words.push(&name[s..i]);
+            }
+            continue;
+        }
+        let prev = i.checked_sub(1).map(|j| bytes[j]);
+        let next = bytes.get(i + 1);
+        let boundary = c.is_ascii_uppercase()
+            && prev.is_some_and(|p| {
+                p.is_ascii_lowercase()
+                    || p.is_ascii_digit()
+                    || (p.is_ascii_uppercase() && next.is_some_and(u8::is_ascii_lowercase))
+            });
+        match start {
+            Some(s) if boundary => {
+                words.push(&name[s..i]);
+                start = Some(i);
+            }
+            None => start = Some(i),
+            Some(_) => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push(&name[s..]);
+    }
+    words
+}
+
+/// Returns the `UpperCamelCase` type name for a member name.
+pub(crate) fn type_name(name: &str) -> String {
+    let mut result: String = words(name)
+        .into_iter()
+        .map(|word| {
+            let lower = word.to_ascii_lowercase();
+            lower[..1].to_ascii_uppercase() + &lower[1..]
+        })
+        .collect();
+    if result.is_empty() {
+        result.push_str("Value");
+    } else if result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert_str(0, "Type");
+    }
+    if result == "Self" {
+        result.push('_');
+    }
+    result
+}
+
+/// Returns the `snake_case` field name for a member name, as a raw identifier if it is
+/// a keyword.
+pub(crate) fn field_name(name: &str) -> String {
+    let mut result = words(name)
+        .into_iter()
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("_");
+    if result.is_empty() {
+        result.push_str("field");
+    } else if result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert_str(0, "field_");
+    }
+    match result.as_str() {
+        // These cannot be raw identifiers.
+        "self" | "super" | "crate" => result + "_",
+        word if KEYWORDS.contains(&word) => format!("r#{result}"),
+        _ => result,
+    }
+}
+
+/// Returns the name for the elements of an array held by the member `name`, e.g.
+/// `"categories"` becomes `"category"` and `"data"` becomes `"dataItem"`.
+pub(crate) fn singular(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies").filter(|stem| !stem.is_empty()) {
+        format!("{stem}y")
+    } else if name.ends_with('s') && !name.ends_with("ss") && name.len() > 1 {
+        name[..name.len() - 1].to_string()
+    } else {
+        format!("{name}Item")
+    }
+}
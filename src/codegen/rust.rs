@@ -0,0 +1,238 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use super::names::{field_name, singular, type_name};
+use crate::model::{CodegenDerive, JsonCodegenOptions, JsonShape, ShapeField, ShapeType};
+
+/// Names the generated types must not take, so field types keep their meaning.
+const RESERVED: [&str; 13] = [
+    "Option",
+    "Some",
+    "None",
+    "Vec",
+    "String",
+    "Box",
+    "Result",
+    "Ok",
+    "Err",
+    "FromJson",
+    "ToJson",
+    "Serialize",
+    "Deserialize",
+];
+
+/// Generates Rust type definitions for values of the given shape.
+///
+/// Objects become structs with one `pub` field per member: keys that are not
+/// `snake_case` identifiers get a `rename` attribute, optional and nullable members become
+/// `Option`s, and nested objects get their own struct named after the member holding them
+/// (array elements after the singular, e.g. `Tag` for `tags`). Unions of several kinds
+/// become untagged enums, and values with an unknown shape are kept as `JsonValue`s.
+///
+/// The output starts with the `use` line the derives need; the root type is a struct
+/// (or enum) named `options.root_name`, or a type alias of that name for other shapes.
+/// A nullable root is generated as if it were not; use `Option` of it for such documents.
+///
+/// # Arguments
+///
+/// * `shape` - The shape to generate types for, e.g. from
+///   [`infer_shape`](crate::infer::infer_shape) or
+///   [`JsonShape::from_schema`].
+/// * `options` - The root type name and the derives to emit.
+///
+/// # Returns
+///
+/// The Rust source code.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{generate_rust, infer_shape, json};
+/// use synson::model::JsonCodegenOptions;
+///
+/// let samples = [
+///     json!({"userId": 1, "tags": [{"label": "a"}]}),
+///     json!({"userId": 2, "tags": [], "note": "hi"}),
+/// ];
+/// let code = generate_rust(&infer_shape(&samples), &JsonCodegenOptions::default());
+/// assert_eq!(code, "\
+/// use synson::{FromJson, ToJson};
+///
+/// #[derive(Debug, Clone, PartialEq, FromJson, ToJson)]
+/// pub struct Root {
+///     pub note: Option<String>,
+///     pub tags: Vec<Tag>,
+///     #[json(rename = \"userId\")]
+///     pub user_id: i64,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, FromJson, ToJson)]
+/// pub struct Tag {
+///     pub label: String,
+/// }
+/// ");
+/// ```
+pub fn generate_rust(shape: &JsonShape, options: &JsonCodegenOptions) -> String {
+    let mut generator = Generator {
+        derive: options.derive,
+        names: RESERVED.iter().map(|name| name.to_string()).collect(),
+        items: Vec::new(),
+    };
+
+    let root = JsonShape {
+        types: shape.types.clone(),
+        nullable: false,
+    };
+    let root_name = type_name(&options.root_name);
+    let ty = generator.type_of(&root, &options.root_name);
+    if ty != root_name {
+        generator
+            .items
+            .insert(0, format!("pub type {root_name} = {ty};\n"));
+    }
+
+    let mut code = match options.derive {
+        CodegenDerive::Synson => "use synson::{FromJson, ToJson};\n".to_string(),
+        CodegenDerive::Serde => "use serde::{Deserialize, Serialize};\n".to_string(),
+    };
+    for item in generator.items {
+        code.push('\n');
+        code.push_str(&item);
+    }
+    code
+}
+
+struct Generator {
+    derive: CodegenDerive,
+    /// The type names already taken.
+    names: HashSet<String>,
+    /// The generated definitions, in the order they are emitted.
+    items: Vec<String>,
+}
+
+impl Generator {
+    /// Returns the Rust type for `shape`, generating the definitions it needs. `hint`
+    /// is the member name the type is derived from.
+    fn type_of(&mut self, shape: &JsonShape, hint: &str) -> String {
+        let ty = match shape.types.as_slice() {
+            [] => "synson::JsonValue".to_string(),
+            [kind] => self.kind_type(kind, hint),
+            kinds => self.union(kinds, hint),
+        };
+        if shape.nullable {
+            format!("Option<{ty}>")
+        } else {
+            ty
+        }
+    }
+
+    fn kind_type(&mut self, kind: &ShapeType, hint: &str) -> String {
+        match kind {
+            ShapeType::Boolean => "bool".to_string(),
+            ShapeType::Integer => "i64".to_string(),
+            ShapeType::Number => "f64".to_string(),
+            ShapeType::String(_) => "String".to_string(),
+            ShapeType::Array(items) => format!("Vec<{}>", self.type_of(items, &singular(hint))),
+            ShapeType::Object(fields) => self.structure(fields, hint),
+        }
+    }
+
+    /// Reserves a unique type name derived from `hint`.
+    fn name(&mut self, hint: &str) -> String {
+        let base = type_name(hint);
+        let mut name = base.clone();
+        let mut n = 2;
+        while !self.names.insert(name.clone()) {
+            name = format!("{base}{n}");
+            n += 1;
+        }
+        name
+    }
+
+    /// Starts a definition, returning its slot so it precedes the types it uses.
+    fn reserve(&mut self) -> usize {
+        self.items.push(String::new());
+        self.items.len() - 1
+    }
+
+    fn header(&self, code: &mut String) {
+        code.push_str(match self.derive {
+            CodegenDerive::Synson => "#[derive(Debug, Clone, PartialEq, FromJson, ToJson)]\n",
+            CodegenDerive::Serde => "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+        });
+    }
+
+    fn attribute(&self) -> &'static str {
+        match self.derive {
+            CodegenDerive::Synson => "json",
+            CodegenDerive::Serde => "serde",
+        }
+    }
+
+    fn structure(&mut self, fields: &[ShapeField], hint: &str) -> String {
+        let name = self.name(hint);
+        let slot = self.reserve();
+
+        let mut code = String::new();
+        self.header(&mut code);
+        let _ = writeln!(code, "pub struct {name} {{");
+
+        let mut field_names = HashSet::new();
+        for field in fields {
+            let mut ident = field_name(&field.name);
+            let base = ident.clone();
+            let mut n = 2;
+            while !field_names.insert(ident.clone()) {
+                ident = format!("{base}_{n}");
+                n += 1;
+            }
+
+            let mut ty = self.type_of(&field.shape, &field.name);
+            if !field.required && !field.shape.nullable {
+                ty = format!("Option<{ty}>");
+            }
+
+            let mut options = Vec::new();
+            if ident.trim_start_matches("r#") != field.name {
+                options.push(format!("rename = {:?}", field.name));
+            }
+            if !field.required && self.derive == CodegenDerive::Serde {
+                options.push("default".to_string());
+                options.push("skip_serializing_if = \"Option::is_none\"".to_string());
+            }
+            if !options.is_empty() {
+                let _ = writeln!(code, "    #[{}({})]", self.attribute(), options.join(", "));
+            }
+            let _ = writeln!(code, "    pub {ident}: {ty},");
+        }
+
+        code.push_str("}\n");
+        self.items[slot] = code;
+        name
+    }
+
+    fn union(&mut self, kinds: &[ShapeType], hint: &str) -> String {
+        let name = self.name(hint);
+        let slot = self.reserve();
+
+        let mut code = String::new();
+        self.header(&mut code);
+        let _ = writeln!(code, "#[{}(untagged)]", self.attribute());
+        let _ = writeln!(code, "pub enum {name} {{");
+        for kind in kinds {
+            let (variant, ty) = match kind {
+                ShapeType::Boolean => ("Bool", "bool".to_string()),
+                ShapeType::Integer => ("Integer", "i64".to_string()),
+                ShapeType::Number => ("Number", "f64".to_string()),
+                ShapeType::String(_) => ("String", "String".to_string()),
+                ShapeType::Array(_) => ("Array", self.kind_type(kind, hint)),
+                ShapeType::Object(_) => ("Object", self.kind_type(kind, &format!("{hint}Object"))),
+            };
+            let _ = writeln!(code, "    {variant}({ty}),");
+        }
+
+        code.push_str("}\n");
+        self.items[slot] = code;
+        name
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use crate::model::{FromJsonError, JsonValue};
+use crate::serializer::serialize_json;
+
+/// Conversion from a borrowed [`JsonValue`] into a Rust value.
+///
+/// Implemented for the standard scalar types, `String`, `Option`, `Box`, `Vec`, arrays,
+/// tuples, and maps with string keys. Errors carry the path of the offending value.
+/// With the `derive` feature, `#[derive(FromJson)]` implements it for structs and enums.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use synson::parse_json;
+/// use synson::convert::FromJson;
+///
+/// let doc = parse_json(r#"{"ports": [80, 443], "limits": [1, 70000]}"#, None).unwrap();
+///
+/// let map = HashMap::<String, Vec<u16>>::from_json(&doc);
+/// let err = map.unwrap_err();
+/// assert_eq!(err.path.to_string(), "/limits/1");
+/// assert_eq!(err.to_string(), "number 70000 is out of range for u16 at '/limits/1'");
+/// ```
+pub trait FromJson: Sized {
+    /// Converts `value`, or reports why it cannot be converted.
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError>;
+
+    /// The value to use when an object member of this type is absent, if any.
+    ///
+    /// Returns `None` (the member is required) by default; `Option<T>` returns `Some(None)`
+    /// so optional members may be omitted.
+    fn from_missing() -> Option<Self> {
+        None
+    }
+}
+
+impl FromJson for JsonValue {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        Ok(value.clone())
+    }
+}
+
+impl FromJson for bool {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        value
+            .as_bool()
+            .ok_or_else(|| FromJsonError::invalid_type("a boolean", value))
+    }
+}
+
+impl FromJson for () {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        match value {
+            JsonValue::Null => Ok(()),
+            _ => Err(FromJsonError::invalid_type("null", value)),
+        }
+    }
+}
+
+macro_rules! from_json_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FromJson for $ty {
+                fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+                    let JsonValue::Number(n) = value else {
+                        return Err(FromJsonError::invalid_type("an integer", value));
+                    };
+                    if n.fract() != 0.0 || !n.is_finite() {
+                        return Err(FromJsonError::new(format!(
+                            "expected an integer, found {}",
+                            serialize_json(value)
+                        )));
+                    }
+                    // `MAX + 1` is a power of two (exact as an f64), so every integer
+                    // below it fits.
+                    if *n < <$ty>::MIN as f64 || *n >= (<$ty>::MAX as f64) + 1.0 {
+                        return Err(FromJsonError::new(format!(
+                            "number {} is out of range for {}",
+                            serialize_json(value),
+                            stringify!($ty)
+                        )));
+                    }
+                    Ok(*n as $ty)
+                }
+            }
+        )*
+    };
+}
+
+from_json_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl FromJson for f64 {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        value
+            .as_f64()
+            .ok_or_else(|| FromJsonError::invalid_type("a number", value))
+    }
+}
+
+impl FromJson for f32 {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        f64::from_json(value).map(|n| n as f32)
+    }
+}
+
+impl FromJson for String {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| FromJsonError::invalid_type("a string", value))
+    }
+}
+
+impl FromJson for char {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        let s = value
+            .as_str()
+            .ok_or_else(|| FromJsonError::invalid_type("a character", value))?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(FromJsonError::new(format!(
+                "expected a single character, found {}",
+                serialize_json(value)
+            ))),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        match value {
+            JsonValue::Null => Ok(None),
+            _ => T::from_json(value).map(Some),
+        }
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T: FromJson> FromJson for Box<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        T::from_json(value).map(Box::new)
+    }
+}
+
+/// Converts each element, prefixing errors with the element index.
+fn elements<T: FromJson>(items: &[JsonValue]) -> Result<Vec<T>, FromJsonError> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| T::from_json(item).map_err(|e| e.at(i.to_string())))
+        .collect()
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        match value {
+            JsonValue::Array(items) => elements(items),
+            _ => Err(FromJsonError::invalid_type("an array", value)),
+        }
+    }
+}
+
+impl<T: FromJson, const N: usize> FromJson for [T; N] {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        match value {
+            JsonValue::Array(items) if items.len() == N => elements(items)?
+                .try_into()
+                .map_err(|_| FromJsonError::new("array length changed during conversion")),
+            JsonValue::Array(items) => Err(FromJsonError::new(format!(
+                "expected an array of length {N}, found length {}",
+                items.len()
+            ))),
+            _ => Err(FromJsonError::invalid_type("an array", value)),
+        }
+    }
+}
+
+macro_rules! from_json_tuple {
+    ($len:expr => $($name:ident $index:tt),+) => {
+        impl<$($name: FromJson),+> FromJson for ($($name,)+) {
+            fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+                match value {
+                    JsonValue::Array(items) if items.len() == $len => Ok(($(
+                        $name::from_json(&items[$index])
+                            .map_err(|e| e.at(stringify!($index)))?,
+                    )+)),
+                    JsonValue::Array(items) => Err(FromJsonError::new(format!(
+                        "expected an array of length {}, found length {}",
+                        $len,
+                        items.len()
+                    ))),
+                    _ => Err(FromJsonError::invalid_type("an array", value)),
+                }
+            }
+        }
+    };
+}
+
+from_json_tuple!(1 => A 0);
+from_json_tuple!(2 => A 0, B 1);
+from_json_tuple!(3 => A 0, B 1, C 2);
+from_json_tuple!(4 => A 0, B 1, C 2, D 3);
+from_json_tuple!(5 => A 0, B 1, C 2, D 3, E 4);
+from_json_tuple!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Converts each member, prefixing errors with the member key.
+fn members<'v, K, V>(
+    value: &'v JsonValue,
+) -> Result<impl Iterator<Item = Result<(K, V), FromJsonError>> + 'v, FromJsonError>
+where
+    K: From<String>,
+    V: FromJson,
+{
+    match value {
+        JsonValue::Object(map) => Ok(map.iter().map(|(key, item)| {
+            V::from_json(item)
+                .map(|v| (K::from(key.clone()), v))
+                .map_err(|e| e.at(key.clone()))
+        })),
+        _ => Err(FromJsonError::invalid_type("an object", value)),
+    }
+}
+
+impl<K, V, S> FromJson for HashMap<K, V, S>
+where
+    K: From<String> + Eq + Hash,
+    V: FromJson,
+    S: BuildHasher + Default,
+{
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        members(value)?.collect()
+    }
+}
+
+impl<K, V> FromJson for BTreeMap<K, V>
+where
+    K: From<String> + Ord,
+    V: FromJson,
+{
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        members(value)?.collect()
+    }
+}
@@ -0,0 +1,21 @@
+//! Conversions between [`JsonValue`](crate::JsonValue) and Rust types.
+//!
+//! With the `derive` feature, `#[derive(FromJson, ToJson)]` implements both traits.
+//! Container attributes such as `#[json(tag = "...")]` configure enum tagging and are
+//! rejected on structs:
+//!
+//! ```compile_fail
+//! use synson::{FromJson, ToJson};
+//!
+//! #[derive(FromJson, ToJson)]
+//! #[json(tag = "type")]
+//! struct Point {
+//!     x: i64,
+//! }
+//! ```
+
+pub mod from_json;
+pub mod to_json;
+
+pub use from_json::FromJson;
+pub use to_json::ToJson;
@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::model::JsonValue;
+
+/// Conversion from a Rust value into a [`JsonValue`].
+///
+/// Implemented for the standard scalar types, strings, `Option`, `Box`, slices, `Vec`,
+/// arrays, tuples, and maps with string keys. Integers are stored as `f64`, so values
+/// beyond 2^53 in magnitude lose precision. With the `derive` feature,
+/// `#[derive(ToJson)]` implements it for structs and enums.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use synson::convert::ToJson;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert("point", (1, 2.5));
+/// assert_eq!(map.to_json().to_string(), r#"{"point":[1,2.5]}"#);
+/// assert_eq!(Some("x").to_json().to_string(), r#""x""#);
+/// ```
+pub trait ToJson {
+    /// Converts `self` into a JSON value.
+    fn to_json(&self) -> JsonValue;
+}
+
+impl ToJson for JsonValue {
+    fn to_json(&self) -> JsonValue {
+        self.clone()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn to_json(&self) -> JsonValue {
+        (**self).to_json()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for Box<T> {
+    fn to_json(&self) -> JsonValue {
+        (**self).to_json()
+    }
+}
+
+macro_rules! to_json_via_from {
+    ($($ty:ty),*) => {
+        $(
+            impl ToJson for $ty {
+                fn to_json(&self) -> JsonValue {
+                    JsonValue::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+to_json_via_from!(
+    bool,
+    (),
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64
+);
+
+impl ToJson for str {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::String(self.to_string())
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::String(self.clone())
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> JsonValue {
+        self.as_ref().map_or(JsonValue::Null, ToJson::to_json)
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::Array(self.iter().map(ToJson::to_json).collect())
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> JsonValue {
+        self.as_slice().to_json()
+    }
+}
+
+impl<T: ToJson, const N: usize> ToJson for [T; N] {
+    fn to_json(&self) -> JsonValue {
+        self.as_slice().to_json()
+    }
+}
+
+macro_rules! to_json_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: ToJson),+> ToJson for ($($name,)+) {
+            fn to_json(&self) -> JsonValue {
+                JsonValue::Array(vec![$(self.$index.to_json()),+])
+            }
+        }
+    };
+}
+
+to_json_tuple!(A 0);
+to_json_tuple!(A 0, B 1);
+to_json_tuple!(A 0, B 1, C 2);
+to_json_tuple!(A 0, B 1, C 2, D 3);
+to_json_tuple!(A 0, B 1, C 2, D 3, E 4);
+to_json_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+impl<K: AsRef<str>, V: ToJson, S> ToJson for HashMap<K, V, S> {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::Object(
+            self.iter()
+                .map(|(k, v)| (k.as_ref().to_string(), v.to_json()))
+                .collect(),
+        )
+    }
+}
+
+impl<K: AsRef<str>, V: ToJson> ToJson for BTreeMap<K, V> {
+    fn to_json(&self) -> JsonValue {
+        JsonValue::Object(
+            self.iter()
+                .map(|(k, v)| (k.as_ref().to_string(), v.to_json()))
+                .collect(),
+        )
+    }
+}
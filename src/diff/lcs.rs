@@ -0,0 +1,56 @@
+/// Above this many element comparisons, [`align`] gives up and callers compare by index.
+const MAX_LCS_CELLS: usize = 1_000_000;
+
+/// One step of an alignment between two sequences.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Edit {
+    /// The current elements are equal.
+    Keep,
+    /// Neither current element is part of the alignment: one replaces the other in place.
+    Change,
+    /// The current element of the new sequence is inserted.
+    Insert,
+    /// The current element of the old sequence is deleted.
+    Delete,
+}
+
+/// Aligns two sequences of lengths `n` and `m` along a longest common subsequence, where
+/// `equal(i, j)` compares the `i`th old element with the `j`th new one.
+///
+/// Returns the steps that consume both sequences in order, or `None` if the `n × m`
+/// table would exceed [`MAX_LCS_CELLS`].
+pub(crate) fn align(n: usize, m: usize, equal: impl Fn(usize, usize) -> bool) -> Option<Vec<Edit>> {
+    if n.saturating_mul(m) > MAX_LCS_CELLS {
+        return None;
+    }
+
+    // lcs[i][j] is the LCS length of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if equal(i, j) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let edit = if i < n && j < m && equal(i, j) {
+            Edit::Keep
+        } else if i < n && j < m && lcs[i][j] == lcs[i + 1][j + 1] {
+            Edit::Change
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            Edit::Insert
+        } else {
+            Edit::Delete
+        };
+        i += usize::from(edit != Edit::Insert);
+        j += usize::from(edit != Edit::Delete);
+        edits.push(edit);
+    }
+    Some(edits)
+}
@@ -0,0 +1,6 @@
+pub(crate) mod lcs;
+pub mod render;
+pub mod structural;
+
+pub use render::render_diff;
+pub use structural::diff_json;
@@ -0,0 +1,63 @@
+use crate::model::{JsonChange, JsonValue};
+use crate::serializer::serialize_json;
+
+/// Renders changes as a human-readable, unified-diff-like text.
+///
+/// The output starts with `--- old` / `+++ new` headers; each change then gets a
+/// `@@ <pointer> @@` hunk header followed by the removed value on a `-` line and/or the
+/// added value on a `+` line, serialized as compact JSON. The whole document is shown as
+/// `@@ (root) @@`. No changes render as an empty string.
+///
+/// # Arguments
+///
+/// * `changes` - The changes returned by [`diff_json`](super::diff_json).
+///
+/// # Returns
+///
+/// The rendered text, one line per header or value, each ending with `\n`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::diff::{diff_json, render_diff};
+///
+/// let old = parse_json(r#"{"name": "api", "port": 80}"#, None).unwrap();
+/// let new = parse_json(r#"{"port": 8080, "tls": true}"#, None).unwrap();
+///
+/// assert_eq!(
+///     render_diff(&diff_json(&old, &new, None)),
+///     "--- old\n+++ new\n\
+///      @@ /name @@\n-\"api\"\n\
+///      @@ /port @@\n-80\n+8080\n\
+///      @@ /tls @@\n+true\n"
+/// );
+/// ```
+pub fn render_diff(changes: &[JsonChange]) -> String {
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from("--- old\n+++ new\n");
+    for change in changes {
+        let path = change.path().to_string();
+        let path = if path.is_empty() { "(root)" } else { &path };
+        out.push_str(&format!("@@ {path} @@\n"));
+
+        match change {
+            JsonChange::Added { value, .. } => push_line(&mut out, '+', value),
+            JsonChange::Removed { value, .. } => push_line(&mut out, '-', value),
+            JsonChange::Modified { old, new, .. } => {
+                push_line(&mut out, '-', old);
+                push_line(&mut out, '+', new);
+            }
+        }
+    }
+    out
+}
+
+fn push_line(out: &mut String, marker: char, value: &JsonValue) {
+    out.push(marker);
+    out.push_str(&serialize_json(value));
+    out.push('\n');
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use super::lcs::{align, Edit};
+use crate::model::{ArrayDiffStrategy, JsonChange, JsonDiffOptions, JsonValue};
+use crate::pointer::JsonPointer;
+use crate::serializer::serialize_json;
+
+/// Compares two JSON documents and lists what changed between them.
+///
+/// Objects are compared member by member (in key order), arrays according to
+/// `options.array_strategy`, and any other pair of differing values (including values of
+/// different types) is reported as a single modification.
+///
+/// # Arguments
+///
+/// * `old` - The original document.
+/// * `new` - The updated document.
+/// * `options` - Optional diff options. Defaults to LCS array alignment and exact numbers.
+///
+/// # Returns
+///
+/// The changes, empty if both documents are equal under the given options.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::diff::diff_json;
+/// use synson::model::{ArrayDiffStrategy, JsonChange, JsonDiffOptions};
+///
+/// let old = parse_json(r#"{"port": 80, "hosts": [{"id": 1, "ip": "a"}, {"id": 2, "ip": "b"}]}"#, None).unwrap();
+/// let new = parse_json(r#"{"port": 8080, "hosts": [{"id": 2, "ip": "c"}, {"id": 1, "ip": "a"}]}"#, None).unwrap();
+///
+/// let options = JsonDiffOptions::new(ArrayDiffStrategy::Keyed("id".to_string()), 0.0);
+/// let changes = diff_json(&old, &new, Some(options));
+/// let paths: Vec<_> = changes.iter().map(|c| c.path().to_string()).collect();
+/// assert_eq!(paths, ["/hosts/1/ip", "/port"]);
+/// assert!(matches!(changes[1], JsonChange::Modified { .. }));
+/// ```
+pub fn diff_json(
+    old: &JsonValue,
+    new: &JsonValue,
+    options: Option<JsonDiffOptions>,
+) -> Vec<JsonChange> {
+    let options = options.unwrap_or_default();
+    let mut changes = Vec::new();
+    Differ {
+        options: &options,
+        changes: &mut changes,
+    }
+    .values(old, new, &JsonPointer::root());
+    changes
+}
+
+struct Differ<'a> {
+    options: &'a JsonDiffOptions,
+    changes: &'a mut Vec<JsonChange>,
+}
+
+impl Differ<'_> {
+    fn values(&mut self, old: &JsonValue, new: &JsonValue, path: &JsonPointer) {
+        match (old, new) {
+            _ if self.equal(old, new) => {}
+            (JsonValue::Object(old_map), JsonValue::Object(new_map)) => {
+                let mut keys: Vec<_> = old_map.keys().chain(new_map.keys()).collect();
+                keys.sort();
+                keys.dedup();
+
+                for key in keys {
+                    let child = path.join(key.as_str());
+                    match (old_map.get(key), new_map.get(key)) {
+                        (Some(a), Some(b)) => self.values(a, b, &child),
+                        (Some(a), None) => self.removed(child, a),
+                        (None, Some(b)) => self.added(child, b),
+                        (None, None) => {}
+                    }
+                }
+            }
+            (JsonValue::Array(old_items), JsonValue::Array(new_items)) => {
+                match &self.options.array_strategy {
+                    ArrayDiffStrategy::Index => self.arrays_by_index(old_items, new_items, path),
+                    ArrayDiffStrategy::Lcs => self.arrays_by_lcs(old_items, new_items, path),
+                    ArrayDiffStrategy::Keyed(key) => {
+                        if !self.arrays_by_key(old_items, new_items, key, path) {
+                            self.arrays_by_lcs(old_items, new_items, path);
+                        }
+                    }
+                }
+            }
+            _ => self.changes.push(JsonChange::Modified {
+                path: path.clone(),
+                old: old.clone(),
+                new: new.clone(),
+            }),
+        }
+    }
+
+    fn arrays_by_index(&mut self, old: &[JsonValue], new: &[JsonValue], path: &JsonPointer) {
+        for (i, (a, b)) in old.iter().zip(new).enumerate() {
+            self.values(a, b, &path.join(i.to_string()));
+        }
+        for (i, value) in old.iter().enumerate().skip(new.len()) {
+            self.removed(path.join(i.to_string()), value);
+        }
+        for (i, value) in new.iter().enumerate().skip(old.len()) {
+            self.added(path.join(i.to_string()), value);
+        }
+    }
+
+    fn arrays_by_lcs(&mut self, old: &[JsonValue], new: &[JsonValue], path: &JsonPointer) {
+        let Some(edits) = align(old.len(), new.len(), |i, j| self.equal(&old[i], &new[j])) else {
+            return self.arrays_by_index(old, new, path);
+        };
+
+        let (mut i, mut j) = (0, 0);
+        for edit in edits {
+            match edit {
+                Edit::Keep => {}
+                Edit::Change => self.values(&old[i], &new[j], &path.join(i.to_string())),
+                Edit::Insert => self.added(path.join(j.to_string()), &new[j]),
+                Edit::Delete => self.removed(path.join(i.to_string()), &old[i]),
+            }
+            i += usize::from(edit != Edit::Insert);
+            j += usize::from(edit != Edit::Delete);
+        }
+    }
+
+    /// Diffs arrays whose elements are matched by `key`. Returns `false` without reporting
+    /// anything if some element has no unique value for `key`.
+    fn arrays_by_key(
+        &mut self,
+        old: &[JsonValue],
+        new: &[JsonValue],
+        key: &str,
+        path: &JsonPointer,
+    ) -> bool {
+        let (Some(old_ids), Some(new_ids)) = (element_ids(old, key), element_ids(new, key)) else {
+            return false;
+        };
+        let new_positions: HashMap<&String, usize> =
+            new_ids.iter().enumerate().map(|(j, id)| (id, j)).collect();
+        let old_positions: HashMap<&String, usize> =
+            old_ids.iter().enumerate().map(|(i, id)| (id, i)).collect();
+
+        for (i, id) in old_ids.iter().enumerate() {
+            match new_positions.get(id) {
+                Some(&j) => self.values(&old[i], &new[j], &path.join(i.to_string())),
+                None => self.removed(path.join(i.to_string()), &old[i]),
+            }
+        }
+        for (j, id) in new_ids.iter().enumerate() {
+            if !old_positions.contains_key(id) {
+                self.added(path.join(j.to_string()), &new[j]);
+            }
+        }
+        true
+    }
+
+    /// Deep equality in which numbers may differ by up to the configured tolerance.
+    fn equal(&self, a: &JsonValue, b: &JsonValue) -> bool {
+        match (a, b) {
+            (JsonValue::Number(x), JsonValue::Number(y)) => {
+                x == y || (x - y).abs() <= self.options.numeric_tolerance
+            }
+            (JsonValue::Array(xs), JsonValue::Array(ys)) => {
+                xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| self.equal(x, y))
+            }
+            (JsonValue::Object(xs), JsonValue::Object(ys)) => {
+                xs.len() == ys.len()
+                    && xs
+                        .iter()
+                        .all(|(key, x)| ys.get(key).is_some_and(|y| self.equal(x, y)))
+            }
+            _ => a == b,
+        }
+    }
+
+    fn added(&mut self, path: JsonPointer, value: &JsonValue) {
+        self.changes.push(JsonChange::Added {
+            path,
+            value: value.clone(),
+        });
+    }
+
+    fn removed(&mut self, path: JsonPointer, value: &JsonValue) {
+        self.changes.push(JsonChange::Removed {
+            path,
+            value: value.clone(),
+        });
+    }
+}
+
+/// Returns the serialized `key` member of every element, or `None` if some element is not
+/// an object with that member or two elements share the same value.
+fn element_ids(items: &[JsonValue], key: &str) -> Option<Vec<String>> {
+    let mut ids = Vec::with_capacity(items.len());
+    for item in items {
+        let JsonValue::Object(map) = item else {
+            return None;
+        };
+        ids.push(serialize_json(map.get(key)?));
+    }
+
+    let mut sorted: Vec<_> = ids.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+    (sorted.len() == ids.len()).then_some(ids)
+}
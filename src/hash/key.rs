@@ -0,0 +1,154 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use super::stable::{encode, normalized_bits};
+use crate::model::JsonValue;
+
+/// A `JsonValue` with `Eq`, `Hash` and `Ord`, so documents can be used as map keys,
+/// deduplicated and sorted deterministically.
+///
+/// `JsonValue` itself only has `PartialEq`, since its numbers are `f64`s. A `JsonKey`
+/// normalizes its numbers when it is created: `-0.0` becomes `0.0` and every `NaN` the
+/// same `NaN`, which equals itself. Objects compare equal regardless of member order.
+///
+/// Values are ordered by type first (`null` < booleans < numbers < strings < arrays <
+/// objects), then by content: numbers numerically (`NaN` last), strings by code point,
+/// arrays element by element, and objects by their members in key order.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::{BTreeSet, HashSet};
+/// use synson::hash::JsonKey;
+/// use synson::json;
+///
+/// let docs = [json!({"a": 1, "b": [0.0]}), json!({"b": [-0.0], "a": 1.0}), json!(f64::NAN)];
+/// let unique: HashSet<JsonKey> = docs.iter().cloned().map(JsonKey::new).collect();
+/// assert_eq!(unique.len(), 2);
+///
+/// let sorted: BTreeSet<JsonKey> =
+///     [json!("b"), json!(2), json!(null), json!("a")].into_iter().map(JsonKey::from).collect();
+/// let sorted: Vec<_> = sorted.iter().map(|key| key.to_string()).collect();
+/// assert_eq!(sorted, ["null", "2", "\"a\"", "\"b\""]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonKey(JsonValue);
+
+impl JsonKey {
+    /// Wraps a value, normalizing its numbers.
+    pub fn new(mut value: JsonValue) -> Self {
+        normalize(&mut value);
+        JsonKey(value)
+    }
+
+    /// Returns the normalized value.
+    pub fn get(&self) -> &JsonValue {
+        &self.0
+    }
+
+    /// Unwraps the normalized value.
+    pub fn into_inner(self) -> JsonValue {
+        self.0
+    }
+}
+
+fn normalize(value: &mut JsonValue) {
+    match value {
+        JsonValue::Number(n) => *n = f64::from_bits(normalized_bits(*n)),
+        JsonValue::Array(items) => items.iter_mut().for_each(normalize),
+        JsonValue::Object(map) => map.values_mut().for_each(normalize),
+        _ => {}
+    }
+}
+
+/// Orders two normalized values as documented on [`JsonKey`].
+fn compare(a: &JsonValue, b: &JsonValue) -> Ordering {
+    match (a, b) {
+        (JsonValue::Null, JsonValue::Null) => Ordering::Equal,
+        (JsonValue::Bool(a), JsonValue::Bool(b)) => a.cmp(b),
+        (JsonValue::Number(a), JsonValue::Number(b)) => a.total_cmp(b),
+        (JsonValue::String(a), JsonValue::String(b)) => a.cmp(b),
+        (JsonValue::Array(a), JsonValue::Array(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| compare(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (JsonValue::Object(a), JsonValue::Object(b)) => {
+            let mut a: Vec<_> = a.iter().collect();
+            let mut b: Vec<_> = b.iter().collect();
+            a.sort_by(|x, y| x.0.cmp(y.0));
+            b.sort_by(|x, y| x.0.cmp(y.0));
+            a.iter()
+                .zip(&b)
+                .map(|((ka, va), (kb, vb))| ka.cmp(kb).then_with(|| compare(va, vb)))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+fn rank(value: &JsonValue) -> u8 {
+    match value {
+        JsonValue::Null => 0,
+        JsonValue::Bool(_) => 1,
+        JsonValue::Number(_) => 2,
+        JsonValue::String(_) => 3,
+        JsonValue::Array(_) => 4,
+        JsonValue::Object(_) => 5,
+    }
+}
+
+impl PartialEq for JsonKey {
+    fn eq(&self, other: &Self) -> bool {
+        compare(&self.0, &other.0).is_eq()
+    }
+}
+
+impl Eq for JsonKey {}
+
+impl PartialOrd for JsonKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&self.0, &other.0)
+    }
+}
+
+impl Hash for JsonKey {
+    /// Hashes the [stable encoding](super::write_stable_bytes) of the value.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        encode(&self.0, &mut |bytes| state.write(bytes));
+    }
+}
+
+impl From<JsonValue> for JsonKey {
+    fn from(value: JsonValue) -> Self {
+        JsonKey::new(value)
+    }
+}
+
+impl From<JsonKey> for JsonValue {
+    fn from(key: JsonKey) -> Self {
+        key.0
+    }
+}
+
+impl AsRef<JsonValue> for JsonKey {
+    fn as_ref(&self) -> &JsonValue {
+        &self.0
+    }
+}
+
+impl fmt::Display for JsonKey {
+    /// Formats the normalized value as compact JSON.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
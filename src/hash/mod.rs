@@ -0,0 +1,8 @@
+//! Stable structural hashing of [`JsonValue`](crate::JsonValue)s and the [`JsonKey`]
+//! wrapper with `Eq`, `Hash` and `Ord`.
+
+pub mod key;
+pub mod stable;
+
+pub use key::JsonKey;
+pub use stable::{stable_bytes, stable_hash, write_stable_bytes};
@@ -0,0 +1,153 @@
+use std::io::{self, Write};
+
+use crate::model::JsonValue;
+
+/// The bit pattern every `NaN` is hashed as.
+const CANONICAL_NAN: u64 = 0x7ff8_0000_0000_0000;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Returns the bits a number is hashed and compared as: `-0.0` is `0.0` and every
+/// `NaN` is the same `NaN`.
+pub(crate) fn normalized_bits(n: f64) -> u64 {
+    if n.is_nan() {
+        CANONICAL_NAN
+    } else if n == 0.0 {
+        0
+    } else {
+        n.to_bits()
+    }
+}
+
+/// Feeds the stable encoding of `value` to `sink`, in chunks.
+///
+/// The encoding is a one-byte tag per value followed by its content: `0` for `null`,
+/// `1` / `2` for `false` / `true`, `3` and the 8 big-endian bytes of the normalized
+/// number, `4` and the length-prefixed UTF-8 bytes of a string, `5` and the element
+/// count followed by the elements of an array, and `6` and the member count followed by
+/// the length-prefixed key and the value of each member, in key byte order. Lengths and
+/// counts are 8-byte big-endian integers.
+pub(crate) fn encode(value: &JsonValue, sink: &mut impl FnMut(&[u8])) {
+    match value {
+        JsonValue::Null => sink(&[0]),
+        JsonValue::Bool(false) => sink(&[1]),
+        JsonValue::Bool(true) => sink(&[2]),
+        JsonValue::Number(n) => {
+            sink(&[3]);
+            sink(&normalized_bits(*n).to_be_bytes());
+        }
+        JsonValue::String(s) => {
+            sink(&[4]);
+            encode_str(s, sink);
+        }
+        JsonValue::Array(items) => {
+            sink(&[5]);
+            sink(&(items.len() as u64).to_be_bytes());
+            for item in items {
+                encode(item, sink);
+            }
+        }
+        JsonValue::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            sink(&[6]);
+            sink(&(entries.len() as u64).to_be_bytes());
+            for (key, item) in entries {
+                encode_str(key, sink);
+                encode(item, sink);
+            }
+        }
+    }
+}
+
+fn encode_str(s: &str, sink: &mut impl FnMut(&[u8])) {
+    sink(&(s.len() as u64).to_be_bytes());
+    sink(s.as_bytes());
+}
+
+/// Writes the stable binary encoding of a `JsonValue`, e.g. into a cryptographic digest.
+///
+/// Two values have the same encoding exactly when they are structurally equal: object
+/// member order does not matter, `-0.0` and `0.0` are the same number, all `NaN`s are
+/// equal, and, since numbers are `f64`s, `1` and `1.0` are the same number. The encoding
+/// is unambiguous (every value is tagged and every string and container is
+/// length-prefixed) and does not depend on the platform or the Rust version, so digests
+/// of it can be stored and compared across processes.
+///
+/// # Arguments
+///
+/// * `value` - The value to encode.
+/// * `writer` - The destination, e.g. a hasher implementing `io::Write`.
+///
+/// # Returns
+///
+/// * `Ok(())` once the whole encoding has been written.
+/// * `Err(io::Error)` if the writer fails.
+///
+/// # Examples
+///
+/// ```
+/// use synson::hash::write_stable_bytes;
+/// use synson::json;
+///
+/// let mut bytes = Vec::new();
+/// write_stable_bytes(&json!([true, "a"]), &mut bytes).unwrap();
+/// assert_eq!(bytes, [5, 0, 0, 0, 0, 0, 0, 0, 2, 2, 4, 0, 0, 0, 0, 0, 0, 0, 1, b'a']);
+/// ```
+pub fn write_stable_bytes<W: Write + ?Sized>(value: &JsonValue, writer: &mut W) -> io::Result<()> {
+    let mut result = Ok(());
+    encode(value, &mut |bytes| {
+        if result.is_ok() {
+            result = writer.write_all(bytes);
+        }
+    });
+    result
+}
+
+/// Returns the stable binary encoding of a `JsonValue`; see [`write_stable_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// use synson::hash::stable_bytes;
+/// use synson::json;
+///
+/// assert_eq!(stable_bytes(&json!({"a": 1, "b": 2})), stable_bytes(&json!({"b": 2.0, "a": 1})));
+/// assert_eq!(stable_bytes(&json!(-0.0)), stable_bytes(&json!(0)));
+/// ```
+pub fn stable_bytes(value: &JsonValue) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    encode(value, &mut |chunk| bytes.extend_from_slice(chunk));
+    bytes
+}
+
+/// Returns a stable 64-bit hash of a `JsonValue`: the 64-bit FNV-1a hash of its
+/// [stable encoding](write_stable_bytes).
+///
+/// Structurally equal values have the same hash, and the hash never changes between
+/// runs, platforms or versions, so it can serve as a cache or deduplication key. It is
+/// not collision-resistant; feed [`write_stable_bytes`] to a cryptographic digest where
+/// that matters.
+///
+/// # Examples
+///
+/// ```
+/// use synson::hash::stable_hash;
+/// use synson::json;
+///
+/// assert_eq!(stable_hash(&json!(null)), 0xaf63bd4c8601b7df);
+/// assert_eq!(stable_hash(&json!({"x": [1, 2]})), stable_hash(&json!({"x": [1.0, 2.0]})));
+/// assert_ne!(stable_hash(&json!([1, 2])), stable_hash(&json!([2, 1])));
+/// ```
+pub fn stable_hash(value: &JsonValue) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    encode(value, &mut |bytes| {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    });
+    hash
+}
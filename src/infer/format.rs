@@ -0,0 +1,91 @@
+use crate::model::StringFormat;
+
+/// Returns the format of `s`, if it has one of the recognized formats.
+pub(crate) fn detect(s: &str) -> Option<StringFormat> {
+    let bytes = s.as_bytes();
+    if is_date_time(bytes) {
+        Some(StringFormat::DateTime)
+    } else if is_date(bytes) {
+        Some(StringFormat::Date)
+    } else if is_uuid(bytes) {
+        Some(StringFormat::Uuid)
+    } else if is_email(s) {
+        Some(StringFormat::Email)
+    } else {
+        None
+    }
+}
+
+/// `full-date` of RFC 3339: `YYYY-MM-DD`.
+fn is_date(b: &[u8]) -> bool {
+    b.len() == 10
+        && digits(&b[0..4])
+        && b[4] == b'-'
+        && in_range(&b[5..7], 1, 12)
+        && b[7] == b'-'
+        && in_range(&b[8..10], 1, 31)
+}
+
+/// `date-time` of RFC 3339: a full date, `T`, `HH:MM:SS`, optional fractional seconds
+/// and `Z` or a `+HH:MM` / `-HH:MM` offset.
+fn is_date_time(b: &[u8]) -> bool {
+    if b.len() < 20 || !is_date(&b[..10]) || !matches!(b[10], b'T' | b't') {
+        return false;
+    }
+    let time = &b[11..];
+    if !(in_range(&time[0..2], 0, 23)
+        && time[2] == b':'
+        && in_range(&time[3..5], 0, 59)
+        && time[5] == b':'
+        && in_range(&time[6..8], 0, 60))
+    {
+        return false;
+    }
+
+    let mut rest = &time[8..];
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+        if len == 0 {
+            return false;
+        }
+        rest = &fraction[len..];
+    }
+    match rest {
+        [b'Z' | b'z'] => true,
+        [b'+' | b'-', hours @ .., b':', _, _] if hours.len() == 2 => {
+            in_range(hours, 0, 23) && in_range(&rest[4..6], 0, 59)
+        }
+        _ => false,
+    }
+}
+
+/// A UUID in its hyphenated form: `8-4-4-4-12` hexadecimal digits.
+fn is_uuid(b: &[u8]) -> bool {
+    b.len() == 36
+        && b.iter().enumerate().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => *c == b'-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// A conservative e-mail check: a non-empty local part, one `@` and a dotted domain,
+/// with no whitespace.
+fn is_email(s: &str) -> bool {
+    let Some((local, domain)) = s.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && !s.chars().any(char::is_whitespace)
+        && domain.split('.').all(|label| !label.is_empty())
+        && domain.contains('.')
+}
+
+fn digits(b: &[u8]) -> bool {
+    b.iter().all(u8::is_ascii_digit)
+}
+
+/// Returns `true` if the two digits in `b` form a number within `min..=max`.
+fn in_range(b: &[u8], min: u8, max: u8) -> bool {
+    b.len() == 2 && digits(b) && (min..=max).contains(&((b[0] - b'0') * 10 + (b[1] - b'0')))
+}
@@ -0,0 +1,8 @@
+//! Shape and JSON Schema inference from sample [`JsonValue`](crate::JsonValue) documents.
+
+mod format;
+pub mod schema;
+pub mod shape;
+
+pub use schema::infer_schema;
+pub use shape::infer_shape;
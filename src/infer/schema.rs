@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+
+use super::shape::infer_shape;
+use crate::model::{JsonSchemaError, JsonShape, JsonValue, ShapeField, ShapeType, StringFormat};
+use crate::pointer::JsonPointer;
+use crate::schema::{collect_anchors, percent_decode, JsonSchema};
+
+/// The dialect declared by [`infer_schema`].
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl JsonShape {
+    /// Converts the shape to a JSON Schema (draft 2020-12).
+    ///
+    /// Every kind of the union contributes its name to `type` and its own keywords:
+    /// `format` for strings, `items` for arrays, and `properties` and `required` for
+    /// objects. An unknown shape becomes the empty schema, which allows anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::{json, serialize_json, JsonShape};
+    ///
+    /// let shape = JsonShape::of(&json!({"id": "123e4567-e89b-12d3-a456-426614174000"}));
+    /// assert_eq!(
+    ///     serialize_json(&shape.to_schema()),
+    ///     r#"{"properties":{"id":{"format":"uuid","type":"string"}},"required":["id"],"type":"object"}"#
+    /// );
+    /// ```
+    pub fn to_schema(&self) -> JsonValue {
+        let mut schema = HashMap::new();
+
+        let mut types: Vec<JsonValue> = self
+            .types
+            .iter()
+            .map(|kind| JsonValue::String(type_name(kind).to_string()))
+            .collect();
+        if self.nullable {
+            types.push(JsonValue::String("null".to_string()));
+        }
+        match types.len() {
+            0 => {}
+            1 => {
+                schema.insert("type".to_string(), types.remove(0));
+            }
+            _ => {
+                schema.insert("type".to_string(), JsonValue::Array(types));
+            }
+        }
+
+        for kind in &self.types {
+            match kind {
+                ShapeType::String(Some(format)) => {
+                    schema.insert(
+                        "format".to_string(),
+                        JsonValue::String(format.name().to_string()),
+                    );
+                }
+                ShapeType::Array(items) if !items.types.is_empty() || items.nullable => {
+                    schema.insert("items".to_string(), items.to_schema());
+                }
+                ShapeType::Object(fields) => {
+                    let properties = fields
+                        .iter()
+                        .map(|field| (field.name.clone(), field.shape.to_schema()))
+                        .collect();
+                    schema.insert("properties".to_string(), JsonValue::Object(properties));
+
+                    let required: Vec<_> = fields
+                        .iter()
+                        .filter(|field| field.required)
+                        .map(|field| JsonValue::String(field.name.clone()))
+                        .collect();
+                    if !required.is_empty() {
+                        schema.insert("required".to_string(), JsonValue::Array(required));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        JsonValue::Object(schema)
+    }
+
+    /// Reads the shape described by a JSON Schema, e.g. to generate code from a
+    /// published schema rather than from samples.
+    ///
+    /// `type`, `format`, `items`, `properties`, `required`, `enum`, `const` and local
+    /// `$ref`s are understood. The subschemas of `anyOf` and `oneOf` are unified like
+    /// samples, while those of `allOf`, a `$ref` and its sibling keywords all describe the
+    /// same value: their object members are combined, and required if any of them
+    /// requires it. Other keywords are ignored, and a recursive reference becomes an
+    /// unknown shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema document.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(JsonShape)` describing the values the schema allows.
+    /// * `Err(JsonSchemaError)` if the schema is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::{json, JsonShape};
+    ///
+    /// let schema = json!({
+    ///     "type": "object",
+    ///     "properties": {
+    ///         "id": {"type": "string", "format": "uuid"},
+    ///         "score": {"type": ["number", "null"]}
+    ///     },
+    ///     "required": ["id"]
+    /// });
+    /// let shape = JsonShape::from_schema(&schema).unwrap();
+    /// assert_eq!(shape.to_string(), "{id: uuid, score?: number | null}");
+    /// ```
+    pub fn from_schema(schema: &JsonValue) -> Result<JsonShape, JsonSchemaError> {
+        JsonSchema::compile(schema)?;
+
+        let mut anchors = HashMap::new();
+        collect_anchors(schema, &JsonPointer::root(), &mut anchors);
+        let mut reader = SchemaReader {
+            root: schema,
+            anchors,
+            refs: Vec::new(),
+        };
+        Ok(reader.shape(schema))
+    }
+}
+
+/// Walks a schema that [`JsonSchema::compile`] accepted.
+struct SchemaReader<'a> {
+    root: &'a JsonValue,
+    anchors: HashMap<String, JsonPointer>,
+    /// The references being read, to stop at recursive ones.
+    refs: Vec<String>,
+}
+
+impl SchemaReader<'_> {
+    fn shape(&mut self, schema: &JsonValue) -> JsonShape {
+        let JsonValue::Object(map) = schema else {
+            return JsonShape::default();
+        };
+        // Each keyword constrains the same value, so their shapes are intersected.
+        let mut constraints = Vec::new();
+
+        if let Some(JsonValue::String(reference)) = map.get("$ref") {
+            constraints.push(self.reference(reference));
+        }
+        if let Some(value) = map.get("const") {
+            constraints.push(JsonShape::of(value));
+        }
+        if let Some(JsonValue::Array(values)) = map.get("enum") {
+            constraints.push(infer_shape(values));
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(JsonValue::Array(subschemas)) = map.get(keyword) {
+                let mut union = JsonShape::default();
+                for subschema in subschemas {
+                    union.merge(self.shape(subschema));
+                }
+                constraints.push(union);
+            }
+        }
+        if let Some(JsonValue::Array(subschemas)) = map.get("allOf") {
+            for subschema in subschemas {
+                constraints.push(self.shape(subschema));
+            }
+        }
+
+        let names: Vec<&str> = match map.get("type") {
+            Some(JsonValue::String(name)) => vec![name],
+            Some(JsonValue::Array(names)) => names.iter().filter_map(JsonValue::as_str).collect(),
+            // Without `type`, the type-specific keywords still imply one.
+            _ => {
+                let mut implied = Vec::new();
+                if map.contains_key("items") || map.contains_key("prefixItems") {
+                    implied.push("array");
+                }
+                if map.contains_key("properties") || map.contains_key("required") {
+                    implied.push("object");
+                }
+                implied
+            }
+        };
+        let mut typed = JsonShape::default();
+        for name in &names {
+            let kind = match *name {
+                "null" => {
+                    typed.nullable = true;
+                    continue;
+                }
+                "boolean" => ShapeType::Boolean,
+                "integer" => ShapeType::Integer,
+                "number" => ShapeType::Number,
+                "string" => ShapeType::String(
+                    map.get("format")
+                        .and_then(JsonValue::as_str)
+                        .and_then(StringFormat::from_name),
+                ),
+                "array" => ShapeType::Array(Box::new(match map.get("items") {
+                    Some(items) => self.shape(items),
+                    None => JsonShape::default(),
+                })),
+                _ => ShapeType::Object(self.fields(map)),
+            };
+            typed.merge(JsonShape {
+                types: vec![kind],
+                nullable: false,
+            });
+        }
+        if !names.is_empty() {
+            constraints.push(typed);
+        }
+
+        constraints
+            .into_iter()
+            .reduce(intersect)
+            .unwrap_or_default()
+    }
+
+    /// Reads `properties` and `required`; required members without a property schema
+    /// have an unknown shape.
+    fn fields(&mut self, map: &HashMap<String, JsonValue>) -> Vec<ShapeField> {
+        let required: Vec<&str> = match map.get("required") {
+            Some(JsonValue::Array(names)) => names.iter().filter_map(JsonValue::as_str).collect(),
+            _ => Vec::new(),
+        };
+        let properties = map.get("properties").and_then(JsonValue::as_object);
+        let mut fields: Vec<_> = properties
+            .into_iter()
+            .flatten()
+            .map(|(name, schema)| ShapeField {
+                name: name.clone(),
+                shape: self.shape(schema),
+                required: required.contains(&name.as_str()),
+            })
+            .collect();
+        for name in required {
+            if !fields.iter().any(|field| field.name == name) {
+                fields.push(ShapeField {
+                    name: name.to_string(),
+                    shape: JsonShape::default(),
+                    required: true,
+                });
+            }
+        }
+        fields.sort_by(|a, b| a.name.cmp(&b.name));
+        fields
+    }
+
+    fn reference(&mut self, reference: &str) -> JsonShape {
+        if self.refs.iter().any(|r| r == reference) {
+            return JsonShape::default();
+        }
+        let fragment = reference
+            .strip_prefix('#')
+            .and_then(percent_decode)
+            .unwrap_or_default();
+        let target = if fragment.is_empty() || fragment.starts_with('/') {
+            JsonPointer::parse(&fragment).ok()
+        } else {
+            self.anchors.get(&fragment).cloned()
+        };
+        let root = self.root;
+        let Some(schema) = target.and_then(|pointer| pointer.resolve(root).ok()) else {
+            return JsonShape::default();
+        };
+
+        self.refs.push(reference.to_string());
+        let shape = self.shape(schema);
+        self.refs.pop();
+        shape
+    }
+}
+
+/// Narrows `shape` to the values also described by `other`, the opposite of
+/// [`JsonShape::merge`]: an unknown shape allows anything, integers are numbers, strings
+/// keep either format, and object members from both sides are kept, required if either
+/// side requires them. Kinds only one side allows are dropped.
+fn intersect(shape: JsonShape, other: JsonShape) -> JsonShape {
+    let unknown = |shape: &JsonShape| shape.types.is_empty() && !shape.nullable;
+    if unknown(&shape) {
+        return other;
+    }
+    if unknown(&other) {
+        return shape;
+    }
+
+    let mut result = JsonShape {
+        types: Vec::new(),
+        nullable: shape.nullable && other.nullable,
+    };
+    for kind in shape.types {
+        let kind = other.types.iter().find_map(|other| match (&kind, other) {
+            (ShapeType::Boolean, ShapeType::Boolean) => Some(ShapeType::Boolean),
+            (ShapeType::Integer | ShapeType::Number, ShapeType::Integer)
+            | (ShapeType::Integer, ShapeType::Number) => Some(ShapeType::Integer),
+            (ShapeType::Number, ShapeType::Number) => Some(ShapeType::Number),
+            (ShapeType::String(format), ShapeType::String(other)) => {
+                Some(ShapeType::String(format.or(*other)))
+            }
+            (ShapeType::Array(items), ShapeType::Array(other)) => Some(ShapeType::Array(Box::new(
+                intersect((**items).clone(), (**other).clone()),
+            ))),
+            (ShapeType::Object(fields), ShapeType::Object(other)) => {
+                Some(ShapeType::Object(intersect_fields(fields, other)))
+            }
+            _ => None,
+        });
+        result.types.extend(kind);
+    }
+    result
+}
+
+fn intersect_fields(fields: &[ShapeField], other: &[ShapeField]) -> Vec<ShapeField> {
+    let mut result = fields.to_vec();
+    for field in other {
+        match result.iter_mut().find(|f| f.name == field.name) {
+            Some(existing) => {
+                existing.required |= field.required;
+                existing.shape =
+                    intersect(std::mem::take(&mut existing.shape), field.shape.clone());
+            }
+            None => result.push(field.clone()),
+        }
+    }
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+fn type_name(kind: &ShapeType) -> &'static str {
+    match kind {
+        ShapeType::Boolean => "boolean",
+        ShapeType::Integer => "integer",
+        ShapeType::Number => "number",
+        ShapeType::String(_) => "string",
+        ShapeType::Array(_) => "array",
+        ShapeType::Object(_) => "object",
+    }
+}
+
+/// Infers a JSON Schema (draft 2020-12) describing a set of sample documents.
+///
+/// This is [`infer_shape`] followed by [`JsonShape::to_schema`], with a `$schema`
+/// member declaring the dialect. Every sample validates against the result.
+///
+/// # Arguments
+///
+/// * `samples` - The documents to describe.
+///
+/// # Returns
+///
+/// The schema, as a `JsonValue` object.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{json, infer_schema, JsonSchema};
+///
+/// let samples = [
+///     json!({"id": 1, "price": 9.5}),
+///     json!({"id": 2, "price": 10, "note": "sale"}),
+/// ];
+/// let schema = infer_schema(&samples);
+/// assert_eq!(schema["properties"]["price"]["type"], json!("number"));
+/// assert_eq!(schema["required"], json!(["id", "price"]));
+///
+/// let schema = JsonSchema::compile(&schema).unwrap();
+/// assert!(samples.iter().all(|sample| schema.is_valid(sample)));
+/// ```
+pub fn infer_schema<'a, I>(samples: I) -> JsonValue
+where
+    I: IntoIterator<Item = &'a JsonValue>,
+{
+    let mut schema = infer_shape(samples).to_schema();
+    if let JsonValue::Object(map) = &mut schema {
+        map.insert(
+            "$schema".to_string(),
+            JsonValue::String(DIALECT.to_string()),
+        );
+    }
+    schema
+}
@@ -0,0 +1,161 @@
+use std::mem;
+
+use super::format::detect;
+use crate::model::{JsonShape, JsonValue, ShapeField, ShapeType};
+
+impl JsonShape {
+    /// Returns the shape of a single value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::{json, JsonShape};
+    ///
+    /// let shape = JsonShape::of(&json!({"id": 1, "at": "2024-05-01T12:30:00Z"}));
+    /// assert_eq!(shape.to_string(), "{at: date-time, id: integer}");
+    /// ```
+    pub fn of(value: &JsonValue) -> Self {
+        let kind = match value {
+            JsonValue::Null => {
+                return JsonShape {
+                    types: Vec::new(),
+                    nullable: true,
+                }
+            }
+            JsonValue::Bool(_) => ShapeType::Boolean,
+            JsonValue::Number(n) if n.is_finite() && n.fract() == 0.0 => ShapeType::Integer,
+            JsonValue::Number(_) => ShapeType::Number,
+            JsonValue::String(s) => ShapeType::String(detect(s)),
+            JsonValue::Array(items) => ShapeType::Array(Box::new(infer_shape(items))),
+            JsonValue::Object(map) => {
+                let mut fields: Vec<_> = map
+                    .iter()
+                    .map(|(name, member)| ShapeField {
+                        name: name.clone(),
+                        shape: JsonShape::of(member),
+                        required: true,
+                    })
+                    .collect();
+                fields.sort_by(|a, b| a.name.cmp(&b.name));
+                ShapeType::Object(fields)
+            }
+        };
+        JsonShape {
+            types: vec![kind],
+            nullable: false,
+        }
+    }
+
+    /// Widens the shape so it also describes the values described by `other`.
+    ///
+    /// Integers and numbers unify to numbers, strings keep a format only if both sides
+    /// have the same one, array element shapes are merged, and object members missing
+    /// from either side become optional. Other kinds are kept side by side as a union.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::{json, JsonShape};
+    ///
+    /// let mut shape = JsonShape::of(&json!({"a": 1, "b": "x"}));
+    /// shape.merge(JsonShape::of(&json!({"a": 1.5, "b": null})));
+    /// shape.merge(JsonShape::of(&json!(true)));
+    /// assert_eq!(shape.to_string(), "boolean | {a: number, b: string | null}");
+    /// ```
+    pub fn merge(&mut self, other: JsonShape) {
+        self.nullable |= other.nullable;
+        for kind in other.types {
+            self.add(kind);
+        }
+    }
+
+    fn add(&mut self, kind: ShapeType) {
+        let Some(existing) = self.types.iter_mut().find(|t| rank(t) == rank(&kind)) else {
+            let at = self.types.partition_point(|t| rank(t) < rank(&kind));
+            self.types.insert(at, kind);
+            return;
+        };
+
+        match (existing, kind) {
+            (existing @ ShapeType::Integer, ShapeType::Number) => *existing = ShapeType::Number,
+            (ShapeType::String(format), ShapeType::String(other)) if *format != other => {
+                *format = None
+            }
+            (ShapeType::Array(items), ShapeType::Array(other)) => items.merge(*other),
+            (ShapeType::Object(fields), ShapeType::Object(other)) => merge_fields(fields, other),
+            _ => {}
+        }
+    }
+}
+
+/// Orders the kinds of a union; integers and numbers are one kind.
+fn rank(kind: &ShapeType) -> u8 {
+    match kind {
+        ShapeType::Boolean => 0,
+        ShapeType::Integer | ShapeType::Number => 1,
+        ShapeType::String(_) => 2,
+        ShapeType::Array(_) => 3,
+        ShapeType::Object(_) => 4,
+    }
+}
+
+fn merge_fields(fields: &mut Vec<ShapeField>, other: Vec<ShapeField>) {
+    for field in fields.iter_mut() {
+        if !other.iter().any(|f| f.name == field.name) {
+            field.required = false;
+        }
+    }
+    for mut field in other {
+        match fields.iter_mut().find(|f| f.name == field.name) {
+            Some(existing) => {
+                existing.required &= field.required;
+                existing.shape.merge(mem::take(&mut field.shape));
+            }
+            None => {
+                field.required = false;
+                fields.push(field);
+            }
+        }
+    }
+    fields.sort_by(|a, b| a.name.cmp(&b.name));
+}
+
+/// Infers the shape shared by a set of sample documents.
+///
+/// Each sample's shape is merged into the result with [`JsonShape::merge`], so object
+/// members absent from some samples are optional, `null`s make a shape nullable and
+/// differing kinds form a union.
+///
+/// # Arguments
+///
+/// * `samples` - The documents to describe.
+///
+/// # Returns
+///
+/// The unified shape; it is unknown (no types, not nullable) if there are no samples.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{json, infer_shape};
+///
+/// let samples = [
+///     json!({"id": 1, "name": "Ada", "tags": ["x"]}),
+///     json!({"id": 2, "name": null, "tags": [], "email": "bob@example.com"}),
+/// ];
+/// let shape = infer_shape(&samples);
+/// assert_eq!(
+///     shape.to_string(),
+///     "{email?: email, id: integer, name: string | null, tags: [string]}"
+/// );
+/// ```
+pub fn infer_shape<'a, I>(samples: I) -> JsonShape
+where
+    I: IntoIterator<Item = &'a JsonValue>,
+{
+    let mut shape = JsonShape::default();
+    for sample in samples {
+        shape.merge(JsonShape::of(sample));
+    }
+    shape
+}
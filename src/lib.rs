@@ -1,7 +1,54 @@
+mod macros;
+
+pub mod codegen;
+pub mod convert;
+pub mod diff;
+pub mod hash;
+pub mod infer;
+pub mod merge;
 pub mod model;
+pub mod number;
 pub mod parser;
+pub mod patch;
+pub mod path;
+pub mod pointer;
+mod regex;
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod serializer;
+pub mod value;
 
-pub use model::{JsonParseError, JsonParseOptions, JsonValue};
+#[cfg(feature = "serde")]
+pub use crate::serde::{from_str, to_string};
+pub use codegen::generate_rust;
+pub use convert::{FromJson, ToJson};
+pub use diff::{diff_json, render_diff};
+pub use hash::{stable_hash, JsonKey};
+pub use infer::{infer_schema, infer_shape};
+pub use merge::deep_merge;
+pub use model::{
+    ArrayDiffStrategy, ArrayMergeStrategy, CodegenDerive, FromJsonError, JsonChange,
+    JsonCodegenOptions, JsonDiffOptions, JsonEvent, JsonMergeConflict, JsonMergeOptions,
+    JsonParseError, JsonParseOptions, JsonPatchError, JsonPointerError, JsonSchemaError,
+    JsonSchemaViolation, JsonSerializeError, JsonShape, JsonValue, NullMergeStrategy, ShapeField,
+    ShapeType, StringFormat,
+};
 pub use parser::{
-    parse_array, parse_bool, parse_json, parse_null, parse_number, parse_object, parse_string,
+    parse_array, parse_bool, parse_json, parse_json_at, parse_json_prefix, parse_many,
+    parse_ndjson, parse_null, parse_number, parse_object, parse_string, JsonPushParser,
+    NdjsonReader, ParseMany,
+};
+pub use patch::{
+    apply_patch, create_merge_patch, create_patch, merge_patch, parse_patch, JsonPatch,
+    PatchOperation,
+};
+pub use path::{query_path, JsonPath, JsonPathMatch, NormalizedPath};
+pub use pointer::JsonPointer;
+pub use schema::{validate_json, JsonSchema};
+pub use serializer::{
+    serialize_canonical, serialize_json, serialize_json_pretty, serialize_ndjson, NdjsonWriter,
 };
+#[cfg(feature = "derive")]
+pub use synson_derive::{FromJson, ToJson};
+pub use value::JsonIndex;
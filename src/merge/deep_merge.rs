@@ -0,0 +1,183 @@
+use crate::model::{
+    ArrayMergeStrategy, JsonMergeConflict, JsonMergeOptions, JsonValue, NullMergeStrategy,
+};
+use crate::pointer::JsonPointer;
+
+/// Merges `overlay` into `base` in place, recursively.
+///
+/// Objects are merged member by member; arrays are combined according to
+/// `options.array_strategy`; any other overlay value replaces the base value. `null`
+/// members of overlay objects either delete the key or are stored, depending on
+/// `options.null_strategy`. The overlay always wins, but every different, non-`null` base
+/// value that gets overwritten is reported as a [`JsonMergeConflict`].
+///
+/// Merging layers in order (defaults, then environment, then user settings) gives each
+/// layer precedence over the previous ones.
+///
+/// # Arguments
+///
+/// * `base` - The document to update.
+/// * `overlay` - The document whose values take precedence.
+/// * `options` - Optional merge options. Defaults to replacing arrays and storing `null`s.
+///
+/// # Returns
+///
+/// The conflicts, in the order they were encountered (object members in key order).
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::merge::deep_merge;
+/// use synson::model::{ArrayMergeStrategy, JsonMergeOptions, NullMergeStrategy};
+///
+/// let mut config = parse_json(r#"{"server": {"host": "localhost", "port": 80}, "plugins": ["auth"], "debug": true}"#, None).unwrap();
+/// let user = parse_json(r#"{"server": {"port": 8080}, "plugins": ["metrics"], "debug": null}"#, None).unwrap();
+///
+/// let options = JsonMergeOptions::new(ArrayMergeStrategy::Append, NullMergeStrategy::Delete);
+/// let conflicts = deep_merge(&mut config, &user, Some(options));
+///
+/// assert_eq!(
+///     config,
+///     parse_json(r#"{"server": {"host": "localhost", "port": 8080}, "plugins": ["auth", "metrics"]}"#, None).unwrap()
+/// );
+/// assert_eq!(conflicts.len(), 1);
+/// assert_eq!(conflicts[0].path.to_string(), "/server/port");
+/// ```
+pub fn deep_merge(
+    base: &mut JsonValue,
+    overlay: &JsonValue,
+    options: Option<JsonMergeOptions>,
+) -> Vec<JsonMergeConflict> {
+    let options = options.unwrap_or_default();
+    let mut merger = Merger {
+        options: &options,
+        arrays: ArrayMerge::new(&options.array_strategy),
+        conflicts: Vec::new(),
+    };
+    merger.merge(base, overlay, &JsonPointer::root());
+    merger.conflicts
+}
+
+struct Merger<'a> {
+    options: &'a JsonMergeOptions,
+    /// How arrays are combined, or `None` when the overlay array replaces the base array.
+    arrays: Option<ArrayMerge<'a>>,
+    conflicts: Vec<JsonMergeConflict>,
+}
+
+/// The [`ArrayMergeStrategy`]s that combine the elements of two arrays.
+#[derive(Clone, Copy)]
+enum ArrayMerge<'a> {
+    Append,
+    ByIndex,
+    ByKey(&'a str),
+}
+
+impl<'a> ArrayMerge<'a> {
+    fn new(strategy: &'a ArrayMergeStrategy) -> Option<Self> {
+        match strategy {
+            ArrayMergeStrategy::Replace => None,
+            ArrayMergeStrategy::Append => Some(ArrayMerge::Append),
+            ArrayMergeStrategy::MergeByIndex => Some(ArrayMerge::ByIndex),
+            ArrayMergeStrategy::MergeByKey(key) => Some(ArrayMerge::ByKey(key)),
+        }
+    }
+}
+
+impl Merger<'_> {
+    fn merge(&mut self, base: &mut JsonValue, overlay: &JsonValue, path: &JsonPointer) {
+        match (base, overlay, self.arrays) {
+            (JsonValue::Object(map), JsonValue::Object(members), _) => {
+                let mut keys: Vec<_> = members.keys().collect();
+                keys.sort();
+
+                for key in keys {
+                    let value = &members[key];
+                    if *value == JsonValue::Null
+                        && self.options.null_strategy == NullMergeStrategy::Delete
+                    {
+                        map.remove(key);
+                        continue;
+                    }
+                    match map.get_mut(key) {
+                        Some(existing) => self.merge(existing, value, &path.join(key.as_str())),
+                        None => {
+                            map.insert(key.clone(), self.fresh(value));
+                        }
+                    }
+                }
+            }
+            (JsonValue::Array(items), JsonValue::Array(overlay_items), Some(arrays)) => {
+                self.merge_arrays(items, overlay_items, arrays, path);
+            }
+            (base, overlay, _) => {
+                if base != overlay {
+                    if *base != JsonValue::Null {
+                        self.conflicts.push(JsonMergeConflict {
+                            path: path.clone(),
+                            base: base.clone(),
+                            overlay: overlay.clone(),
+                        });
+                    }
+                    *base = self.fresh(overlay);
+                }
+            }
+        }
+    }
+
+    fn merge_arrays(
+        &mut self,
+        items: &mut Vec<JsonValue>,
+        overlay_items: &[JsonValue],
+        arrays: ArrayMerge,
+        path: &JsonPointer,
+    ) {
+        match arrays {
+            ArrayMerge::Append => {
+                items.extend(overlay_items.iter().map(|item| self.fresh(item)));
+            }
+            ArrayMerge::ByIndex => {
+                for (i, item) in overlay_items.iter().enumerate() {
+                    match items.get_mut(i) {
+                        Some(existing) => self.merge(existing, item, &path.join(i.to_string())),
+                        None => items.push(self.fresh(item)),
+                    }
+                }
+            }
+            ArrayMerge::ByKey(key) => {
+                for item in overlay_items {
+                    let id = match item {
+                        JsonValue::Object(map) => map.get(key),
+                        _ => None,
+                    };
+                    let position = id.and_then(|id| {
+                        items.iter().position(|existing| match existing {
+                            JsonValue::Object(map) => map.get(key) == Some(id),
+                            _ => false,
+                        })
+                    });
+                    match position {
+                        Some(i) => self.merge(&mut items[i], item, &path.join(i.to_string())),
+                        None => items.push(self.fresh(item)),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Copies an overlay value into the base, dropping `null` members when they mean "delete".
+    fn fresh(&self, value: &JsonValue) -> JsonValue {
+        match value {
+            JsonValue::Object(map) if self.options.null_strategy == NullMergeStrategy::Delete => {
+                JsonValue::Object(
+                    map.iter()
+                        .filter(|(_, v)| **v != JsonValue::Null)
+                        .map(|(k, v)| (k.clone(), self.fresh(v)))
+                        .collect(),
+                )
+            }
+            _ => value.clone(),
+        }
+    }
+}
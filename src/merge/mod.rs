@@ -0,0 +1,3 @@
+pub mod deep_merge;
+
+pub use deep_merge::deep_merge;
@@ -0,0 +1,86 @@
+// src/model/from_json_error.rs
+
+use std::fmt;
+
+use crate::model::JsonValue;
+use crate::pointer::JsonPointer;
+
+/// Represents an error encountered while converting a [`JsonValue`] into a Rust type with
+/// [`FromJson`](crate::convert::FromJson).
+///
+/// `path` locates the offending value within the converted document; containers prefix
+/// it with the key or index of the member that failed, so nested errors point at the
+/// exact value (e.g. `/users/2/age`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FromJsonError {
+    /// The location of the value that could not be converted.
+    pub path: JsonPointer,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl FromJsonError {
+    /// Creates an error located at the converted value itself.
+    pub fn new(message: impl Into<String>) -> Self {
+        FromJsonError {
+            path: JsonPointer::root(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for a value of the wrong JSON type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::JsonValue;
+    /// use synson::model::FromJsonError;
+    ///
+    /// let err = FromJsonError::invalid_type("a string", &JsonValue::Bool(true));
+    /// assert_eq!(err.message, "expected a string, found boolean");
+    /// ```
+    pub fn invalid_type(expected: &str, found: &JsonValue) -> Self {
+        let kind = match found {
+            JsonValue::Null => "null",
+            JsonValue::Bool(_) => "boolean",
+            JsonValue::Number(_) => "number",
+            JsonValue::String(_) => "string",
+            JsonValue::Array(_) => "array",
+            JsonValue::Object(_) => "object",
+        };
+        FromJsonError::new(format!("expected {expected}, found {kind}"))
+    }
+
+    /// Creates an error for a required object member that is absent.
+    pub fn missing_field(name: &str) -> Self {
+        FromJsonError::new(format!("missing field '{name}'"))
+    }
+
+    /// Prefixes the error path with the key or index of the enclosing member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::FromJsonError;
+    ///
+    /// let err = FromJsonError::new("bad").at("2").at("users");
+    /// assert_eq!(err.path.to_string(), "/users/2");
+    /// ```
+    pub fn at(mut self, token: impl Into<String>) -> Self {
+        let tokens = std::iter::once(token.into()).chain(self.path.tokens().iter().cloned());
+        self.path = JsonPointer::from_tokens(tokens);
+        self
+    }
+}
+
+impl fmt::Display for FromJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_root() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} at '{}'", self.message, self.path)
+        }
+    }
+}
+
+impl std::error::Error for FromJsonError {}
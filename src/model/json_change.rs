@@ -0,0 +1,36 @@
+// src/model/json_change.rs
+
+use crate::model::JsonValue;
+use crate::pointer::JsonPointer;
+
+/// A single difference reported by [`diff_json`](crate::diff::diff_json).
+///
+/// Paths of removed and modified values point into the old document; paths of added
+/// values point into the new document. An array element matched in both documents (in
+/// place, by LCS alignment or by key) is named by its index in the old document, also in
+/// the paths of changes nested inside it, so a member added to an element that moved
+/// from index 1 to index 0 is reported under `/1`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonChange {
+    /// A value present only in the new document.
+    Added { path: JsonPointer, value: JsonValue },
+    /// A value present only in the old document.
+    Removed { path: JsonPointer, value: JsonValue },
+    /// A value that differs between the two documents (including a change of type).
+    Modified {
+        path: JsonPointer,
+        old: JsonValue,
+        new: JsonValue,
+    },
+}
+
+impl JsonChange {
+    /// Returns the location of the change.
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            JsonChange::Added { path, .. }
+            | JsonChange::Removed { path, .. }
+            | JsonChange::Modified { path, .. } => path,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// src/model/json_codegen_options.rs
+
+/// Which derives and attributes [`generate_rust`](crate::codegen::generate_rust) emits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CodegenDerive {
+    /// `#[derive(FromJson, ToJson)]` with `#[json(...)]` attributes (the `derive` feature).
+    Synson,
+    /// `#[derive(Serialize, Deserialize)]` with `#[serde(...)]` attributes.
+    Serde,
+}
+
+/// Configuration options for Rust code generation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonCodegenOptions {
+    /// The name of the type generated for the document itself. Nested types are named
+    /// after the member that holds them.
+    pub root_name: String,
+
+    /// The derives and attributes to emit.
+    pub derive: CodegenDerive,
+}
+
+impl JsonCodegenOptions {
+    /// Creates a new instance of `JsonCodegenOptions` with the specified settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `root_name` - The name of the top-level type, e.g. `"Order"`.
+    /// * `derive` - The derives and attributes to emit.
+    ///
+    /// # Returns
+    ///
+    /// A new `JsonCodegenOptions` instance with the specified settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::{CodegenDerive, JsonCodegenOptions};
+    ///
+    /// let options = JsonCodegenOptions::new("Order", CodegenDerive::Serde);
+    /// ```
+    pub fn new(root_name: impl Into<String>, derive: CodegenDerive) -> Self {
+        JsonCodegenOptions {
+            root_name: root_name.into(),
+            derive,
+        }
+    }
+}
+
+impl Default for JsonCodegenOptions {
+    /// Returns a `JsonCodegenOptions` instance with the default settings:
+    /// - `root_name` is `"Root"`.
+    /// - `derive` is [`CodegenDerive::Synson`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::{CodegenDerive, JsonCodegenOptions};
+    ///
+    /// let options = JsonCodegenOptions::default();
+    /// assert_eq!(options.derive, CodegenDerive::Synson);
+    /// ```
+    fn default() -> Self {
+        JsonCodegenOptions {
+            root_name: "Root".to_string(),
+            derive: CodegenDerive::Synson,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// src/model/json_diff_options.rs
+
+/// How [`diff_json`](crate::diff::diff_json) pairs up the elements of two arrays.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrayDiffStrategy {
+    /// Compare elements at the same index; extra elements are added or removed at the end.
+    Index,
+    /// Align elements with a longest common subsequence, so insertions and removals in the
+    /// middle of an array do not show every following element as modified.
+    Lcs,
+    /// Match object elements by the value of the given member (e.g. `"id"`), regardless of
+    /// their position. Falls back to [`Lcs`](ArrayDiffStrategy::Lcs) for arrays in which some
+    /// element is not an object with a unique value for that member.
+    Keyed(String),
+}
+
+/// Configuration options for the structural diff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonDiffOptions {
+    /// The strategy used to compare arrays.
+    pub array_strategy: ArrayDiffStrategy,
+
+    /// Two numbers are considered equal if they differ by at most this amount.
+    pub numeric_tolerance: f64,
+}
+
+impl JsonDiffOptions {
+    /// Creates a new instance of `JsonDiffOptions` with the specified settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `array_strategy` - How array elements are paired up.
+    /// * `numeric_tolerance` - The largest difference between numbers that is not reported.
+    ///
+    /// # Returns
+    ///
+    /// A new `JsonDiffOptions` instance with the specified settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::{ArrayDiffStrategy, JsonDiffOptions};
+    ///
+    /// let options = JsonDiffOptions::new(ArrayDiffStrategy::Keyed("id".to_string()), 1e-9);
+    /// ```
+    pub fn new(array_strategy: ArrayDiffStrategy, numeric_tolerance: f64) -> Self {
+        JsonDiffOptions {
+            array_strategy,
+            numeric_tolerance,
+        }
+    }
+}
+
+impl Default for JsonDiffOptions {
+    /// Returns a `JsonDiffOptions` instance with the default settings:
+    /// - `array_strategy` is [`ArrayDiffStrategy::Lcs`].
+    /// - `numeric_tolerance` is `0.0` (numbers must be exactly equal).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::{ArrayDiffStrategy, JsonDiffOptions};
+    ///
+    /// let options = JsonDiffOptions::default();
+    /// assert_eq!(options.array_strategy, ArrayDiffStrategy::Lcs);
+    /// ```
+    fn default() -> Self {
+        JsonDiffOptions {
+            array_strategy: ArrayDiffStrategy::Lcs,
+            numeric_tolerance: 0.0,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// src/model/json_event.rs
+
+use super::JsonValue;
+
+/// Represents an event emitted by the incremental [`JsonPushParser`](crate::parser::JsonPushParser).
+///
+/// Structural events are emitted as soon as the corresponding token is read, scalar
+/// values once they are fully terminated, and `Document` once the top-level value is
+/// complete.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    /// An object was opened with `{`.
+    StartObject,
+    /// The innermost open object was closed with `}`.
+    EndObject,
+    /// An array was opened with `[`.
+    StartArray,
+    /// The innermost open array was closed with `]`.
+    EndArray,
+    /// An object key was read; the next event belongs to its value.
+    Key(String),
+    /// A scalar value (`null`, boolean, number or string) was read.
+    Value(JsonValue),
+    /// The top-level value is complete.
+    Document(JsonValue),
+}
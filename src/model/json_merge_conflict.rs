@@ -0,0 +1,34 @@
+// src/model/json_merge_conflict.rs
+
+use std::fmt;
+
+use crate::model::JsonValue;
+use crate::pointer::JsonPointer;
+use crate::serializer::serialize_json;
+
+/// A value that [`deep_merge`](crate::merge::deep_merge) overwrote instead of merging.
+///
+/// A conflict is reported whenever the overlay replaces a different, non-`null` base value
+/// that could not be merged with it: a changed scalar, a change of type, or an array
+/// replaced under [`ArrayMergeStrategy::Replace`](crate::model::ArrayMergeStrategy::Replace).
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonMergeConflict {
+    /// The location of the overwritten value.
+    pub path: JsonPointer,
+    /// The value from the base document.
+    pub base: JsonValue,
+    /// The overlay value that replaced it.
+    pub overlay: JsonValue,
+}
+
+impl fmt::Display for JsonMergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Value at '{}' changed from {} to {}",
+            self.path,
+            serialize_json(&self.base),
+            serialize_json(&self.overlay)
+        )
+    }
+}
@@ -0,0 +1,82 @@
+// src/model/json_merge_options.rs
+
+/// How [`deep_merge`](crate::merge::deep_merge) combines an array with an overlay array.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrayMergeStrategy {
+    /// The overlay array replaces the base array.
+    Replace,
+    /// The overlay elements are appended to the base array.
+    Append,
+    /// Elements at the same index are merged recursively; extra overlay elements are appended.
+    MergeByIndex,
+    /// Overlay objects are merged into the base object with the same value for the given
+    /// member (e.g. `"name"`); other overlay elements are appended.
+    MergeByKey(String),
+}
+
+/// How [`deep_merge`](crate::merge::deep_merge) treats `null` values in an overlay object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NullMergeStrategy {
+    /// A `null` member deletes the key from the base object, as in JSON Merge Patch.
+    Delete,
+    /// A `null` member is stored like any other value.
+    Set,
+}
+
+/// Configuration options for deep merging.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonMergeOptions {
+    /// The strategy used when both sides of a merge are arrays.
+    pub array_strategy: ArrayMergeStrategy,
+
+    /// The treatment of `null` members in overlay objects.
+    pub null_strategy: NullMergeStrategy,
+}
+
+impl JsonMergeOptions {
+    /// Creates a new instance of `JsonMergeOptions` with the specified settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `array_strategy` - How arrays are combined.
+    /// * `null_strategy` - Whether `null` members delete keys or are stored.
+    ///
+    /// # Returns
+    ///
+    /// A new `JsonMergeOptions` instance with the specified settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::{ArrayMergeStrategy, JsonMergeOptions, NullMergeStrategy};
+    ///
+    /// let options = JsonMergeOptions::new(ArrayMergeStrategy::Append, NullMergeStrategy::Delete);
+    /// ```
+    pub fn new(array_strategy: ArrayMergeStrategy, null_strategy: NullMergeStrategy) -> Self {
+        JsonMergeOptions {
+            array_strategy,
+            null_strategy,
+        }
+    }
+}
+
+impl Default for JsonMergeOptions {
+    /// Returns a `JsonMergeOptions` instance with the default settings:
+    /// - `array_strategy` is [`ArrayMergeStrategy::Replace`].
+    /// - `null_strategy` is [`NullMergeStrategy::Set`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::{ArrayMergeStrategy, JsonMergeOptions};
+    ///
+    /// let options = JsonMergeOptions::default();
+    /// assert_eq!(options.array_strategy, ArrayMergeStrategy::Replace);
+    /// ```
+    fn default() -> Self {
+        JsonMergeOptions {
+            array_strategy: ArrayMergeStrategy::Replace,
+            null_strategy: NullMergeStrategy::Set,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // src/model/json_parse_error.rs
 
 /// Represents an error encountered while parsing JSON.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct JsonParseError {
     pub message: String,
     pub index: usize,
@@ -38,16 +38,7 @@ impl JsonParseError {
     /// assert_eq!(err.column, 13);
     /// ```
     pub fn new(message: &str, position: usize, input: &str) -> Self {
-        let mut line = 1;
-        let mut column = 1;
-        for (_, c) in input.char_indices().take(position) {
-            if c == '\n' {
-                line += 1;
-                column = 1;
-            } else {
-                column += 1;
-            }
-        }
+        let (line, column) = line_and_column(input, position);
 
         Self {
             message: message.to_string(),
@@ -73,4 +64,39 @@ impl JsonParseError {
     pub fn unmatched(expected: &str, input: &str) -> Self {
         Self::new(&format!("Expected '{expected}' literal"), 0, input)
     }
+
+    /// Re-anchors an error raised while parsing `input[offset..]` onto `input`, so that
+    /// its index, line and column point into the whole of `input`.
+    ///
+    /// Only the first `offset` bytes are scanned, so re-anchoring an error once per
+    /// nesting level stays linear in the size of the input.
+    pub(crate) fn shifted(&self, offset: usize, input: &str) -> Self {
+        let (line, column) = line_and_column(input, offset);
+
+        Self {
+            message: self.message.clone(),
+            index: offset + self.index,
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+        }
+    }
+}
+
+/// Returns the 1-based line and column of the byte `position` in `input`.
+fn line_and_column(input: &str, position: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for (_, c) in input.char_indices().take_while(|(i, _)| *i < position) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
 }
@@ -0,0 +1,64 @@
+// src/model/json_patch_error.rs
+
+use std::fmt;
+
+use super::JsonPointerError;
+
+/// Represents an error encountered while reading or applying a JSON Patch (RFC 6902).
+///
+/// `operation` is the zero-based index of the offending operation in the patch document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPatchError {
+    /// The patch document is not an array of well-formed operation objects.
+    InvalidPatch {
+        operation: Option<usize>,
+        message: String,
+    },
+    /// An operation's `path` or `from` could not be resolved in the target document.
+    Pointer {
+        operation: usize,
+        error: JsonPointerError,
+    },
+    /// A `test` operation found a different value at `path`.
+    TestFailed { operation: usize, path: String },
+    /// A `move` operation tried to move a value into one of its own children.
+    MoveIntoChild {
+        operation: usize,
+        from: String,
+        path: String,
+    },
+}
+
+impl fmt::Display for JsonPatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPatch {
+                operation: Some(operation),
+                message,
+            } => write!(f, "Invalid patch operation #{operation}: {message}"),
+            Self::InvalidPatch {
+                operation: None,
+                message,
+            } => write!(f, "Invalid patch document: {message}"),
+            Self::Pointer { operation, error } => {
+                write!(f, "Patch operation #{operation} failed: {error}")
+            }
+            Self::TestFailed { operation, path } => {
+                write!(
+                    f,
+                    "Patch operation #{operation} failed: test at '{path}' did not match"
+                )
+            }
+            Self::MoveIntoChild {
+                operation,
+                from,
+                path,
+            } => write!(
+                f,
+                "Patch operation #{operation} failed: cannot move '{from}' into its child '{path}'"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for JsonPatchError {}
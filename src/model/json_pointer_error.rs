@@ -0,0 +1,53 @@
+// src/model/json_pointer_error.rs
+
+use std::fmt;
+
+/// Represents an error encountered while parsing or resolving a JSON Pointer (RFC 6901).
+///
+/// Every resolution error carries `path`: the pointer prefix, up to and including the
+/// reference token that could not be resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPointerError {
+    /// The pointer is not empty and does not start with `/`, or contains a `~` that is
+    /// not followed by `0` or `1`.
+    Syntax { pointer: String, message: String },
+    /// An object does not contain the referenced key.
+    KeyNotFound { path: String, key: String },
+    /// An array index is past the end of the array. `-`, which names the position after
+    /// the last element, is reported as index `len` everywhere but in an insertion.
+    IndexOutOfBounds {
+        path: String,
+        index: usize,
+        len: usize,
+    },
+    /// A reference token applied to an array is not a valid index (e.g. `01` or `x`).
+    InvalidIndex { path: String, token: String },
+    /// A reference token was applied to a value that is neither an object nor an array.
+    NotAContainer { path: String },
+    /// The operation cannot target the whole document (e.g. removing the root).
+    RootNotAllowed,
+}
+
+impl fmt::Display for JsonPointerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { pointer, message } => {
+                write!(f, "Invalid JSON pointer '{pointer}': {message}")
+            }
+            Self::KeyNotFound { path, key } => write!(f, "Key '{key}' not found at '{path}'"),
+            Self::IndexOutOfBounds { path, index, len } => write!(
+                f,
+                "Index {index} out of bounds at '{path}' (array length is {len})"
+            ),
+            Self::InvalidIndex { path, token } => {
+                write!(f, "Invalid array index '{token}' at '{path}'")
+            }
+            Self::NotAContainer { path } => {
+                write!(f, "Cannot descend into a scalar value at '{path}'")
+            }
+            Self::RootNotAllowed => write!(f, "Operation cannot target the document root"),
+        }
+    }
+}
+
+impl std::error::Error for JsonPointerError {}
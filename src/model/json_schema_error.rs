@@ -0,0 +1,35 @@
+// src/model/json_schema_error.rs
+
+use std::fmt;
+
+use crate::pointer::JsonPointer;
+
+/// Represents an error found while compiling a JSON Schema with
+/// [`JsonSchema::compile`](crate::schema::JsonSchema::compile).
+///
+/// The schema itself is invalid: a keyword has a value of the wrong type, a `pattern` is
+/// not a supported regular expression, or a `$ref` cannot be resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonSchemaError {
+    /// The location of the offending keyword within the schema document.
+    pub path: JsonPointer,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl JsonSchemaError {
+    pub(crate) fn new(path: JsonPointer, message: impl Into<String>) -> Self {
+        JsonSchemaError {
+            path,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for JsonSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid schema at '{}': {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for JsonSchemaError {}
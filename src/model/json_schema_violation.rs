@@ -0,0 +1,24 @@
+// src/model/json_schema_violation.rs
+
+use std::fmt;
+
+use crate::pointer::JsonPointer;
+
+/// A place where an instance does not satisfy a JSON Schema, as reported by
+/// [`JsonSchema::validate`](crate::schema::JsonSchema::validate).
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonSchemaViolation {
+    /// The location of the offending value within the instance.
+    pub instance_path: JsonPointer,
+    /// The location of the failing keyword within the schema document. Behind a `$ref`,
+    /// this is the location of the keyword in the referenced schema.
+    pub schema_path: JsonPointer,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for JsonSchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at '{}'", self.message, self.instance_path)
+    }
+}
@@ -0,0 +1,33 @@
+// src/model/json_serialize_error.rs
+
+use std::fmt;
+
+/// Represents an error raised while serializing a value with
+/// [`serialize_canonical`](crate::serializer::serialize_canonical) or, with the `serde`
+/// feature, `to_string`.
+///
+/// Serialization only fails when the value has no JSON representation, such as a
+/// non-finite number or a map whose keys are not strings, or when a `Serialize`
+/// implementation reports an error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonSerializeError {
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for JsonSerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for JsonSerializeError {}
+
+#[cfg(feature = "serde")]
+impl serde::ser::Error for JsonSerializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        JsonSerializeError {
+            message: msg.to_string(),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// src/model/json_shape.rs
+
+use std::fmt;
+
+use crate::model::JsonValue;
+use crate::serializer::serialize_json;
+
+/// The shape shared by a set of JSON values, as inferred by
+/// [`infer_shape`](crate::infer::infer_shape).
+///
+/// A shape is a union of at most one [`ShapeType`] per kind of value, plus a `nullable`
+/// flag for `null`. A shape with no types that is not nullable is unknown: nothing was
+/// observed, e.g. for the elements of arrays that were always empty.
+///
+/// `Display` writes a compact description such as
+/// `{id: integer, name?: string | null, tags: [string]}`, where `?` marks optional
+/// members.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct JsonShape {
+    /// The observed kinds of non-null values, in the order of [`ShapeType`]'s variants.
+    pub types: Vec<ShapeType>,
+    /// Whether `null` was observed.
+    pub nullable: bool,
+}
+
+/// One kind of value in a [`JsonShape`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShapeType {
+    Boolean,
+    /// Numbers that were all integral.
+    Integer,
+    /// Numbers, at least one of which had a fractional part.
+    Number,
+    /// Strings, with the format they all had, if any.
+    String(Option<StringFormat>),
+    /// Arrays, with the shape of all their elements.
+    Array(Box<JsonShape>),
+    /// Objects, with their members sorted by name.
+    Object(Vec<ShapeField>),
+}
+
+/// A member of an object [`ShapeType`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapeField {
+    pub name: String,
+    /// The shape of the member's values.
+    pub shape: JsonShape,
+    /// Whether every observed object had the member.
+    pub required: bool,
+}
+
+/// A string format recognized by shape inference, named as in JSON Schema's `format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringFormat {
+    /// An RFC 3339 timestamp, e.g. `2024-05-01T12:30:00Z`.
+    DateTime,
+    /// An RFC 3339 full date, e.g. `2024-05-01`.
+    Date,
+    /// A hyphenated UUID, e.g. `123e4567-e89b-12d3-a456-426614174000`.
+    Uuid,
+    /// An e-mail address.
+    Email,
+}
+
+impl StringFormat {
+    /// Returns the format with the given JSON Schema name, e.g. `"uuid"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "date-time" => StringFormat::DateTime,
+            "date" => StringFormat::Date,
+            "uuid" => StringFormat::Uuid,
+            "email" => StringFormat::Email,
+            _ => return None,
+        })
+    }
+
+    /// Returns the JSON Schema name of the format, e.g. `"date-time"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            StringFormat::DateTime => "date-time",
+            StringFormat::Date => "date",
+            StringFormat::Uuid => "uuid",
+            StringFormat::Email => "email",
+        }
+    }
+}
+
+impl fmt::Display for JsonShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.types.is_empty() {
+            return f.write_str(if self.nullable { "null" } else { "unknown" });
+        }
+        for (i, kind) in self.types.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            write!(f, "{kind}")?;
+        }
+        if self.nullable {
+            f.write_str(" | null")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ShapeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeType::Boolean => f.write_str("boolean"),
+            ShapeType::Integer => f.write_str("integer"),
+            ShapeType::Number => f.write_str("number"),
+            ShapeType::String(None) => f.write_str("string"),
+            ShapeType::String(Some(format)) => f.write_str(format.name()),
+            ShapeType::Array(items) => write!(f, "[{items}]"),
+            ShapeType::Object(fields) => {
+                f.write_str("{")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    if is_identifier(&field.name) {
+                        f.write_str(&field.name)?;
+                    } else {
+                        f.write_str(&serialize_json(&JsonValue::String(field.name.clone())))?;
+                    }
+                    let marker = if field.required { "" } else { "?" };
+                    write!(f, "{marker}: {}", field.shape)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -1,7 +1,33 @@
+pub mod from_json_error;
+pub mod json_change;
+pub mod json_codegen_options;
+pub mod json_diff_options;
+pub mod json_event;
+pub mod json_merge_conflict;
+pub mod json_merge_options;
 pub mod json_parse_error;
 pub mod json_parse_options;
+pub mod json_patch_error;
+pub mod json_pointer_error;
+pub mod json_schema_error;
+pub mod json_schema_violation;
+pub mod json_serialize_error;
+pub mod json_shape;
 pub mod json_value;
 
+pub use from_json_error::FromJsonError;
+pub use json_change::JsonChange;
+pub use json_codegen_options::{CodegenDerive, JsonCodegenOptions};
+pub use json_diff_options::{ArrayDiffStrategy, JsonDiffOptions};
+pub use json_event::JsonEvent;
+pub use json_merge_conflict::JsonMergeConflict;
+pub use json_merge_options::{ArrayMergeStrategy, JsonMergeOptions, NullMergeStrategy};
 pub use json_parse_error::JsonParseError;
 pub use json_parse_options::JsonParseOptions;
+pub use json_patch_error::JsonPatchError;
+pub use json_pointer_error::JsonPointerError;
+pub use json_schema_error::JsonSchemaError;
+pub use json_schema_violation::JsonSchemaViolation;
+pub use json_serialize_error::JsonSerializeError;
+pub use json_shape::{JsonShape, ShapeField, ShapeType, StringFormat};
 pub use json_value::JsonValue;
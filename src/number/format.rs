@@ -0,0 +1,240 @@
+// src/number/format.rs
+use super::table::{POW5_INV_SPLIT, POW5_SPLIT};
+
+/// Formats an `f64` with the fewest significant digits that parse back to the same value.
+///
+/// The digits come from the Ryu algorithm (Adams, "Ryū: fast float-to-string conversion",
+/// 2018): among the shortest decimals that round to `n`, the one closest to its exact
+/// value. They are laid out as ECMAScript's `Number.prototype.toString` does, which is
+/// also how `JSON.stringify` prints numbers: integers below `10^21` are written out in
+/// full (`100`, no `.0`), other numbers between `10^-7` and `10^21` in positional notation
+/// (`0.001`), and the rest with an exponent (`1e+21`, `1.5e-7`). Unlike ECMAScript,
+/// negative zero keeps its sign so that it survives a round trip.
+///
+/// # Arguments
+///
+/// * `n` - The number to format.
+///
+/// # Returns
+///
+/// * The shortest round-trip representation of `n`, or `null` for `NaN` and infinities,
+///   which JSON cannot represent.
+///
+/// # Examples
+///
+/// ```
+/// use synson::number::{format_f64, parse_f64};
+///
+/// assert_eq!(format_f64(100.0), "100");
+/// assert_eq!(format_f64(0.1 + 0.2), "0.30000000000000004");
+/// assert_eq!(format_f64(1e21), "1e+21");
+/// assert_eq!(format_f64(-1.5e-7), "-1.5e-7");
+/// assert_eq!(format_f64(5e-324), "5e-324");
+/// assert_eq!(format_f64(-0.0), "-0");
+/// assert_eq!(format_f64(f64::NAN), "null");
+///
+/// let n = 2.2250738585072014e-308;
+/// assert_eq!(parse_f64(&format_f64(n)), Some(n));
+/// ```
+pub fn format_f64(n: f64) -> String {
+    let mut out = String::new();
+    write_f64(&mut out, n);
+    out
+}
+
+/// Appends [`format_f64`]'s representation of `n` to `out`.
+pub(crate) fn write_f64(out: &mut String, n: f64) {
+    if !n.is_finite() {
+        out.push_str("null");
+        return;
+    }
+    if n.is_sign_negative() {
+        out.push('-');
+    }
+    if n == 0.0 {
+        out.push('0');
+        return;
+    }
+
+    let (mantissa, exponent) = shortest(n.abs());
+    let digits = mantissa.to_string();
+    let k = digits.len() as i32;
+    // The value is 0.DIGITS × 10^point.
+    let point = exponent + k;
+
+    if k <= point && point <= 21 {
+        out.push_str(&digits);
+        out.extend((k..point).map(|_| '0'));
+    } else if 0 < point && point <= 21 {
+        let (int, fraction) = digits.split_at(point as usize);
+        out.push_str(int);
+        out.push('.');
+        out.push_str(fraction);
+    } else if -6 < point && point <= 0 {
+        out.push_str("0.");
+        out.extend((point..0).map(|_| '0'));
+        out.push_str(&digits);
+    } else {
+        let (first, rest) = digits.split_at(1);
+        out.push_str(first);
+        if !rest.is_empty() {
+            out.push('.');
+            out.push_str(rest);
+        }
+        out.push('e');
+        if point > 0 {
+            out.push('+');
+        }
+        out.push_str(&(point - 1).to_string());
+    }
+}
+
+const MANTISSA_BITS: u32 = 52;
+const EXPONENT_BIAS: i32 = 1023;
+const POW5_INV_BITCOUNT: i32 = 125;
+const POW5_BITCOUNT: i32 = 125;
+
+/// Returns the shortest `(digits, exponent)` with `digits × 10^exponent` rounding to the
+/// positive, finite, non-zero `n`, choosing the closest one when there are several.
+fn shortest(n: f64) -> (u64, i32) {
+    let bits = n.to_bits();
+    let ieee_mantissa = bits & ((1 << MANTISSA_BITS) - 1);
+    let ieee_exponent = (bits >> MANTISSA_BITS) as i32;
+
+    // Step 1: n = m2 × 2^e2, with two extra bits for the bounds.
+    let (m2, e2) = if ieee_exponent == 0 {
+        (ieee_mantissa, 1 - EXPONENT_BIAS - MANTISSA_BITS as i32 - 2)
+    } else {
+        (
+            ieee_mantissa | (1 << MANTISSA_BITS),
+            ieee_exponent - EXPONENT_BIAS - MANTISSA_BITS as i32 - 2,
+        )
+    };
+    let accept_bounds = m2 & 1 == 0;
+
+    // Step 2: the interval of decimals that round to n is (mm, mp) × 2^e2, around mv.
+    // The lower gap is half as wide at a power of two.
+    let mv = 4 * m2;
+    let mm_shift = u64::from(ieee_mantissa != 0 || ieee_exponent <= 1);
+
+    // Step 3: convert the interval to a decimal power base.
+    let (mut vr, mut vp, mut vm);
+    let e10;
+    let mut vm_is_trailing_zeros = false;
+    let mut vr_is_trailing_zeros = false;
+    if e2 >= 0 {
+        let q = log10_pow2(e2) - u32::from(e2 > 3);
+        e10 = q as i32;
+        let k = POW5_INV_BITCOUNT + pow5_bits(q as i32) - 1;
+        let i = -e2 + q as i32 + k;
+        let multiplier = POW5_INV_SPLIT[q as usize];
+        vr = mul_shift(4 * m2, multiplier, i);
+        vp = mul_shift(4 * m2 + 2, multiplier, i);
+        vm = mul_shift(4 * m2 - 1 - mm_shift, multiplier, i);
+        if q <= 21 {
+            // Only one of mp, mv and mm can be a multiple of 5, if any.
+            if mv % 5 == 0 {
+                vr_is_trailing_zeros = multiple_of_power_of_5(mv, q);
+            } else if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(mv - 1 - mm_shift, q);
+            } else {
+                vp -= u64::from(multiple_of_power_of_5(mv + 2, q));
+            }
+        }
+    } else {
+        let q = log10_pow5(-e2) - u32::from(-e2 > 1);
+        e10 = q as i32 + e2;
+        let i = -e2 - q as i32;
+        let k = pow5_bits(i) - POW5_BITCOUNT;
+        let j = q as i32 - k;
+        let multiplier = POW5_SPLIT[i as usize];
+        vr = mul_shift(4 * m2, multiplier, j);
+        vp = mul_shift(4 * m2 + 2, multiplier, j);
+        vm = mul_shift(4 * m2 - 1 - mm_shift, multiplier, j);
+        if q <= 1 {
+            // mv = 4 × m2 always has at least two trailing zero bits.
+            vr_is_trailing_zeros = true;
+            if accept_bounds {
+                vm_is_trailing_zeros = mm_shift == 1;
+            } else {
+                vp -= 1;
+            }
+        } else if q < 63 {
+            vr_is_trailing_zeros = mv & ((1 << q) - 1) == 0;
+        }
+    }
+
+    // Step 4: remove digits while the interval still contains a shorter decimal.
+    let mut removed = 0;
+    let mut last_removed_digit = 0;
+    let output = if vm_is_trailing_zeros || vr_is_trailing_zeros {
+        // The general case, when the exact bounds matter (rare).
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm % 10 == 0;
+            vr_is_trailing_zeros &= last_removed_digit == 0;
+            last_removed_digit = vr % 10;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        if vm_is_trailing_zeros {
+            while vm % 10 == 0 {
+                vr_is_trailing_zeros &= last_removed_digit == 0;
+                last_removed_digit = vr % 10;
+                vr /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+        }
+        if vr_is_trailing_zeros && last_removed_digit == 5 && vr % 2 == 0 {
+            // The exact value ends in 5 followed by zeros: round half to even.
+            last_removed_digit = 4;
+        }
+        let round_up =
+            (vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5;
+        vr + u64::from(round_up)
+    } else {
+        let mut round_up = false;
+        while vp / 10 > vm / 10 {
+            round_up = vr % 10 >= 5;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        vr + u64::from(vr == vm || round_up)
+    };
+    (output, e10 + removed)
+}
+
+/// `ceil(log2(5^e))`, or 1 for `e == 0`, for `0 <= e <= 3528`.
+fn pow5_bits(e: i32) -> i32 {
+    ((e as u32 * 1_217_359) >> 19) as i32 + 1
+}
+
+/// `floor(log10(2^e))` for `0 <= e <= 1650`.
+fn log10_pow2(e: i32) -> u32 {
+    (e as u32 * 78_913) >> 18
+}
+
+/// `floor(log10(5^e))` for `0 <= e <= 2620`.
+fn log10_pow5(e: i32) -> u32 {
+    (e as u32 * 732_923) >> 20
+}
+
+fn multiple_of_power_of_5(mut value: u64, p: u32) -> bool {
+    let mut count = 0;
+    while value.is_multiple_of(5) {
+        value /= 5;
+        count += 1;
+    }
+    count >= p
+}
+
+/// `(m × multiplier) >> shift` for a 125-bit `multiplier` given as `(low, high)` words.
+fn mul_shift(m: u64, (low, high): (u64, u64), shift: i32) -> u64 {
+    let b0 = u128::from(m) * u128::from(low);
+    let b2 = u128::from(m) * u128::from(high);
+    (((b0 >> 64) + b2) >> (shift - 64)) as u64
+}
@@ -0,0 +1,9 @@
+//! Conversion between JSON number text and `f64`: correctly rounded parsing
+//! ([`parse_f64`]) and shortest round-trip formatting ([`format_f64`]).
+
+pub mod format;
+pub mod parse;
+mod table;
+
+pub use format::format_f64;
+pub use parse::parse_f64;
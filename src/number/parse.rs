@@ -0,0 +1,281 @@
+// src/number/parse.rs
+use super::table::{LARGEST_POWER_OF_FIVE, POWERS_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
+
+/// Explicit mantissa bits of an `f64`.
+const MANTISSA_BITS: i32 = 52;
+
+/// The biased exponent of infinity.
+const INFINITE_POWER: i32 = 0x7FF;
+
+/// At most this many significant digits fit in the `u64` mantissa (`10^19 < 2^64`).
+const MAX_DIGITS: usize = 19;
+
+/// Parses a JSON number into the nearest `f64`, rounding halfway cases to even.
+///
+/// Numbers with at most 19 significant digits are converted with exact `f64` arithmetic
+/// when both the digits and the power of ten are exactly representable (Clinger's fast
+/// path), and otherwise with the Eisel-Lemire algorithm, which needs a single 128-bit
+/// multiplication in almost all cases. The rare inputs Eisel-Lemire cannot decide (values
+/// extremely close to halfway between two `f64`s, or more than 19 significant digits
+/// that straddle such a point) fall back to the standard library's arbitrary-precision
+/// conversion, so the result is always correctly rounded. Values too large for an `f64`
+/// become infinities and values too small become (signed) zeros.
+///
+/// # Arguments
+///
+/// * `text` - A complete JSON number (`-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`),
+///   without surrounding whitespace.
+///
+/// # Returns
+///
+/// * `Some(value)` - The correctly rounded value of `text`.
+/// * `None` - If `text` is not a JSON number.
+///
+/// # Examples
+///
+/// ```
+/// use synson::number::parse_f64;
+///
+/// assert_eq!(parse_f64("-12.5e1"), Some(-125.0));
+/// assert_eq!(parse_f64("0.1"), Some(0.1));
+/// assert_eq!(parse_f64("2.2250738585072011e-308"), Some(2.225073858507201e-308));
+/// assert_eq!(parse_f64("1e400"), Some(f64::INFINITY));
+/// assert_eq!(parse_f64("-0"), Some(-0.0));
+/// assert_eq!(parse_f64("01"), None);
+/// assert_eq!(parse_f64("1."), None);
+/// ```
+pub fn parse_f64(text: &str) -> Option<f64> {
+    let decimal = Decimal::parse(text.as_bytes())?;
+    let magnitude = decimal
+        .fast_path()
+        .or_else(|| decimal.eisel_lemire())
+        .or_else(|| text.trim_start_matches('-').parse().ok())?;
+    Some(if decimal.negative {
+        -magnitude
+    } else {
+        magnitude
+    })
+}
+
+/// A JSON number as `mantissa × 10^exponent`, keeping the first 19 significant digits.
+struct Decimal {
+    negative: bool,
+    mantissa: u64,
+    exponent: i64,
+    /// Whether non-zero digits were dropped after the first 19, so that the exact value
+    /// lies strictly between `mantissa` and `mantissa + 1` (times `10^exponent`).
+    truncated: bool,
+}
+
+impl Decimal {
+    /// Reads a JSON number, or returns `None` if `bytes` is not exactly one.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        let mut decimal = Decimal {
+            negative: bytes.first() == Some(&b'-'),
+            mantissa: 0,
+            exponent: 0,
+            truncated: false,
+        };
+        let mut i = usize::from(decimal.negative);
+        let mut digits = 0;
+
+        let int_start = i;
+        while let Some(&b @ b'0'..=b'9') = bytes.get(i) {
+            decimal.push_digit(b, &mut digits, false);
+            i += 1;
+        }
+        let int_len = i - int_start;
+        if int_len == 0 || (int_len > 1 && bytes[int_start] == b'0') {
+            return None;
+        }
+
+        if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            let fraction_start = i;
+            while let Some(&b @ b'0'..=b'9') = bytes.get(i) {
+                decimal.push_digit(b, &mut digits, true);
+                i += 1;
+            }
+            if i == fraction_start {
+                return None;
+            }
+        }
+
+        if let Some(b'e' | b'E') = bytes.get(i) {
+            i += 1;
+            let negative = match bytes.get(i) {
+                Some(b'-') => {
+                    i += 1;
+                    true
+                }
+                Some(b'+') => {
+                    i += 1;
+                    false
+                }
+                _ => false,
+            };
+            let exponent_start = i;
+            let mut exponent: i64 = 0;
+            while let Some(&b @ b'0'..=b'9') = bytes.get(i) {
+                // Anything beyond this is zero or infinity whatever the mantissa is.
+                if exponent < 1_000_000 {
+                    exponent = exponent * 10 + i64::from(b - b'0');
+                }
+                i += 1;
+            }
+            if i == exponent_start {
+                return None;
+            }
+            decimal.exponent += if negative { -exponent } else { exponent };
+        }
+
+        (i == bytes.len()).then_some(decimal)
+    }
+
+    /// Adds a digit to the mantissa, or to the exponent once the mantissa is full.
+    fn push_digit(&mut self, byte: u8, digits: &mut usize, in_fraction: bool) {
+        let digit = u64::from(byte - b'0');
+        if self.mantissa == 0 && digit == 0 {
+            // Leading zeros are not significant, but shift the point in a fraction.
+            self.exponent -= i64::from(in_fraction);
+        } else if *digits < MAX_DIGITS {
+            self.mantissa = self.mantissa * 10 + digit;
+            *digits += 1;
+            self.exponent -= i64::from(in_fraction);
+        } else {
+            self.truncated |= digit != 0;
+            self.exponent += i64::from(!in_fraction);
+        }
+    }
+
+    /// Clinger's fast path: when the mantissa and the power of ten are both exact `f64`s,
+    /// one correctly rounded multiplication or division gives the correctly rounded result.
+    fn fast_path(&self) -> Option<f64> {
+        const MAX_EXACT_MANTISSA: u64 = 1 << 53;
+        const POWERS_OF_TEN: [f64; 23] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+            1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+        ];
+
+        if self.truncated || self.mantissa > MAX_EXACT_MANTISSA {
+            return None;
+        }
+        if self.mantissa == 0 {
+            return Some(0.0);
+        }
+        let mut mantissa = self.mantissa;
+        let mut exponent = self.exponent;
+        // "Disguised" fast path: move excess powers of ten into the mantissa when it stays
+        // exact, as in `123e25 = 1230000e22`.
+        while exponent > 22 && mantissa <= MAX_EXACT_MANTISSA / 10 {
+            mantissa *= 10;
+            exponent -= 1;
+        }
+        match exponent {
+            0..=22 => Some(mantissa as f64 * POWERS_OF_TEN[exponent as usize]),
+            -22..=-1 => Some(mantissa as f64 / POWERS_OF_TEN[-exponent as usize]),
+            _ => None,
+        }
+    }
+
+    /// The Eisel-Lemire algorithm, or `None` when it cannot guarantee correct rounding.
+    fn eisel_lemire(&self) -> Option<f64> {
+        let value = compute_float(self.exponent, self.mantissa)?;
+        // The exact value lies between the mantissa and the next integer: both ends must
+        // round to the same float.
+        if self.truncated && compute_float(self.exponent, self.mantissa + 1)? != value {
+            return None;
+        }
+        Some(value)
+    }
+}
+
+/// Computes the `f64` nearest to `w × 10^q` for a non-zero `w`, following Lemire,
+/// "Number Parsing at a Gigabyte per Second" (2021). Returns `None` in the rare cases
+/// where the 128-bit approximation of `5^q` is not precise enough to decide.
+fn compute_float(q: i64, mut w: u64) -> Option<f64> {
+    if w == 0 || q < i64::from(SMALLEST_POWER_OF_FIVE) {
+        return Some(0.0);
+    }
+    if q > i64::from(LARGEST_POWER_OF_FIVE) {
+        return Some(f64::INFINITY);
+    }
+    let q = q as i32;
+
+    // Normalize the mantissa so that its most significant bit is set.
+    let leading_zeros = w.leading_zeros();
+    w <<= leading_zeros;
+
+    let (low, high) = product_approximation(q, w, MANTISSA_BITS + 3);
+    // `low` is exact only when 5^q fits in 128 bits (`-27 <= q <= 55`); otherwise an
+    // all-ones low word might carry into the bits that decide rounding.
+    if low == u64::MAX && !(-27..=55).contains(&q) {
+        return None;
+    }
+
+    let upper_bit = (high >> 63) as i32;
+    let shift = upper_bit + 64 - MANTISSA_BITS - 3;
+    let mut mantissa = high >> shift;
+    let mut power2 = power_of_two(q) + upper_bit - leading_zeros as i32 + 1023;
+
+    if power2 <= 0 {
+        // A subnormal number, or zero.
+        if -power2 + 1 >= 64 {
+            return Some(0.0);
+        }
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        // Rounding up may have made the smallest normal number.
+        let power2 = u64::from(mantissa >= 1 << MANTISSA_BITS);
+        return Some(f64::from_bits(
+            (power2 << MANTISSA_BITS) | (mantissa & MANTISSA_MASK),
+        ));
+    }
+
+    // An exact halfway case rounds to even: this happens only when the product is exact
+    // (small `q`), the bit below the mantissa is set and every bit below that is zero.
+    if low <= 1 && (-4..=23).contains(&q) && mantissa & 3 == 1 && (mantissa << shift) == high {
+        mantissa &= !1;
+    }
+
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    if mantissa >= 2 << MANTISSA_BITS {
+        // Rounding carried into a new bit.
+        mantissa = 1 << MANTISSA_BITS;
+        power2 += 1;
+    }
+    if power2 >= INFINITE_POWER {
+        return Some(f64::INFINITY);
+    }
+    Some(f64::from_bits(
+        ((power2 as u64) << MANTISSA_BITS) | (mantissa & MANTISSA_MASK),
+    ))
+}
+
+const MANTISSA_MASK: u64 = (1 << MANTISSA_BITS) - 1;
+
+/// `floor(log2(10^q)) + 63`, exact for `q` in the table's range.
+fn power_of_two(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65_536) >> 16) + 63
+}
+
+/// Multiplies `w` by the 128-bit approximation of `5^q`, returning the high 128 bits of
+/// the product as `(low, high)`. The second multiplication is skipped when the first
+/// already determines the top `precision` bits.
+fn product_approximation(q: i32, w: u64, precision: i32) -> (u64, u64) {
+    let mask = u64::MAX >> precision;
+    let (high5, low5) = POWERS_OF_FIVE_128[(q - SMALLEST_POWER_OF_FIVE) as usize];
+
+    let first = u128::from(w) * u128::from(high5);
+    let (mut low, mut high) = (first as u64, (first >> 64) as u64);
+    if high & mask == mask {
+        let second_high = ((u128::from(w) * u128::from(low5)) >> 64) as u64;
+        low = low.wrapping_add(second_high);
+        if second_high > low {
+            high += 1;
+        }
+    }
+    (low, high)
+}
@@ -1,4 +1,5 @@
-use super::parse_value;
+use super::value::parse_value_at;
+use super::value::skip_whitespace;
 use crate::model::{JsonParseError, JsonValue};
 
 /// Parses a JSON array with potentially nested values and precise error tracking.
@@ -34,7 +35,17 @@ use crate::model::{JsonParseError, JsonValue};
 /// );
 /// ```
 pub fn parse_array(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
-    let input = input.trim_start();
+    parse_array_at(input, 0, false)
+}
+
+/// Parses an array nested `depth` containers deep, accepting a comma before the `]` if
+/// `allow_trailing_commas` is set.
+pub(crate) fn parse_array_at(
+    input: &str,
+    depth: usize,
+    allow_trailing_commas: bool,
+) -> Result<(JsonValue, &str), JsonParseError> {
+    let input = skip_whitespace(input);
 
     if !input.starts_with('[') {
         return Err(JsonParseError::unmatched("array", input));
@@ -44,23 +55,23 @@ pub fn parse_array(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
     let mut values = Vec::new();
 
     loop {
-        remaining = remaining.trim_start();
+        remaining = skip_whitespace(remaining);
 
         if let Some(rest) = remaining.strip_prefix(']') {
             return Ok((JsonValue::Array(values), rest));
         }
 
         // Parse value
-        let (value, rest) =
-            parse_value(remaining).map_err(|e| JsonParseError::new(&e.message, e.index, input))?;
+        let (value, rest) = parse_value_at(remaining, depth + 1, allow_trailing_commas)
+            .map_err(|e| e.shifted(input.len() - remaining.len(), input))?;
         values.push(value);
-        remaining = rest.trim_start();
+        remaining = skip_whitespace(rest);
 
         if let Some(rest) = remaining.strip_prefix(',') {
             remaining = rest;
 
-            if remaining.trim_start().starts_with(']') {
-                let err_pos = input.len() - remaining.trim_start().len();
+            if !allow_trailing_commas && skip_whitespace(remaining).starts_with(']') {
+                let err_pos = input.len() - skip_whitespace(remaining).len();
                 return Err(JsonParseError::new(
                     "Trailing comma not allowed before ']'",
                     err_pos,
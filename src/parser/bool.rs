@@ -1,3 +1,4 @@
+use super::value::skip_whitespace;
 use crate::model::{JsonParseError, JsonValue};
 
 /// Parses a JSON boolean literal (`true` or `false`) with strict syntax validation.
@@ -27,7 +28,7 @@ use crate::model::{JsonParseError, JsonValue};
 /// assert!(parse_bool("falsehood").is_err());
 /// ```
 pub fn parse_bool(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
-    let input = input.trim_start();
+    let input = skip_whitespace(input);
 
     if let Some(rest) = input.strip_prefix("true") {
         if let Some(c) = rest.chars().next() {
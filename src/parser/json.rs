@@ -1,5 +1,5 @@
 use crate::model::{JsonParseError, JsonParseOptions, JsonValue};
-use crate::parser::parse_value;
+use crate::parser::value::{parse_value_at, skip_whitespace};
 
 /// Parses a complete JSON value from a string slice, ensuring full input consumption.
 ///
@@ -12,7 +12,10 @@ use crate::parser::parse_value;
 /// - Invalid constructs (e.g., malformed numbers or unterminated strings) are rejected
 ///
 /// The behavior of the parser can be controlled using the `JsonParseOptions` structure.
-/// If no options are provided, the parser defaults to strict mode.
+/// If no options are provided, the parser defaults to strict mode. In tolerant mode, anything
+/// after the first value is ignored; use [`parse_json_prefix`] to find out where it ends.
+/// With `allow_trailing_commas`, a comma may also follow the last element of an array or
+/// the last member of an object.
 ///
 /// # Arguments
 ///
@@ -37,18 +40,22 @@ use crate::parser::parse_value;
 /// // Tolerant mode, trailing characters are allowed
 /// let result = parse_json("true false", Some(&JsonParseOptions::tolerant()));
 /// assert!(result.is_ok());
+///
+/// // Trailing commas are rejected unless allowed
+/// assert!(parse_json("[1, 2,]", None).is_err());
+/// let result = parse_json("[1, 2,]", Some(&JsonParseOptions::new(true, true)));
+/// assert!(result.is_ok());
 /// ```
 pub fn parse_json(
     input: &str,
     options: Option<&JsonParseOptions>,
 ) -> Result<JsonValue, JsonParseError> {
-    let trimmed_input = input.trim_start();
-    let (value, rest) = parse_value(trimmed_input)?;
-    let rest_trimmed = rest.trim_start();
-
     let default_options = JsonParseOptions::default();
     let options = options.unwrap_or(&default_options);
 
+    let (value, end) = parse_document(input, 0, options.allow_trailing_commas)?;
+    let rest_trimmed = skip_whitespace(&input[end..]);
+
     if !rest_trimmed.is_empty() && options.strict {
         let offset = input.len() - rest_trimmed.len();
         return Err(JsonParseError::new(
@@ -60,3 +67,94 @@ pub fn parse_json(
 
     Ok(value)
 }
+
+/// Parses the JSON value at the start of `input` and returns the unparsed remainder.
+///
+/// Leading whitespace is skipped. Parsing stops right after the value, so the remainder
+/// starts with whatever follows it (including whitespace). This is useful when JSON is
+/// embedded in another protocol and the caller needs to know where the value ended.
+///
+/// # Arguments
+///
+/// * `input` - A string slice starting with a JSON value, possibly followed by anything.
+///
+/// # Returns
+///
+/// * `Ok((JsonValue, remaining_input))` on success. The number of consumed bytes is
+///   `input.len() - remaining_input.len()`.
+/// * `Err(JsonParseError)` if no valid value starts the input.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_json_prefix, JsonValue};
+///
+/// let input = "{\"len\": 2} trailing payload";
+/// let (value, rest) = parse_json_prefix(input).unwrap();
+///
+/// assert!(matches!(value, JsonValue::Object(_)));
+/// assert_eq!(rest, " trailing payload");
+/// assert_eq!(input.len() - rest.len(), 10);
+/// ```
+pub fn parse_json_prefix(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
+    let (value, end) = parse_json_at(input, 0)?;
+    Ok((value, &input[end..]))
+}
+
+/// Parses the JSON value starting at byte `offset` in a larger buffer.
+///
+/// Leading whitespace at `offset` is skipped. Error positions (index, line and column)
+/// are reported relative to the whole buffer, not to `offset`.
+///
+/// # Arguments
+///
+/// * `input` - The complete buffer.
+/// * `offset` - The byte offset at which the value (or its leading whitespace) starts.
+///
+/// # Returns
+///
+/// * `Ok((JsonValue, end))` where `end` is the byte offset just past the value.
+/// * `Err(JsonParseError)` if no valid value starts at `offset`, or if `offset` is out of
+///   bounds or not on a character boundary.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_json_at, JsonValue};
+///
+/// let buffer = "HEADER [1, 2] FOOTER";
+/// let (value, end) = parse_json_at(buffer, 6).unwrap();
+///
+/// assert_eq!(value, JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Number(2.0)]));
+/// assert_eq!(&buffer[end..], " FOOTER");
+///
+/// let err = parse_json_at("a\n  01", 2).unwrap_err();
+/// assert_eq!((err.index, err.line), (5, 2));
+/// ```
+pub fn parse_json_at(input: &str, offset: usize) -> Result<(JsonValue, usize), JsonParseError> {
+    parse_document(input, offset, false)
+}
+
+/// Parses the value at `offset` in `input`, returning it with its end offset and reporting
+/// errors relative to the whole input.
+fn parse_document(
+    input: &str,
+    offset: usize,
+    allow_trailing_commas: bool,
+) -> Result<(JsonValue, usize), JsonParseError> {
+    let Some(slice) = input.get(offset..) else {
+        return Err(JsonParseError::new(
+            "Offset is outside the input or not on a character boundary",
+            offset.min(input.len()),
+            input,
+        ));
+    };
+
+    let trimmed = skip_whitespace(slice);
+    let start = input.len() - trimmed.len();
+
+    match parse_value_at(trimmed, 0, allow_trailing_commas) {
+        Ok((value, rest)) => Ok((value, input.len() - rest.len())),
+        Err(e) => Err(JsonParseError::new(&e.message, start + e.index, input)),
+    }
+}
@@ -0,0 +1,91 @@
+use std::ops::Range;
+
+use super::parse_json_at;
+use crate::model::{JsonParseError, JsonValue};
+
+/// The RFC 7464 record separator (`0x1E`) that starts each JSON text in a sequence.
+const RECORD_SEPARATOR: char = '\u{1E}';
+
+/// Iterator over the top-level values of a concatenated JSON stream, see [`parse_many`].
+pub struct ParseMany<'a> {
+    input: &'a str,
+    offset: usize,
+    done: bool,
+}
+
+impl<'a> Iterator for ParseMany<'a> {
+    type Item = Result<(JsonValue, Range<usize>), JsonParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut in_sequence = false;
+        let remaining = self.input[self.offset..].trim_start_matches(|c: char| {
+            in_sequence |= c == RECORD_SEPARATOR;
+            c == RECORD_SEPARATOR || matches!(c, ' ' | '\t' | '\n' | '\r')
+        });
+        let start = self.input.len() - remaining.len();
+
+        if remaining.is_empty() {
+            self.done = true;
+            return None;
+        }
+
+        match parse_json_at(self.input, start) {
+            Ok((value, end)) => {
+                self.offset = end;
+                Some(Ok((value, start..end)))
+            }
+            Err(e) => {
+                // Inside an RFC 7464 sequence a broken record does not affect the next one.
+                match remaining.find(RECORD_SEPARATOR).filter(|_| in_sequence) {
+                    Some(next) => self.offset = start + next,
+                    None => self.done = true,
+                }
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Parses a stream of concatenated top-level JSON values.
+///
+/// Values may be separated by whitespace, follow each other directly (`{"a":1}{"b":2}`),
+/// or be prefixed by the `0x1E` record separator of RFC 7464 JSON text sequences. Each
+/// item is the parsed value together with its byte span in `input`.
+///
+/// Outside of a JSON text sequence, iteration stops after the first error. Within a
+/// sequence, a malformed record yields an error and parsing resumes at the next `0x1E`.
+///
+/// # Arguments
+///
+/// * `input` - The full text containing zero or more JSON values.
+///
+/// # Returns
+///
+/// An iterator of `Result<(JsonValue, Range<usize>), JsonParseError>`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_many, JsonValue};
+///
+/// let values: Vec<_> = parse_many("{\"a\":1}{\"b\":2} 3")
+///     .map(|item| item.map(|(_, span)| span))
+///     .collect();
+/// assert_eq!(values, vec![Ok(0..7), Ok(7..14), Ok(15..16)]);
+///
+/// let records: Vec<_> = parse_many("\u{1E}1\n\u{1E}[oops\n\u{1E}true\n").collect();
+/// assert_eq!(records.len(), 3);
+/// assert!(records[1].is_err());
+/// assert_eq!(records[2], Ok((JsonValue::Bool(true), 11..15)));
+/// ```
+pub fn parse_many(input: &str) -> ParseMany<'_> {
+    ParseMany {
+        input,
+        offset: 0,
+        done: false,
+    }
+}
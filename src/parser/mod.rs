@@ -1,17 +1,24 @@
 pub mod array;
 pub mod bool;
 pub mod json;
+pub mod many;
+pub mod ndjson;
 pub mod null;
 pub mod number;
 pub mod object;
+pub mod push;
+pub mod scan;
 pub mod string;
 pub mod value;
 
 pub use array::parse_array;
 pub use bool::parse_bool;
-pub use json::parse_json;
+pub use json::{parse_json, parse_json_at, parse_json_prefix};
+pub use many::{parse_many, ParseMany};
+pub use ndjson::{parse_ndjson, NdjsonReader};
 pub use null::parse_null;
 pub use number::parse_number;
 pub use object::parse_object;
+pub use push::JsonPushParser;
 pub use string::parse_string;
-pub use value::parse_value;
+pub use value::{parse_value, MAX_DEPTH};
@@ -0,0 +1,148 @@
+use std::io::BufRead;
+
+use super::parse_json;
+use crate::model::{JsonParseError, JsonParseOptions, JsonValue};
+
+/// Reads newline-delimited JSON (NDJSON / JSON Lines), one value per line.
+///
+/// The reader is an iterator yielding one `Result` per non-empty line, so a malformed
+/// line produces an error and iteration simply continues with the next one. Errors carry
+/// the NDJSON line number in `line`, the column within that line, and the byte offset
+/// from the start of the stream in `index`. Lines may end with `\n` or `\r\n`; blank
+/// lines are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{JsonValue, NdjsonReader};
+///
+/// let input = "{\"id\": 1}\nnot json\n[true]\n";
+/// let results: Vec<_> = NdjsonReader::new(input.as_bytes()).collect();
+///
+/// assert_eq!(results.len(), 3);
+/// assert!(results[0].is_ok());
+/// assert_eq!(results[1].as_ref().unwrap_err().line, 2);
+/// assert_eq!(results[2], Ok(JsonValue::Array(vec![JsonValue::Bool(true)])));
+/// ```
+pub struct NdjsonReader<R: BufRead> {
+    reader: R,
+    options: JsonParseOptions,
+    buffer: Vec<u8>,
+    line: usize,
+    offset: usize,
+    done: bool,
+}
+
+impl<R: BufRead> NdjsonReader<R> {
+    /// Creates a reader using the default (strict) options for every line.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The NDJSON source, e.g. a `BufReader<File>`, `stdin().lock()` or a byte slice.
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, JsonParseOptions::default())
+    }
+
+    /// Creates a reader parsing every line with the given options.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The NDJSON source.
+    /// * `options` - The options passed to [`parse_json`] for each line.
+    pub fn with_options(reader: R, options: JsonParseOptions) -> Self {
+        NdjsonReader {
+            reader,
+            options,
+            buffer: Vec::new(),
+            line: 0,
+            offset: 0,
+            done: false,
+        }
+    }
+
+    fn parse_line(&self, start: usize) -> Result<JsonValue, JsonParseError> {
+        let bytes = strip_line_ending(&self.buffer);
+        let text = std::str::from_utf8(bytes).map_err(|e| JsonParseError {
+            message: "Invalid UTF-8 sequence in line".to_string(),
+            index: start + e.valid_up_to(),
+            line: self.line,
+            column: 1,
+        })?;
+
+        parse_json(text, Some(&self.options)).map_err(|e| JsonParseError {
+            message: e.message,
+            index: start + e.index,
+            line: self.line,
+            column: e.column,
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for NdjsonReader<R> {
+    type Item = Result<JsonValue, JsonParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buffer.clear();
+            let start = self.offset;
+
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => self.done = true,
+                Ok(read) => {
+                    self.offset += read;
+                    self.line += 1;
+
+                    if strip_line_ending(&self.buffer)
+                        .iter()
+                        .all(|b| b.is_ascii_whitespace())
+                    {
+                        continue;
+                    }
+
+                    return Some(self.parse_line(start));
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(JsonParseError {
+                        message: format!("I/O error while reading NDJSON: {e}"),
+                        index: start,
+                        line: self.line + 1,
+                        column: 1,
+                    }));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Parses an in-memory NDJSON document, one value per line.
+///
+/// This is a convenience wrapper around [`NdjsonReader`] for string input.
+///
+/// # Arguments
+///
+/// * `input` - The NDJSON text.
+///
+/// # Returns
+///
+/// An iterator yielding one `Result<JsonValue, JsonParseError>` per non-empty line.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_ndjson, JsonValue};
+///
+/// let values: Result<Vec<_>, _> = parse_ndjson("1\r\n\n2\n").collect();
+/// assert_eq!(values, Ok(vec![JsonValue::Number(1.0), JsonValue::Number(2.0)]));
+/// ```
+pub fn parse_ndjson(input: &str) -> NdjsonReader<&[u8]> {
+    NdjsonReader::new(input.as_bytes())
+}
+
+fn strip_line_ending(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
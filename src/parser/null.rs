@@ -1,3 +1,4 @@
+use super::value::skip_whitespace;
 use crate::model::{JsonParseError, JsonValue};
 
 /// Parses the JSON `null` literal with strict validation and error tracking.
@@ -23,7 +24,7 @@ use crate::model::{JsonParseError, JsonValue};
 /// assert!(parse_null("nulla").is_err());
 /// ```
 pub fn parse_null(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
-    let input = input.trim_start();
+    let input = skip_whitespace(input);
 
     if let Some(rest) = input.strip_prefix("null") {
         if let Some(c) = rest.chars().next() {
@@ -1,4 +1,7 @@
+use super::scan::digit_run;
+use super::value::skip_whitespace;
 use crate::model::{JsonParseError, JsonValue};
+use crate::number::parse_f64;
 
 /// Parses a JSON number, including integers, decimals, and scientific notation (e.g. `1e3`, `-2.5E-2`).
 ///
@@ -30,125 +33,72 @@ use crate::model::{JsonParseError, JsonValue};
 /// assert!(parse_number("abc").is_err());
 /// ```
 pub fn parse_number(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
-    let input = input.trim_start();
-    let mut chars = input.char_indices().peekable();
-    let mut end_index = 0;
-
-    let original = input;
+    let input = skip_whitespace(input);
+    let bytes = input.as_bytes();
 
     // Optional minus sign
-    if let Some((_, '-')) = chars.peek() {
-        chars.next();
-    }
+    let mut i = usize::from(bytes.first() == Some(&b'-'));
 
-    // Reject leading dot
-    if let Some((_, '.')) = chars.peek() {
-        return Err(JsonParseError::unmatched("number", input));
-    }
-
-    // Integer part
-    let mut leading_zero = false;
-    if let Some(&(i, c)) = chars.peek() {
-        if c == '0' {
-            leading_zero = true;
-            end_index = i + 1;
-            chars.next();
-        } else if c.is_ascii_digit() {
-            while let Some(&(i, c)) = chars.peek() {
-                if c.is_ascii_digit() {
-                    end_index = i + 1;
-                    chars.next();
-                } else {
-                    break;
-                }
-            }
-        } else {
-            return Err(JsonParseError::unmatched("number", input));
-        }
-    } else {
-        return Err(JsonParseError::unmatched("number", input));
-    }
-
-    // Reject leading zeros like "01"
-    if leading_zero {
-        if let Some(&(_, c)) = chars.peek() {
-            if c.is_ascii_digit() {
+    // Integer part (a leading dot or anything else that is not a digit is not a number)
+    let mut end_index = match bytes.get(i) {
+        Some(b'0') => {
+            // Reject leading zeros like "01"
+            if bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
                 return Err(JsonParseError::new(
                     "Leading zeros are not allowed in numbers",
-                    end_index,
-                    original,
+                    i + 1,
+                    input,
                 ));
             }
+            i + 1
         }
-    }
+        Some(b'1'..=b'9') => i + digit_run(&bytes[i..]),
+        _ => return Err(JsonParseError::unmatched("number", input)),
+    };
 
     // Fractional part
-    if let Some(&(_, '.')) = chars.peek() {
-        chars.next(); // consume '.'
-
-        let mut has_frac_digits = false;
-        while let Some(&(j, c)) = chars.peek() {
-            if c.is_ascii_digit() {
-                has_frac_digits = true;
-                end_index = j + 1;
-                chars.next();
-            } else {
-                break;
-            }
-        }
-
-        if !has_frac_digits {
+    if bytes.get(end_index) == Some(&b'.') {
+        let digits = digit_run(&bytes[end_index + 1..]);
+        if digits == 0 {
             return Err(JsonParseError::new(
                 "Expected digits after decimal point",
                 end_index,
-                original,
+                input,
             ));
         }
+        end_index += 1 + digits;
     }
 
     // Exponent part
-    if let Some(&(_, 'e' | 'E')) = chars.peek() {
-        chars.next(); // consume 'e' or 'E'
-
-        if let Some(&(_, '+' | '-')) = chars.peek() {
-            chars.next();
+    if let Some(b'e' | b'E') = bytes.get(end_index) {
+        i = end_index + 1;
+        if let Some(b'+' | b'-') = bytes.get(i) {
+            i += 1;
         }
-
-        let mut has_exp_digits = false;
-        while let Some(&(i, c)) = chars.peek() {
-            if c.is_ascii_digit() {
-                has_exp_digits = true;
-                end_index = i + 1;
-                chars.next();
-            } else {
-                break;
-            }
-        }
-
-        if !has_exp_digits {
+        let digits = digit_run(&bytes[i..]);
+        if digits == 0 {
             return Err(JsonParseError::new(
                 "Missing digits in exponent",
                 end_index,
-                original,
+                input,
             ));
         }
+        end_index = i + digits;
     }
 
     let (matched, rest) = input.split_at(end_index);
 
-    if let Some(c) = rest.chars().next() {
-        if c.is_ascii_alphabetic() || c == '.' {
+    if let Some(&c) = rest.as_bytes().first() {
+        if c.is_ascii_alphabetic() || c == b'.' {
             return Err(JsonParseError::new(
                 "Unexpected trailing character after number",
                 end_index,
-                original,
+                input,
             ));
         }
     }
 
-    matched
-        .parse::<f64>()
-        .ok()
+    parse_f64(matched)
         .map(|num| (JsonValue::Number(num), rest))
-        .ok_or_else(|| JsonParseError::new("Failed to parse number", end_index, original))
+        .ok_or_else(|| JsonParseError::new("Failed to parse number", end_index, input))
 }
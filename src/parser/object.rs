@@ -3,7 +3,8 @@ use crate::parser::parse_string;
 
 use std::collections::HashMap;
 
-use super::parse_value;
+use super::value::parse_value_at;
+use super::value::skip_whitespace;
 
 /// Parses a JSON object with string keys and potentially nested values.
 ///
@@ -46,60 +47,80 @@ use super::parse_value;
 /// );
 /// ```
 pub fn parse_object(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
-    let original_input = input;
-    let mut input = input.trim_start(); // mutable, pas "let input = ..."
+    parse_object_at(input, 0, false)
+}
 
-    if !input.starts_with('{') {
+/// Parses an object nested `depth` containers deep, accepting a comma before the `}` if
+/// `allow_trailing_commas` is set.
+pub(crate) fn parse_object_at(
+    input: &str,
+    depth: usize,
+    allow_trailing_commas: bool,
+) -> Result<(JsonValue, &str), JsonParseError> {
+    let object = skip_whitespace(input);
+    // Errors are reported relative to the start of the object.
+    let offset = |rest: &str| object.len() - rest.len();
+
+    if !object.starts_with('{') {
         return Err(JsonParseError::new(
             "Expected '{' to start object",
             0,
-            input,
+            object,
         ));
     }
 
-    input = &input[1..];
+    let mut input = &object[1..];
     let mut map = HashMap::new();
 
     loop {
-        input = input.trim_start();
+        input = skip_whitespace(input);
 
         if let Some(rest) = input.strip_prefix('}') {
             return Ok((JsonValue::Object(map), rest));
         }
 
-        let (key_value, rest) = parse_string(input)
-            .map_err(|e| JsonParseError::new("Expected string key in object", e.index, input))?;
+        let (key_value, rest) = parse_string(input).map_err(|e| {
+            JsonParseError::new(
+                "Expected string key in object",
+                offset(input) + e.index,
+                object,
+            )
+        })?;
 
         let JsonValue::String(key) = key_value else {
-            return Err(JsonParseError::new("Object keys must be strings", 0, input));
+            return Err(JsonParseError::new(
+                "Object keys must be strings",
+                offset(input),
+                object,
+            ));
         };
 
-        input = rest.trim_start();
+        input = skip_whitespace(rest);
 
         if !input.starts_with(':') {
-            let offset = original_input.len() - input.len();
             return Err(JsonParseError::new(
                 "Expected ':' after key in object",
-                offset,
-                original_input,
+                offset(input),
+                object,
             ));
         }
 
         input = &input[1..];
-        input = input.trim_start();
+        input = skip_whitespace(input);
 
-        let (value, rest) = parse_value(input)?;
+        let (value, rest) = parse_value_at(input, depth + 1, allow_trailing_commas)
+            .map_err(|e| e.shifted(offset(input), object))?;
         map.insert(key, value);
-        input = rest.trim_start();
+        input = skip_whitespace(rest);
 
         if let Some(rest) = input.strip_prefix(',') {
             input = rest;
 
-            if input.trim_start().starts_with('}') {
+            if !allow_trailing_commas && skip_whitespace(input).starts_with('}') {
                 return Err(JsonParseError::new(
                     "Trailing comma not allowed before '}'",
-                    input.len() - input.trim_start().len(),
-                    input,
+                    offset(skip_whitespace(input)),
+                    object,
                 ));
             }
 
@@ -109,8 +130,8 @@ pub fn parse_object(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
         } else {
             return Err(JsonParseError::new(
                 "Expected ',' or '}' after object entry",
-                input.len(),
-                input,
+                offset(input),
+                object,
             ));
         }
     }
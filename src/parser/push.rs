@@ -0,0 +1,512 @@
+use std::collections::HashMap;
+use std::mem;
+
+use super::string::{combine_surrogates, is_high_surrogate};
+use super::{parse_bool, parse_null, parse_number};
+use crate::model::{JsonEvent, JsonParseError, JsonParseOptions, JsonValue};
+
+/// A container whose closing bracket has not been read yet.
+enum Frame {
+    Array(Vec<JsonValue>),
+    Object(HashMap<String, JsonValue>, Option<String>),
+}
+
+/// What the parser expects to read next.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    /// A value, at the top level or after `:`.
+    Value,
+    /// A value or `]`, right after `[`.
+    ArrayFirst,
+    /// A value after `,` in an array.
+    ArrayNext,
+    /// A key or `}`, right after `{`.
+    ObjectFirst,
+    /// A key after `,` in an object.
+    ObjectNext,
+    /// The `:` following an object key.
+    Colon,
+    /// A `,` or the closing bracket of the current container.
+    AfterValue,
+    /// The rest of a string literal.
+    String,
+    /// The rest of a number or `true`/`false`/`null` literal.
+    Token,
+    /// Nothing: the top-level value is complete.
+    Done,
+}
+
+/// Progress through an escape sequence inside a string literal.
+#[derive(Debug, Clone, Copy)]
+enum Escape {
+    None,
+    Backslash,
+    Unicode { digits: u8, unit: u32 },
+}
+
+/// A location in the byte stream fed so far.
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+/// An incremental JSON parser that accepts input in arbitrary byte chunks.
+///
+/// The parser keeps its state between calls to [`feed`](JsonPushParser::feed), so a chunk
+/// boundary may fall anywhere: inside a number, a literal, a multi-byte UTF-8 sequence or a
+/// `\uXXXX` escape. Each call returns the [`JsonEvent`]s that became available, ending with
+/// `JsonEvent::Document` once the top-level value is complete. The parser never blocks and
+/// never recurses, so it can be driven directly from an event loop.
+///
+/// The same rules as [`parse_json`](crate::parse_json) apply: in strict mode anything but
+/// whitespace after the top-level value is rejected, while tolerant mode ignores it.
+/// Trailing commas are accepted when `allow_trailing_commas` is set.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{JsonEvent, JsonPushParser, JsonValue};
+///
+/// let mut parser = JsonPushParser::new();
+/// let mut events = parser.feed(b"[12").unwrap();
+/// events.extend(parser.feed(b"3, \"\\u00").unwrap());
+/// events.extend(parser.feed(b"e9\"]").unwrap());
+/// events.extend(parser.finish().unwrap());
+///
+/// assert_eq!(
+///     events,
+///     vec![
+///         JsonEvent::StartArray,
+///         JsonEvent::Value(JsonValue::Number(123.0)),
+///         JsonEvent::Value(JsonValue::String("é".to_string())),
+///         JsonEvent::EndArray,
+///         JsonEvent::Document(JsonValue::Array(vec![
+///             JsonValue::Number(123.0),
+///             JsonValue::String("é".to_string()),
+///         ])),
+///     ]
+/// );
+/// ```
+pub struct JsonPushParser {
+    options: JsonParseOptions,
+    state: State,
+    stack: Vec<Frame>,
+    buffer: Vec<u8>,
+    is_key: bool,
+    escape: Escape,
+    high_surrogate: Option<u32>,
+    token_start: Position,
+    position: Position,
+    error: Option<JsonParseError>,
+}
+
+impl JsonPushParser {
+    /// Creates a push parser using the default (strict) options.
+    ///
+    /// # Returns
+    ///
+    /// A parser waiting for the first chunk of a JSON document.
+    pub fn new() -> Self {
+        Self::with_options(JsonParseOptions::default())
+    }
+
+    /// Creates a push parser using the given options.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Controls strictness and trailing comma tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A parser waiting for the first chunk of a JSON document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::model::JsonParseOptions;
+    /// use synson::JsonPushParser;
+    ///
+    /// let mut parser = JsonPushParser::with_options(JsonParseOptions::tolerant());
+    /// assert!(parser.feed(b"[1, 2,]").is_ok());
+    /// assert!(parser.is_complete());
+    /// ```
+    pub fn with_options(options: JsonParseOptions) -> Self {
+        let start = Position {
+            index: 0,
+            line: 1,
+            column: 1,
+        };
+
+        JsonPushParser {
+            options,
+            state: State::Value,
+            stack: Vec::new(),
+            buffer: Vec::new(),
+            is_key: false,
+            escape: Escape::None,
+            high_surrogate: None,
+            token_start: start,
+            position: start,
+            error: None,
+        }
+    }
+
+    /// Returns `true` once the top-level value has been fully parsed.
+    ///
+    /// A top-level number is only known to be complete once a delimiter or
+    /// [`finish`](JsonPushParser::finish) is seen.
+    pub fn is_complete(&self) -> bool {
+        self.state == State::Done
+    }
+
+    /// Feeds the next chunk of input to the parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk` - The next bytes of the document. May be empty.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(events)` with every event completed by this chunk (possibly none).
+    /// * `Err(JsonParseError)` with the absolute position of the first syntax error.
+    ///   Once an error is returned, every later call returns the same error.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<JsonEvent>, JsonParseError> {
+        if let Some(err) = &self.error {
+            return Err(err.clone());
+        }
+
+        let mut events = Vec::new();
+        for &byte in chunk {
+            if let Err(err) = self.step(byte, &mut events) {
+                self.error = Some(err.clone());
+                return Err(err);
+            }
+            self.advance(byte);
+        }
+
+        Ok(events)
+    }
+
+    /// Signals the end of input and flushes any pending token.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(events)` with the events completed by the end of input, such as a
+    ///   trailing top-level number and its `JsonEvent::Document`.
+    /// * `Err(JsonParseError)` if the document is incomplete or a previous chunk failed.
+    pub fn finish(mut self) -> Result<Vec<JsonEvent>, JsonParseError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let mut events = Vec::new();
+        if self.state == State::Token {
+            self.finish_token(&mut events)?;
+        }
+
+        match self.state {
+            State::Done => Ok(events),
+            State::String => Err(self.error_here("Unterminated string literal")),
+            _ => Err(self.error_here("Unexpected end of JSON input")),
+        }
+    }
+
+    /// Processes a single byte according to the current state.
+    fn step(&mut self, byte: u8, events: &mut Vec<JsonEvent>) -> Result<(), JsonParseError> {
+        if self.state == State::Token {
+            if is_token_byte(byte) {
+                self.buffer.push(byte);
+                return Ok(());
+            }
+            self.finish_token(events)?;
+        }
+
+        match self.state {
+            State::String => self.step_string(byte, events),
+            _ if is_whitespace(byte) => Ok(()),
+            State::Value | State::ArrayFirst | State::ArrayNext => self.step_value(byte, events),
+            State::ObjectFirst | State::ObjectNext => self.step_key(byte, events),
+            State::Colon => match byte {
+                b':' => {
+                    self.state = State::Value;
+                    Ok(())
+                }
+                _ => Err(self.error_here("Expected ':' after key in object")),
+            },
+            State::AfterValue => self.step_separator(byte, events),
+            State::Done if self.options.strict => {
+                Err(self.error_here("Trailing characters after JSON value"))
+            }
+            State::Done | State::Token => Ok(()),
+        }
+    }
+
+    fn step_value(&mut self, byte: u8, events: &mut Vec<JsonEvent>) -> Result<(), JsonParseError> {
+        match byte {
+            b'{' => {
+                self.stack.push(Frame::Object(HashMap::new(), None));
+                self.state = State::ObjectFirst;
+                events.push(JsonEvent::StartObject);
+            }
+            b'[' => {
+                self.stack.push(Frame::Array(Vec::new()));
+                self.state = State::ArrayFirst;
+                events.push(JsonEvent::StartArray);
+            }
+            b'"' => self.start_string(false),
+            b']' if self.state == State::ArrayFirst => self.close_container(events),
+            b']' if self.state == State::ArrayNext => {
+                if !self.options.allow_trailing_commas {
+                    return Err(self.error_here("Trailing comma not allowed before ']'"));
+                }
+                self.close_container(events);
+            }
+            b'-' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' => {
+                self.buffer.clear();
+                self.buffer.push(byte);
+                self.token_start = self.position;
+                self.state = State::Token;
+            }
+            _ => return Err(self.error_here("Expected 'value' literal")),
+        }
+        Ok(())
+    }
+
+    fn step_key(&mut self, byte: u8, events: &mut Vec<JsonEvent>) -> Result<(), JsonParseError> {
+        match byte {
+            b'"' => self.start_string(true),
+            b'}' if self.state == State::ObjectFirst => self.close_container(events),
+            b'}' if self.options.allow_trailing_commas => self.close_container(events),
+            b'}' => return Err(self.error_here("Trailing comma not allowed before '}'")),
+            _ => return Err(self.error_here("Expected string key in object")),
+        }
+        Ok(())
+    }
+
+    fn step_separator(
+        &mut self,
+        byte: u8,
+        events: &mut Vec<JsonEvent>,
+    ) -> Result<(), JsonParseError> {
+        match (self.stack.last(), byte) {
+            (Some(Frame::Array(_)), b',') => self.state = State::ArrayNext,
+            (Some(Frame::Array(_)), b']') => self.close_container(events),
+            (Some(Frame::Array(_)), _) => {
+                return Err(self.error_here("Expected ',' or ']' after array element"));
+            }
+            (Some(Frame::Object(..)), b',') => self.state = State::ObjectNext,
+            (Some(Frame::Object(..)), b'}') => self.close_container(events),
+            (_, _) => return Err(self.error_here("Expected ',' or '}' after object entry")),
+        }
+        Ok(())
+    }
+
+    fn step_string(&mut self, byte: u8, events: &mut Vec<JsonEvent>) -> Result<(), JsonParseError> {
+        match self.escape {
+            Escape::None => {
+                if self.high_surrogate.is_some() && byte != b'\\' {
+                    return Err(self.error_here("Invalid unicode surrogate in string"));
+                }
+                match byte {
+                    b'\\' => self.escape = Escape::Backslash,
+                    b'"' => self.finish_string(events)?,
+                    0x00..=0x1f => {
+                        return Err(self.error_here("Unescaped control character in string"))
+                    }
+                    _ => self.buffer.push(byte),
+                }
+            }
+            Escape::Backslash => {
+                if self.high_surrogate.is_some() && byte != b'u' {
+                    return Err(self.error_here("Invalid unicode surrogate in string"));
+                }
+                let decoded = match byte {
+                    b'"' => '"',
+                    b'\\' => '\\',
+                    b'/' => '/',
+                    b'b' => '\u{0008}',
+                    b'f' => '\u{000C}',
+                    b'n' => '\n',
+                    b'r' => '\r',
+                    b't' => '\t',
+                    b'u' => {
+                        self.escape = Escape::Unicode { digits: 0, unit: 0 };
+                        return Ok(());
+                    }
+                    _ => return Err(self.error_here("Invalid escape sequence in string")),
+                };
+                self.push_char(decoded);
+                self.escape = Escape::None;
+            }
+            Escape::Unicode { digits, unit } => {
+                let digit = (byte as char)
+                    .to_digit(16)
+                    .ok_or_else(|| self.error_here("Invalid unicode escape in string"))?;
+                let unit = unit * 16 + digit;
+
+                if digits < 3 {
+                    self.escape = Escape::Unicode {
+                        digits: digits + 1,
+                        unit,
+                    };
+                    return Ok(());
+                }
+
+                self.escape = Escape::None;
+                let decoded = match self.high_surrogate.take() {
+                    Some(high) => combine_surrogates(high, unit),
+                    None if is_high_surrogate(unit) => {
+                        self.high_surrogate = Some(unit);
+                        return Ok(());
+                    }
+                    None => char::from_u32(unit),
+                };
+                let decoded = decoded
+                    .ok_or_else(|| self.error_here("Invalid unicode surrogate in string"))?;
+                self.push_char(decoded);
+            }
+        }
+        Ok(())
+    }
+
+    fn start_string(&mut self, is_key: bool) {
+        self.buffer.clear();
+        self.is_key = is_key;
+        self.escape = Escape::None;
+        self.high_surrogate = None;
+        self.token_start = self.position;
+        self.state = State::String;
+    }
+
+    fn push_char(&mut self, c: char) {
+        let mut encoded = [0; 4];
+        self.buffer
+            .extend_from_slice(c.encode_utf8(&mut encoded).as_bytes());
+    }
+
+    fn finish_string(&mut self, events: &mut Vec<JsonEvent>) -> Result<(), JsonParseError> {
+        let bytes = mem::take(&mut self.buffer);
+        let text = String::from_utf8(bytes).map_err(|_| {
+            let start = self.token_start;
+            self.error_at("Invalid UTF-8 sequence in string", start)
+        })?;
+
+        if !self.is_key {
+            events.push(JsonEvent::Value(JsonValue::String(text.clone())));
+            self.complete_value(JsonValue::String(text), events);
+            return Ok(());
+        }
+
+        events.push(JsonEvent::Key(text.clone()));
+        if let Some(Frame::Object(_, key)) = self.stack.last_mut() {
+            *key = Some(text);
+        }
+        self.state = State::Colon;
+        Ok(())
+    }
+
+    /// Parses the buffered number or literal with the regular value parsers.
+    fn finish_token(&mut self, events: &mut Vec<JsonEvent>) -> Result<(), JsonParseError> {
+        let bytes = mem::take(&mut self.buffer);
+        // Token bytes are always ASCII.
+        let text = std::str::from_utf8(&bytes).unwrap_or_default();
+        let start = self.token_start;
+
+        let parsed = match bytes.first() {
+            Some(b't' | b'f') => parse_bool(text),
+            Some(b'n') => parse_null(text),
+            _ => parse_number(text),
+        };
+
+        match parsed {
+            Ok((value, "")) => {
+                events.push(JsonEvent::Value(value.clone()));
+                self.complete_value(value, events);
+                Ok(())
+            }
+            Ok((_, rest)) => {
+                let offset = text.len() - rest.len();
+                Err(self.error_at("Unexpected character after value", shift(start, offset)))
+            }
+            Err(e) => Err(self.error_at(&e.message, shift(start, e.index))),
+        }
+    }
+
+    fn close_container(&mut self, events: &mut Vec<JsonEvent>) {
+        let value = match self.stack.pop() {
+            Some(Frame::Array(items)) => {
+                events.push(JsonEvent::EndArray);
+                JsonValue::Array(items)
+            }
+            Some(Frame::Object(map, _)) => {
+                events.push(JsonEvent::EndObject);
+                JsonValue::Object(map)
+            }
+            None => return,
+        };
+        self.complete_value(value, events);
+    }
+
+    /// Attaches a finished value to its parent container, or emits the document.
+    fn complete_value(&mut self, value: JsonValue, events: &mut Vec<JsonEvent>) {
+        self.state = State::AfterValue;
+        match self.stack.last_mut() {
+            Some(Frame::Array(items)) => items.push(value),
+            Some(Frame::Object(map, key)) => {
+                map.insert(key.take().unwrap_or_default(), value);
+            }
+            None => {
+                events.push(JsonEvent::Document(value));
+                self.state = State::Done;
+            }
+        }
+    }
+
+    fn advance(&mut self, byte: u8) {
+        self.position.index += 1;
+        if byte == b'\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else if byte & 0xC0 != 0x80 {
+            self.position.column += 1;
+        }
+    }
+
+    fn error_here(&self, message: &str) -> JsonParseError {
+        self.error_at(message, self.position)
+    }
+
+    fn error_at(&self, message: &str, position: Position) -> JsonParseError {
+        JsonParseError {
+            message: message.to_string(),
+            index: position.index,
+            line: position.line,
+            column: position.column,
+        }
+    }
+}
+
+impl Default for JsonPushParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Moves a position forward on the same line (tokens never contain newlines).
+fn shift(position: Position, offset: usize) -> Position {
+    Position {
+        index: position.index + offset,
+        line: position.line,
+        column: position.column + offset,
+    }
+}
+
+fn is_token_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.')
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
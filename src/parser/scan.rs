@@ -0,0 +1,331 @@
+//! Bulk scanning of string contents, digit runs and whitespace, used by `parse_string`,
+//! `parse_number` and the whitespace skipping between tokens to pass over the bytes that
+//! need no attention in one step. Structural characters (`[`, `{`, `,`, `:`...) are
+//! single bytes that the parsers dispatch on directly.
+//!
+//! By default the scanners test eight bytes at a time with plain integer arithmetic. With
+//! the `simd` feature on x86_64 they use SSE2, or AVX2 when the CPU supports it (detected
+//! at run time), to test 16 or 32 bytes at a time. Every backend returns the same results.
+
+/// Returns the number of leading bytes of `bytes` that can be copied verbatim into a
+/// string value, i.e. the offset of the first `"`, `\` or control character (U+0000 to
+/// U+001F), or `bytes.len()` if there is none.
+///
+/// The returned offset is always a character boundary when `bytes` comes from a `str`,
+/// since the bytes it stops at are ASCII.
+///
+/// # Arguments
+///
+/// * `bytes` - The string contents following an opening quote or an escape sequence.
+///
+/// # Returns
+///
+/// * The length of the run of plain string bytes at the start of `bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parser::scan::string_run;
+///
+/// assert_eq!(string_run(b"plain text\" rest"), 10);
+/// assert_eq!(string_run(b"caf\xc3\xa9\\n"), 5);
+/// assert_eq!(string_run(b"tab\there"), 3);
+/// assert_eq!(string_run(b"no special bytes"), 16);
+/// ```
+pub fn string_run(bytes: &[u8]) -> usize {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        x86::string_run(bytes)
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        portable::string_run(bytes)
+    }
+}
+
+/// Returns the number of leading ASCII digits (`0` to `9`) in `bytes`.
+///
+/// # Arguments
+///
+/// * `bytes` - The input at the start of an integer, fraction or exponent.
+///
+/// # Returns
+///
+/// * The length of the run of digits at the start of `bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parser::scan::digit_run;
+///
+/// assert_eq!(digit_run(b"12345.5"), 5);
+/// assert_eq!(digit_run(b"e10"), 0);
+/// assert_eq!(digit_run(b"31415926535897932384626"), 23);
+/// ```
+pub fn digit_run(bytes: &[u8]) -> usize {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        x86::digit_run(bytes)
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        portable::digit_run(bytes)
+    }
+}
+
+/// Returns the number of leading JSON whitespace bytes (space, tab, line feed and
+/// carriage return) in `bytes`.
+///
+/// # Arguments
+///
+/// * `bytes` - The input between two tokens.
+///
+/// # Returns
+///
+/// * The length of the run of whitespace at the start of `bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parser::scan::whitespace_run;
+///
+/// assert_eq!(whitespace_run(b"\n        \"key\""), 9);
+/// assert_eq!(whitespace_run(b" \t\r\n]"), 4);
+/// assert_eq!(whitespace_run(b"\x0c"), 0);
+/// assert_eq!(whitespace_run(b"[1]"), 0);
+/// ```
+pub fn whitespace_run(bytes: &[u8]) -> usize {
+    // Most tokens are followed by no whitespace, or by a single space.
+    match bytes {
+        [b' ' | b'\t' | b'\n' | b'\r', next, ..] if !is_whitespace(*next) => return 1,
+        [first, ..] if !is_whitespace(*first) => return 0,
+        _ => {}
+    }
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        x86::whitespace_run(bytes)
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        portable::whitespace_run(bytes)
+    }
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Names the backend the scanners use on this machine: `"avx2"`, `"sse2"` or `"portable"`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parser::scan::backend;
+///
+/// assert!(["avx2", "sse2", "portable"].contains(&backend()));
+/// ```
+pub fn backend() -> &'static str {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            "avx2"
+        } else {
+            "sse2"
+        }
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        "portable"
+    }
+}
+
+/// Eight-bytes-at-a-time scanners that work on every target.
+mod portable {
+    const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+    const HIGHS: u64 = u64::from_ne_bytes([0x80; 8]);
+
+    /// Sets the high bit of (at least) the lowest byte of `word` that is below `n`, for
+    /// `n <= 0x80`. Bytes above a match may be flagged spuriously, so only the lowest flag
+    /// can be relied on.
+    fn bytes_below(word: u64, n: u8) -> u64 {
+        word.wrapping_sub(ONES * u64::from(n)) & !word & HIGHS
+    }
+
+    /// Flags the lowest byte of `word` that is above `n` (for `n < 0x80`), with the same
+    /// caveat as [`bytes_below`].
+    fn bytes_above(word: u64, n: u8) -> u64 {
+        (word.wrapping_add(ONES * u64::from(0x7F - n)) | word) & HIGHS
+    }
+
+    /// Sets the high bit of exactly the bytes of `word` equal to `byte`.
+    fn bytes_equal(word: u64, byte: u8) -> u64 {
+        let lows = !HIGHS;
+        let diff = word ^ (ONES * u64::from(byte));
+        !(((diff & lows).wrapping_add(lows)) | diff | lows)
+    }
+
+    fn load(chunk: &[u8]) -> u64 {
+        u64::from_le_bytes(chunk.try_into().expect("chunks of eight bytes"))
+    }
+
+    pub(super) fn string_run(bytes: &[u8]) -> usize {
+        let mut offset = 0;
+        for chunk in bytes.chunks_exact(8) {
+            let word = load(chunk);
+            let flags = bytes_below(word, 0x20)
+                | bytes_below(word ^ (ONES * u64::from(b'"')), 1)
+                | bytes_below(word ^ (ONES * u64::from(b'\\')), 1);
+            if flags != 0 {
+                return offset + flags.trailing_zeros() as usize / 8;
+            }
+            offset += 8;
+        }
+        offset
+            + bytes[offset..]
+                .iter()
+                .position(|&b| b == b'"' || b == b'\\' || b < 0x20)
+                .unwrap_or(bytes.len() - offset)
+    }
+
+    pub(super) fn digit_run(bytes: &[u8]) -> usize {
+        let mut offset = 0;
+        for chunk in bytes.chunks_exact(8) {
+            // Digits become 0 to 9 and every other byte something greater.
+            let flags = bytes_above(load(chunk) ^ (ONES * u64::from(b'0')), 9);
+            if flags != 0 {
+                return offset + flags.trailing_zeros() as usize / 8;
+            }
+            offset += 8;
+        }
+        offset
+            + bytes[offset..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - offset)
+    }
+
+    pub(super) fn whitespace_run(bytes: &[u8]) -> usize {
+        let mut offset = 0;
+        for chunk in bytes.chunks_exact(8) {
+            let word = load(chunk);
+            let whitespace = bytes_equal(word, b' ')
+                | bytes_equal(word, b'\t')
+                | bytes_equal(word, b'\n')
+                | bytes_equal(word, b'\r');
+            let flags = !whitespace & HIGHS;
+            if flags != 0 {
+                return offset + flags.trailing_zeros() as usize / 8;
+            }
+            offset += 8;
+        }
+        offset
+            + bytes[offset..]
+                .iter()
+                .position(|&b| !super::is_whitespace(b))
+                .unwrap_or(bytes.len() - offset)
+    }
+}
+
+/// SSE2 and AVX2 scanners. SSE2 is part of the x86_64 baseline; AVX2 is used when
+/// `is_x86_feature_detected!` finds it. Tails shorter than a vector go to the portable code.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    use super::portable;
+
+    pub(super) fn string_run(bytes: &[u8]) -> usize {
+        if bytes.len() >= 32 && std::is_x86_feature_detected!("avx2") {
+            // SAFETY: the CPU supports AVX2, as checked just above.
+            unsafe { string_run_avx2(bytes) }
+        } else {
+            string_run_sse2(bytes)
+        }
+    }
+
+    pub(super) fn digit_run(bytes: &[u8]) -> usize {
+        let mut offset = 0;
+        while offset + 16 <= bytes.len() {
+            // SAFETY: the 16 bytes read are within `bytes`, and SSE2 is always available
+            // on x86_64.
+            let mask = unsafe {
+                let chunk = _mm_loadu_si128(bytes.as_ptr().add(offset).cast());
+                let values = _mm_sub_epi8(chunk, _mm_set1_epi8(b'0' as i8));
+                let nine = _mm_set1_epi8(9);
+                _mm_movemask_epi8(_mm_cmpeq_epi8(_mm_max_epu8(values, nine), nine)) as u32
+            };
+            if mask != 0xFFFF {
+                return offset + (!mask).trailing_zeros() as usize;
+            }
+            offset += 16;
+        }
+        offset + portable::digit_run(&bytes[offset..])
+    }
+
+    pub(super) fn whitespace_run(bytes: &[u8]) -> usize {
+        let mut offset = 0;
+        while offset + 16 <= bytes.len() {
+            // SAFETY: the 16 bytes read are within `bytes`, and SSE2 is always available
+            // on x86_64.
+            let mask = unsafe {
+                let chunk = _mm_loadu_si128(bytes.as_ptr().add(offset).cast());
+                let space = _mm_cmpeq_epi8(chunk, _mm_set1_epi8(b' ' as i8));
+                let tab = _mm_cmpeq_epi8(chunk, _mm_set1_epi8(b'\t' as i8));
+                let line_feed = _mm_cmpeq_epi8(chunk, _mm_set1_epi8(b'\n' as i8));
+                let carriage_return = _mm_cmpeq_epi8(chunk, _mm_set1_epi8(b'\r' as i8));
+                _mm_movemask_epi8(_mm_or_si128(
+                    _mm_or_si128(space, tab),
+                    _mm_or_si128(line_feed, carriage_return),
+                )) as u32
+            };
+            if mask != 0xFFFF {
+                return offset + (!mask).trailing_zeros() as usize;
+            }
+            offset += 16;
+        }
+        offset + portable::whitespace_run(&bytes[offset..])
+    }
+
+    fn string_run_sse2(bytes: &[u8]) -> usize {
+        let mut offset = 0;
+        while offset + 16 <= bytes.len() {
+            // SAFETY: the 16 bytes read are within `bytes`, and SSE2 is always available
+            // on x86_64.
+            let mask = unsafe {
+                let chunk = _mm_loadu_si128(bytes.as_ptr().add(offset).cast());
+                let quote = _mm_cmpeq_epi8(chunk, _mm_set1_epi8(b'"' as i8));
+                let backslash = _mm_cmpeq_epi8(chunk, _mm_set1_epi8(b'\\' as i8));
+                let limit = _mm_set1_epi8(0x1F);
+                let control = _mm_cmpeq_epi8(_mm_max_epu8(chunk, limit), limit);
+                _mm_movemask_epi8(_mm_or_si128(_mm_or_si128(quote, backslash), control))
+            };
+            if mask != 0 {
+                return offset + mask.trailing_zeros() as usize;
+            }
+            offset += 16;
+        }
+        offset + portable::string_run(&bytes[offset..])
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn string_run_avx2(bytes: &[u8]) -> usize {
+        let mut offset = 0;
+        while offset + 32 <= bytes.len() {
+            // SAFETY: the 32 bytes read are within `bytes`.
+            let mask = unsafe {
+                let chunk = _mm256_loadu_si256(bytes.as_ptr().add(offset).cast());
+                let quote = _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(b'"' as i8));
+                let backslash = _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(b'\\' as i8));
+                let limit = _mm256_set1_epi8(0x1F);
+                let control = _mm256_cmpeq_epi8(_mm256_max_epu8(chunk, limit), limit);
+                _mm256_movemask_epi8(_mm256_or_si256(_mm256_or_si256(quote, backslash), control))
+                    as u32
+            };
+            if mask != 0 {
+                return offset + mask.trailing_zeros() as usize;
+            }
+            offset += 32;
+        }
+        offset + string_run_sse2(&bytes[offset..])
+    }
+}
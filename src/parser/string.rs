@@ -1,8 +1,11 @@
+use super::scan::string_run;
+use super::value::skip_whitespace;
 use crate::model::{JsonParseError, JsonValue};
 
-/// Parses a JSON string literal with escape support (`\\`, `\"`, `\n`, `\t`, `\/`, `\b`, `\f`, `\r`).
+/// Parses a JSON string literal with escape support (`\\`, `\"`, `\n`, `\t`, `\/`, `\b`, `\f`, `\r`, `\uXXXX`).
 ///
-/// Does **not** support `\uXXXX` (reserved for a future version).
+/// Unicode escapes outside the Basic Multilingual Plane must be written as a UTF-16
+/// surrogate pair (e.g. `\uD83D\uDE00`). Lone surrogates are rejected.
 ///
 /// # Arguments
 ///
@@ -27,18 +30,18 @@ use crate::model::{JsonParseError, JsonValue};
 ///     parse_string("\"slash\\/escape\""),
 ///     Ok((JsonValue::String("slash/escape".to_string()), ""))
 /// );
+/// assert_eq!(
+///     parse_string("\"caf\\u00e9\""),
+///     Ok((JsonValue::String("café".to_string()), ""))
+/// );
 /// assert!(parse_string("\"unterminated").is_err());
 /// assert!(parse_string("\"bad\\escape\"").is_err());
 /// ```
 pub fn parse_string(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
-    let input = input.trim_start();
-    let mut chars = input.char_indices();
-
-    let (_, first) = chars
-        .next()
-        .ok_or_else(|| JsonParseError::new("Expected '\"' to start string", 0, input))?;
+    let input = skip_whitespace(input);
+    let bytes = input.as_bytes();
 
-    if first != '"' {
+    if bytes.first() != Some(&b'"') {
         return Err(JsonParseError::new(
             "Expected '\"' to start string",
             0,
@@ -47,35 +50,53 @@ pub fn parse_string(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
     }
 
     let mut result = String::new();
-    let mut escape = false;
+    let mut i = 1;
 
-    for (i, c) in chars {
-        if escape {
-            match c {
-                '"' => result.push('"'),
-                '\\' => result.push('\\'),
-                '/' => result.push('/'),
-                'b' => result.push('\u{0008}'),
-                'f' => result.push('\u{000C}'),
-                'n' => result.push('\n'),
-                'r' => result.push('\r'),
-                't' => result.push('\t'),
-                _ => {
-                    return Err(JsonParseError::new(
-                        "Invalid escape sequence in string",
-                        i,
-                        input,
-                    ));
-                }
+    loop {
+        // Copy everything up to the next quote, backslash or control character at once;
+        // those bytes are ASCII, so `i` stays on a character boundary.
+        let run = string_run(&bytes[i..]);
+        result.push_str(&input[i..i + run]);
+        i += run;
+
+        match bytes.get(i) {
+            None => break,
+            Some(b'"') => return Ok((JsonValue::String(result), &input[i + 1..])),
+            Some(b'\\') => {
+                let unescaped = match bytes.get(i + 1) {
+                    None => break,
+                    Some(b'"') => '"',
+                    Some(b'\\') => '\\',
+                    Some(b'/') => '/',
+                    Some(b'b') => '\u{0008}',
+                    Some(b'f') => '\u{000C}',
+                    Some(b'n') => '\n',
+                    Some(b'r') => '\r',
+                    Some(b't') => '\t',
+                    Some(b'u') => {
+                        let (decoded, consumed) = parse_unicode_escape(input, i + 2)?;
+                        result.push(decoded);
+                        i += 2 + consumed;
+                        continue;
+                    }
+                    Some(_) => {
+                        return Err(JsonParseError::new(
+                            "Invalid escape sequence in string",
+                            i + 1,
+                            input,
+                        ));
+                    }
+                };
+                result.push(unescaped);
+                i += 2;
+            }
+            Some(_) => {
+                return Err(JsonParseError::new(
+                    "Unescaped control character in string",
+                    i,
+                    input,
+                ));
             }
-            escape = false;
-        } else if c == '\\' {
-            escape = true;
-        } else if c == '"' {
-            let rest = &input[i + 1..];
-            return Ok((JsonValue::String(result), rest));
-        } else {
-            result.push(c);
         }
     }
 
@@ -85,3 +106,50 @@ pub fn parse_string(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
         input,
     ))
 }
+
+/// Decodes the hexadecimal part of a `\u` escape starting at `start`, including the
+/// trailing `\uXXXX` low surrogate when the first code unit is a high surrogate.
+///
+/// Returns the decoded character and the number of bytes consumed after `start`.
+fn parse_unicode_escape(input: &str, start: usize) -> Result<(char, usize), JsonParseError> {
+    let high = read_hex4(input, start)
+        .ok_or_else(|| JsonParseError::new("Invalid unicode escape in string", start, input))?;
+
+    if !is_high_surrogate(high) {
+        return char::from_u32(high).map(|c| (c, 4)).ok_or_else(|| {
+            JsonParseError::new("Invalid unicode surrogate in string", start, input)
+        });
+    }
+
+    let low = input
+        .get(start + 4..start + 6)
+        .filter(|prefix| *prefix == "\\u")
+        .and_then(|_| read_hex4(input, start + 6))
+        .ok_or_else(|| JsonParseError::new("Invalid unicode surrogate in string", start, input))?;
+
+    combine_surrogates(high, low)
+        .map(|c| (c, 10))
+        .ok_or_else(|| JsonParseError::new("Invalid unicode surrogate in string", start, input))
+}
+
+/// Reads exactly four hexadecimal digits at `start`, returning `None` if they are missing.
+fn read_hex4(input: &str, start: usize) -> Option<u32> {
+    let digits = input.get(start..start + 4)?;
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Returns `true` if the UTF-16 code unit is a high (leading) surrogate.
+pub(crate) fn is_high_surrogate(unit: u32) -> bool {
+    (0xD800..=0xDBFF).contains(&unit)
+}
+
+/// Combines a UTF-16 surrogate pair into a `char`, or `None` if the pair is invalid.
+pub(crate) fn combine_surrogates(high: u32, low: u32) -> Option<char> {
+    if !is_high_surrogate(high) || !(0xDC00..=0xDFFF).contains(&low) {
+        return None;
+    }
+    char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+}
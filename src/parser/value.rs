@@ -1,11 +1,26 @@
-use super::{parse_array, parse_bool, parse_null, parse_number, parse_object, parse_string};
+use super::array::parse_array_at;
+use super::object::parse_object_at;
+use super::scan::whitespace_run;
+use super::{parse_bool, parse_null, parse_number, parse_string};
 use crate::model::{JsonParseError, JsonValue};
 
+/// How deeply arrays and objects may be nested in a document parsed by [`parse_value`] and
+/// the functions built on it, such as [`parse_json`](super::parse_json).
+pub const MAX_DEPTH: usize = 512;
+
+/// Skips the whitespace JSON allows between tokens: spaces, tabs, line feeds and
+/// carriage returns.
+pub(crate) fn skip_whitespace(input: &str) -> &str {
+    // The run ends before a non-whitespace byte, which is a character boundary.
+    &input[whitespace_run(input.as_bytes())..]
+}
+
 /// Parses any valid JSON value, including primitives and nested arrays/objects.
 ///
-/// This is the core dispatch function used by composite parsers. It attempts each
-/// value type in order and returns the first successful parse. If no value matches,
-/// a detailed `JsonParseError` is returned.
+/// This is the core dispatch function used by composite parsers. Leading whitespace is
+/// skipped, and the first character decides which parser handles the value, so errors
+/// come from that parser and point at the offending character. Arrays and objects nested
+/// more than [`MAX_DEPTH`] levels deep are rejected rather than exhausting the stack.
 ///
 /// # Arguments
 ///
@@ -14,7 +29,8 @@ use crate::model::{JsonParseError, JsonValue};
 /// # Returns
 ///
 /// * `Ok((JsonValue, remaining_input))` if a valid value is found.
-/// * `Err(JsonParseError)` if none match or an inner parser fails.
+/// * `Err(JsonParseError)` if no value starts the input or an inner parser fails, with
+///   the error index relative to `input`.
 ///
 /// # Examples
 ///
@@ -24,29 +40,57 @@ use crate::model::{JsonParseError, JsonValue};
 ///
 /// assert_eq!(parse_value("true"), Ok((JsonValue::Bool(true), "")));
 /// assert!(parse_value("!invalid").is_err());
+/// assert_eq!(parse_value("[1, tru]").unwrap_err().index, 4);
 /// ```
 pub fn parse_value(input: &str) -> Result<(JsonValue, &str), JsonParseError> {
-    let mut first_expected_err: Option<JsonParseError> = None;
+    parse_value_at(input, 0, false)
+}
+
+/// Parses a value nested `depth` containers deep. `allow_trailing_commas` applies to every
+/// array and object inside it.
+pub(crate) fn parse_value_at(
+    input: &str,
+    depth: usize,
+    allow_trailing_commas: bool,
+) -> Result<(JsonValue, &str), JsonParseError> {
+    let trimmed = skip_whitespace(input);
+    let offset = input.len() - trimmed.len();
 
-    for parser in [
-        parse_object,
-        parse_null,
-        parse_bool,
-        parse_number,
-        parse_string,
-        parse_array,
-    ] {
-        match parser(input) {
-            Ok(ok) => return Ok(ok),
-            Err(e) if e.message.starts_with("Expected") => {
-                if first_expected_err.is_none() {
-                    first_expected_err = Some(e);
-                }
-                continue;
-            }
-            Err(e) => return Err(e),
+    let parser = match trimmed.bytes().next() {
+        Some(b'{' | b'[') if depth == MAX_DEPTH => {
+            return Err(JsonParseError::new(
+                &format!("Nesting is deeper than {MAX_DEPTH} levels"),
+                offset,
+                input,
+            ))
+        }
+        Some(b'{') => {
+            return parse_object_at(trimmed, depth, allow_trailing_commas)
+                .map_err(|e| e.shifted(offset, input))
+        }
+        Some(b'[') => {
+            return parse_array_at(trimmed, depth, allow_trailing_commas)
+                .map_err(|e| e.shifted(offset, input))
+        }
+        Some(b'"') => parse_string,
+        Some(b't' | b'f') => parse_bool,
+        Some(b'n') => parse_null,
+        Some(b'-' | b'0'..=b'9') => parse_number,
+        Some(_) => {
+            return Err(JsonParseError::new(
+                "Expected 'value' literal",
+                offset,
+                input,
+            ))
+        }
+        None => {
+            return Err(JsonParseError::new(
+                "Unexpected end of JSON input",
+                input.len(),
+                input,
+            ))
         }
-    }
+    };
 
-    Err(first_expected_err.unwrap_or_else(|| JsonParseError::unmatched("value", input)))
+    parser(trimmed).map_err(|e| e.shifted(offset, input))
 }
@@ -0,0 +1,148 @@
+use super::{JsonPatch, PatchOperation};
+use crate::diff::lcs::{align, Edit};
+use crate::model::JsonValue;
+use crate::pointer::JsonPointer;
+
+/// Generates a JSON Patch that turns `from` into `to`.
+///
+/// Objects are compared member by member and arrays with a longest-common-subsequence
+/// alignment, so unchanged elements are kept and only insertions, removals and changed
+/// values produce operations. Applying the result to `from` with
+/// [`apply_patch`](super::apply_patch) yields a value equal to `to`.
+///
+/// # Arguments
+///
+/// * `from` - The original document.
+/// * `to` - The desired document.
+///
+/// # Returns
+///
+/// The generated patch, empty if both documents are equal.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_json, serialize_json};
+/// use synson::patch::{apply_patch, create_patch};
+///
+/// let from = parse_json(r#"{"name": "a", "tags": ["x", "y"], "old": true}"#, None).unwrap();
+/// let to = parse_json(r#"{"name": "b", "tags": ["w", "x", "y"]}"#, None).unwrap();
+///
+/// let patch = create_patch(&from, &to);
+/// assert_eq!(
+///     serialize_json(&patch.to_value()),
+///     r#"[{"op":"replace","path":"/name","value":"b"},{"op":"remove","path":"/old"},{"op":"add","path":"/tags/0","value":"w"}]"#
+/// );
+///
+/// let mut patched = from.clone();
+/// apply_patch(&mut patched, &patch).unwrap();
+/// assert_eq!(patched, to);
+/// ```
+pub fn create_patch(from: &JsonValue, to: &JsonValue) -> JsonPatch {
+    let mut operations = Vec::new();
+    diff_values(from, to, &JsonPointer::root(), &mut operations);
+    JsonPatch::new(operations)
+}
+
+fn diff_values(
+    from: &JsonValue,
+    to: &JsonValue,
+    path: &JsonPointer,
+    ops: &mut Vec<PatchOperation>,
+) {
+    match (from, to) {
+        _ if from == to => {}
+        (JsonValue::Object(old), JsonValue::Object(new)) => {
+            let mut keys: Vec<_> = old.keys().chain(new.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let child = path.join(key.as_str());
+                match (old.get(key), new.get(key)) {
+                    (Some(a), Some(b)) => diff_values(a, b, &child, ops),
+                    (Some(_), None) => ops.push(PatchOperation::Remove { path: child }),
+                    (None, Some(b)) => ops.push(PatchOperation::Add {
+                        path: child,
+                        value: b.clone(),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        (JsonValue::Array(old), JsonValue::Array(new)) => diff_arrays(old, new, path, ops),
+        _ => ops.push(PatchOperation::Replace {
+            path: path.clone(),
+            value: to.clone(),
+        }),
+    }
+}
+
+fn diff_arrays(
+    old: &[JsonValue],
+    new: &[JsonValue],
+    path: &JsonPointer,
+    ops: &mut Vec<PatchOperation>,
+) {
+    // Common prefix and suffix never need a table.
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let Some(edits) = align(old_mid.len(), new_mid.len(), |i, j| {
+        old_mid[i] == new_mid[j]
+    }) else {
+        return diff_arrays_by_index(old_mid, new_mid, prefix, path, ops);
+    };
+
+    // `index` tracks the position in the array as it is being patched.
+    let (mut i, mut j, mut index) = (0, 0, prefix);
+    for edit in edits {
+        match edit {
+            Edit::Keep => {}
+            Edit::Change => {
+                diff_values(&old_mid[i], &new_mid[j], &path.join(index.to_string()), ops)
+            }
+            Edit::Insert => ops.push(PatchOperation::Add {
+                path: path.join(index.to_string()),
+                value: new_mid[j].clone(),
+            }),
+            Edit::Delete => ops.push(PatchOperation::Remove {
+                path: path.join(index.to_string()),
+            }),
+        }
+        i += usize::from(edit != Edit::Insert);
+        j += usize::from(edit != Edit::Delete);
+        index += usize::from(edit != Edit::Delete);
+    }
+}
+
+fn diff_arrays_by_index(
+    old: &[JsonValue],
+    new: &[JsonValue],
+    offset: usize,
+    path: &JsonPointer,
+    ops: &mut Vec<PatchOperation>,
+) {
+    for (i, (a, b)) in old.iter().zip(new).enumerate() {
+        diff_values(a, b, &path.join((offset + i).to_string()), ops);
+    }
+    for (i, value) in new.iter().enumerate().skip(old.len()) {
+        ops.push(PatchOperation::Add {
+            path: path.join((offset + i).to_string()),
+            value: value.clone(),
+        });
+    }
+    // Remove surplus elements from the end so earlier indices stay valid.
+    for i in (new.len()..old.len()).rev() {
+        ops.push(PatchOperation::Remove {
+            path: path.join((offset + i).to_string()),
+        });
+    }
+}
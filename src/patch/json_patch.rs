@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use crate::model::{JsonPatchError, JsonValue};
+use crate::pointer::JsonPointer;
+
+/// A single JSON Patch (RFC 6902) operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchOperation {
+    /// Adds `value` at `path` (inserting into arrays, replacing object members).
+    Add { path: JsonPointer, value: JsonValue },
+    /// Removes the value at `path`.
+    Remove { path: JsonPointer },
+    /// Replaces the existing value at `path` with `value`.
+    Replace { path: JsonPointer, value: JsonValue },
+    /// Removes the value at `from` and adds it at `path`.
+    Move {
+        from: JsonPointer,
+        path: JsonPointer,
+    },
+    /// Adds a copy of the value at `from` at `path`.
+    Copy {
+        from: JsonPointer,
+        path: JsonPointer,
+    },
+    /// Checks that the value at `path` equals `value`.
+    Test { path: JsonPointer, value: JsonValue },
+}
+
+impl PatchOperation {
+    /// Returns the operation name as written in the `op` member.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PatchOperation::Add { .. } => "add",
+            PatchOperation::Remove { .. } => "remove",
+            PatchOperation::Replace { .. } => "replace",
+            PatchOperation::Move { .. } => "move",
+            PatchOperation::Copy { .. } => "copy",
+            PatchOperation::Test { .. } => "test",
+        }
+    }
+
+    /// Converts the operation into its JSON object representation.
+    pub fn to_value(&self) -> JsonValue {
+        let mut map = HashMap::new();
+        map.insert("op".to_string(), JsonValue::String(self.name().to_string()));
+
+        let (path, from, value) = match self {
+            PatchOperation::Add { path, value }
+            | PatchOperation::Replace { path, value }
+            | PatchOperation::Test { path, value } => (path, None, Some(value)),
+            PatchOperation::Remove { path } => (path, None, None),
+            PatchOperation::Move { from, path } | PatchOperation::Copy { from, path } => {
+                (path, Some(from), None)
+            }
+        };
+
+        map.insert("path".to_string(), JsonValue::String(path.to_string()));
+        if let Some(from) = from {
+            map.insert("from".to_string(), JsonValue::String(from.to_string()));
+        }
+        if let Some(value) = value {
+            map.insert("value".to_string(), value.clone());
+        }
+        JsonValue::Object(map)
+    }
+
+    /// Applies this single operation in place. On error, `target` may be partially modified.
+    fn apply(&self, target: &mut JsonValue, index: usize) -> Result<(), JsonPatchError> {
+        let pointer_error = |error| JsonPatchError::Pointer {
+            operation: index,
+            error,
+        };
+
+        match self {
+            PatchOperation::Add { path, value } => {
+                path.insert(target, value.clone()).map_err(pointer_error)?;
+            }
+            PatchOperation::Remove { path } => {
+                path.remove(target).map_err(pointer_error)?;
+            }
+            PatchOperation::Replace { path, value } => {
+                path.replace(target, value.clone()).map_err(pointer_error)?;
+            }
+            PatchOperation::Move { from, path } => {
+                if from == path {
+                    from.resolve(target).map_err(pointer_error)?;
+                    return Ok(());
+                }
+                if path.starts_with(from) {
+                    return Err(JsonPatchError::MoveIntoChild {
+                        operation: index,
+                        from: from.to_string(),
+                        path: path.to_string(),
+                    });
+                }
+                let value = from.remove(target).map_err(pointer_error)?;
+                path.insert(target, value).map_err(pointer_error)?;
+            }
+            PatchOperation::Copy { from, path } => {
+                let value = from.resolve(target).map_err(pointer_error)?.clone();
+                path.insert(target, value).map_err(pointer_error)?;
+            }
+            PatchOperation::Test { path, value } => {
+                if path.resolve(target).map_err(pointer_error)? != value {
+                    return Err(JsonPatchError::TestFailed {
+                        operation: index,
+                        path: path.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A JSON Patch document: an ordered list of operations applied as a single unit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonPatch {
+    pub operations: Vec<PatchOperation>,
+}
+
+impl JsonPatch {
+    /// Creates a patch from a list of operations.
+    pub fn new(operations: Vec<PatchOperation>) -> Self {
+        JsonPatch { operations }
+    }
+
+    /// Returns `true` if the patch contains no operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Converts the patch into its JSON array representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::{parse_json, serialize_json};
+    /// use synson::patch::parse_patch;
+    ///
+    /// let doc = parse_json(r#"[{"op": "remove", "path": "/a"}]"#, None).unwrap();
+    /// let patch = parse_patch(&doc).unwrap();
+    /// assert_eq!(serialize_json(&patch.to_value()), r#"[{"op":"remove","path":"/a"}]"#);
+    /// ```
+    pub fn to_value(&self) -> JsonValue {
+        JsonValue::Array(self.operations.iter().map(|op| op.to_value()).collect())
+    }
+}
+
+/// Reads a JSON Patch document into typed operations.
+///
+/// Members other than `op`, `path`, `from` and `value` are ignored, as required by RFC 6902.
+///
+/// # Arguments
+///
+/// * `document` - A JSON array of operation objects.
+///
+/// # Returns
+///
+/// * `Ok(JsonPatch)` if every operation is well formed.
+/// * `Err(JsonPatchError::InvalidPatch)` naming the first malformed operation.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::patch::{parse_patch, PatchOperation};
+///
+/// let doc = parse_json(r#"[{"op": "move", "from": "/a", "path": "/b"}]"#, None).unwrap();
+/// let patch = parse_patch(&doc).unwrap();
+/// assert!(matches!(patch.operations[0], PatchOperation::Move { .. }));
+///
+/// let doc = parse_json(r#"[{"op": "add", "path": "/a"}]"#, None).unwrap();
+/// assert!(parse_patch(&doc).is_err());
+/// ```
+pub fn parse_patch(document: &JsonValue) -> Result<JsonPatch, JsonPatchError> {
+    let JsonValue::Array(items) = document else {
+        return Err(JsonPatchError::InvalidPatch {
+            operation: None,
+            message: "a patch document must be an array".to_string(),
+        });
+    };
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| parse_operation(item, index))
+        .collect::<Result<_, _>>()
+        .map(JsonPatch::new)
+}
+
+fn parse_operation(item: &JsonValue, index: usize) -> Result<PatchOperation, JsonPatchError> {
+    let invalid = |message: String| JsonPatchError::InvalidPatch {
+        operation: Some(index),
+        message,
+    };
+
+    let JsonValue::Object(map) = item else {
+        return Err(invalid("an operation must be an object".to_string()));
+    };
+
+    let pointer = |member: &str| match map.get(member) {
+        Some(JsonValue::String(text)) => {
+            JsonPointer::parse(text).map_err(|e| invalid(format!("invalid '{member}': {e}")))
+        }
+        Some(_) => Err(invalid(format!("'{member}' must be a string"))),
+        None => Err(invalid(format!("missing '{member}'"))),
+    };
+    let value = || {
+        map.get("value")
+            .cloned()
+            .ok_or_else(|| invalid("missing 'value'".to_string()))
+    };
+
+    let op = match map.get("op") {
+        Some(JsonValue::String(op)) => op.as_str(),
+        Some(_) => return Err(invalid("'op' must be a string".to_string())),
+        None => return Err(invalid("missing 'op'".to_string())),
+    };
+
+    Ok(match op {
+        "add" => PatchOperation::Add {
+            path: pointer("path")?,
+            value: value()?,
+        },
+        "remove" => PatchOperation::Remove {
+            path: pointer("path")?,
+        },
+        "replace" => PatchOperation::Replace {
+            path: pointer("path")?,
+            value: value()?,
+        },
+        "move" => PatchOperation::Move {
+            from: pointer("from")?,
+            path: pointer("path")?,
+        },
+        "copy" => PatchOperation::Copy {
+            from: pointer("from")?,
+            path: pointer("path")?,
+        },
+        "test" => PatchOperation::Test {
+            path: pointer("path")?,
+            value: value()?,
+        },
+        other => return Err(invalid(format!("unknown operation '{other}'"))),
+    })
+}
+
+/// Applies a JSON Patch atomically.
+///
+/// Operations are applied in order to a working copy of `target`. If any operation fails,
+/// `target` is left exactly as it was and the error identifies the failing operation.
+///
+/// # Arguments
+///
+/// * `target` - The document to patch.
+/// * `patch` - The operations to apply.
+///
+/// # Returns
+///
+/// * `Ok(())` if every operation succeeded.
+/// * `Err(JsonPatchError)` describing the first failing operation.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::patch::{apply_patch, parse_patch};
+///
+/// let mut doc = parse_json(r#"{"a": 1, "b": [1]}"#, None).unwrap();
+///
+/// let patch = parse_json(r#"[
+///     {"op": "test", "path": "/a", "value": 1},
+///     {"op": "add", "path": "/b/-", "value": 2},
+///     {"op": "move", "from": "/a", "path": "/c"}
+/// ]"#, None).unwrap();
+/// apply_patch(&mut doc, &parse_patch(&patch).unwrap()).unwrap();
+/// assert_eq!(doc, parse_json(r#"{"b": [1, 2], "c": 1}"#, None).unwrap());
+///
+/// // A failing operation rolls back the whole patch.
+/// let patch = parse_json(r#"[
+///     {"op": "remove", "path": "/b"},
+///     {"op": "test", "path": "/c", "value": 2}
+/// ]"#, None).unwrap();
+/// assert!(apply_patch(&mut doc, &parse_patch(&patch).unwrap()).is_err());
+/// assert_eq!(doc, parse_json(r#"{"b": [1, 2], "c": 1}"#, None).unwrap());
+/// ```
+pub fn apply_patch(target: &mut JsonValue, patch: &JsonPatch) -> Result<(), JsonPatchError> {
+    let mut working = target.clone();
+    for (index, operation) in patch.operations.iter().enumerate() {
+        operation.apply(&mut working, index)?;
+    }
+    *target = working;
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::model::JsonValue;
+
+/// Applies a JSON Merge Patch (RFC 7396) to `target` in place.
+///
+/// If `patch` is an object, each member is merged into `target` (which is first turned
+/// into an empty object if it is not one): `null` members delete the corresponding key,
+/// object members are merged recursively, and any other value replaces the existing one.
+/// If `patch` is not an object, it replaces `target` entirely. Arrays are never merged
+/// element-wise.
+///
+/// # Arguments
+///
+/// * `target` - The document to update.
+/// * `patch` - The merge patch.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::patch::merge_patch;
+///
+/// let mut target = parse_json(r#"{"title": "Goodbye!", "author": {"givenName": "John", "familyName": "Doe"}, "tags": ["example", "sample"]}"#, None).unwrap();
+/// let patch = parse_json(r#"{"title": "Hello!", "author": {"familyName": null}, "tags": ["example"], "phoneNumber": "+01-123-456-7890"}"#, None).unwrap();
+///
+/// merge_patch(&mut target, &patch);
+/// assert_eq!(
+///     target,
+///     parse_json(r#"{"title": "Hello!", "author": {"givenName": "John"}, "tags": ["example"], "phoneNumber": "+01-123-456-7890"}"#, None).unwrap()
+/// );
+/// ```
+pub fn merge_patch(target: &mut JsonValue, patch: &JsonValue) {
+    let JsonValue::Object(members) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !matches!(target, JsonValue::Object(_)) {
+        *target = JsonValue::Object(HashMap::new());
+    }
+    let JsonValue::Object(map) = target else {
+        return;
+    };
+
+    for (key, value) in members {
+        if *value == JsonValue::Null {
+            map.remove(key);
+        } else {
+            merge_patch(map.entry(key.clone()).or_insert(JsonValue::Null), value);
+        }
+    }
+}
+
+/// Computes a JSON Merge Patch (RFC 7396) that turns `old` into `new`.
+///
+/// Removed members become `null`, changed objects are described recursively, and every
+/// other change (including any change to an array) is a full replacement. Because `null`
+/// means "delete" in a merge patch, a member whose new value is `null` cannot be
+/// expressed: it is removed when the patch is applied. Use [`create_patch`](super::create_patch)
+/// when such documents must round-trip exactly.
+///
+/// # Arguments
+///
+/// * `old` - The original document.
+/// * `new` - The desired document.
+///
+/// # Returns
+///
+/// The merge patch, an empty object if both documents are equal objects.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::patch::{create_merge_patch, merge_patch};
+///
+/// let old = parse_json(r#"{"a": "b", "c": {"d": "e", "f": "g"}}"#, None).unwrap();
+/// let new = parse_json(r#"{"a": "z", "c": {"d": "e"}}"#, None).unwrap();
+///
+/// let patch = create_merge_patch(&old, &new);
+/// assert_eq!(patch, parse_json(r#"{"a": "z", "c": {"f": null}}"#, None).unwrap());
+///
+/// let mut patched = old.clone();
+/// merge_patch(&mut patched, &patch);
+/// assert_eq!(patched, new);
+/// ```
+pub fn create_merge_patch(old: &JsonValue, new: &JsonValue) -> JsonValue {
+    let (JsonValue::Object(old), JsonValue::Object(new)) = (old, new) else {
+        return new.clone();
+    };
+
+    let mut patch = HashMap::new();
+    for key in old.keys() {
+        if !new.contains_key(key) {
+            patch.insert(key.clone(), JsonValue::Null);
+        }
+    }
+    for (key, value) in new {
+        match old.get(key) {
+            Some(previous) if previous == value => {}
+            Some(previous) => {
+                patch.insert(key.clone(), create_merge_patch(previous, value));
+            }
+            None => {
+                patch.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    JsonValue::Object(patch)
+}
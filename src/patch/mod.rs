@@ -0,0 +1,7 @@
+pub mod diff;
+pub mod json_patch;
+pub mod merge;
+
+pub use diff::create_patch;
+pub use json_patch::{apply_patch, parse_patch, JsonPatch, PatchOperation};
+pub use merge::{create_merge_patch, merge_patch};
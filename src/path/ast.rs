@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::model::JsonValue;
+use crate::regex::Regex;
+
+/// A sequence of segments applied to the root (`$`) or current (`@`) node.
+#[derive(Debug, Clone)]
+pub(crate) struct Query {
+    pub relative: bool,
+    pub segments: Vec<Segment>,
+}
+
+impl Query {
+    /// A singular query contains only name and index selectors in child segments,
+    /// so it produces at most one node.
+    pub fn is_singular(&self) -> bool {
+        self.segments.iter().all(|segment| {
+            !segment.descendant
+                && segment.selectors.len() == 1
+                && matches!(segment.selectors[0], Selector::Name(_) | Selector::Index(_))
+        })
+    }
+}
+
+/// A child (`[...]`, `.name`) or descendant (`..[...]`, `..name`) segment.
+#[derive(Debug, Clone)]
+pub(crate) struct Segment {
+    pub descendant: bool,
+    pub selectors: Vec<Selector>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Selector {
+    Name(String),
+    Wildcard,
+    Index(i64),
+    Slice {
+        start: Option<i64>,
+        end: Option<i64>,
+        step: Option<i64>,
+    },
+    Filter(LogicalExpr),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum LogicalExpr {
+    Or(Vec<LogicalExpr>),
+    And(Vec<LogicalExpr>),
+    Not(Box<LogicalExpr>),
+    Comparison(Comparable, ComparisonOp, Comparable),
+    /// Existence test of a query's nodelist.
+    Exists(Query),
+    /// A function returning a logical value (`match`, `search`).
+    Function(FunctionExpr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ComparisonOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Comparable {
+    Literal(JsonValue),
+    /// A singular query.
+    Query(Query),
+    /// A function returning a value (`length`, `count`, `value`).
+    Function(FunctionExpr),
+}
+
+/// The standard function extensions of RFC 9535.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Function {
+    Length,
+    Count,
+    Match,
+    Search,
+    Value,
+}
+
+/// The declared types of function parameters and results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum FunctionType {
+    Value,
+    Logical,
+    Nodes,
+}
+
+impl Function {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "length" => Function::Length,
+            "count" => Function::Count,
+            "match" => Function::Match,
+            "search" => Function::Search,
+            "value" => Function::Value,
+            _ => return None,
+        })
+    }
+
+    pub fn parameters(self) -> &'static [FunctionType] {
+        match self {
+            Function::Length => &[FunctionType::Value],
+            Function::Count | Function::Value => &[FunctionType::Nodes],
+            Function::Match | Function::Search => &[FunctionType::Value, FunctionType::Value],
+        }
+    }
+
+    pub fn result(self) -> FunctionType {
+        match self {
+            Function::Length | Function::Count | Function::Value => FunctionType::Value,
+            Function::Match | Function::Search => FunctionType::Logical,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FunctionExpr {
+    pub function: Function,
+    pub args: Vec<FunctionArg>,
+    /// The compiled pattern of `match`/`search` when it is a string literal.
+    pub regex: Option<Arc<Regex>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum FunctionArg {
+    Literal(JsonValue),
+    Query(Query),
+    Function(FunctionExpr),
+}
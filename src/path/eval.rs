@@ -0,0 +1,279 @@
+use std::borrow::Cow;
+
+use super::ast::{
+    Comparable, ComparisonOp, Function, FunctionArg, FunctionExpr, LogicalExpr, Query, Segment,
+    Selector,
+};
+use super::normalized::{NormalizedPath, PathElement};
+use crate::model::JsonValue;
+use crate::regex::Regex;
+
+/// A node selected by a query, together with its location.
+pub(crate) type Node<'v> = (NormalizedPath, &'v JsonValue);
+
+/// The result of a function extension.
+enum FunctionResult<'v> {
+    /// A JSON value, or `None` for the special result "Nothing".
+    Value(Option<Cow<'v, JsonValue>>),
+    Logical(bool),
+}
+
+/// Evaluates `query` against the root document and (for `@` queries) the current node.
+pub(crate) fn evaluate<'v>(
+    query: &Query,
+    root: &'v JsonValue,
+    current: &'v JsonValue,
+) -> Vec<Node<'v>> {
+    let start = if query.relative { current } else { root };
+    let mut nodes = vec![(NormalizedPath::default(), start)];
+
+    for segment in &query.segments {
+        let mut next = Vec::new();
+        for (path, value) in &nodes {
+            apply_segment(segment, path, value, root, &mut next);
+        }
+        nodes = next;
+    }
+    nodes
+}
+
+fn apply_segment<'v>(
+    segment: &Segment,
+    path: &NormalizedPath,
+    value: &'v JsonValue,
+    root: &'v JsonValue,
+    out: &mut Vec<Node<'v>>,
+) {
+    for selector in &segment.selectors {
+        select(selector, path, value, root, out);
+    }
+    if segment.descendant {
+        for (child_path, child) in children(path, value) {
+            apply_segment(segment, &child_path, child, root, out);
+        }
+    }
+}
+
+/// Returns the children of a node in document order: array elements by index and
+/// object members by key.
+fn children<'v>(path: &NormalizedPath, value: &'v JsonValue) -> Vec<Node<'v>> {
+    match value {
+        JsonValue::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (path.child(PathElement::Index(index)), item))
+            .collect(),
+        JsonValue::Object(map) => {
+            let mut keys: Vec<_> = map.keys().collect();
+            keys.sort();
+            keys.into_iter()
+                .map(|key| (path.child(PathElement::Name(key.clone())), &map[key]))
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn select<'v>(
+    selector: &Selector,
+    path: &NormalizedPath,
+    value: &'v JsonValue,
+    root: &'v JsonValue,
+    out: &mut Vec<Node<'v>>,
+) {
+    match (selector, value) {
+        (Selector::Name(name), JsonValue::Object(map)) => {
+            if let Some(child) = map.get(name) {
+                out.push((path.child(PathElement::Name(name.clone())), child));
+            }
+        }
+        (Selector::Wildcard, _) => out.extend(children(path, value)),
+        (Selector::Index(index), JsonValue::Array(items)) => {
+            if let Some(index) = normalize_index(*index, items.len()) {
+                out.push((path.child(PathElement::Index(index)), &items[index]));
+            }
+        }
+        (Selector::Slice { start, end, step }, JsonValue::Array(items)) => {
+            for index in slice_indices(*start, *end, *step, items.len()) {
+                out.push((path.child(PathElement::Index(index)), &items[index]));
+            }
+        }
+        (Selector::Filter(expr), _) => {
+            out.extend(
+                children(path, value)
+                    .into_iter()
+                    .filter(|(_, child)| test(expr, root, child)),
+            );
+        }
+        _ => {}
+    }
+}
+
+fn normalize_index(index: i64, len: usize) -> Option<usize> {
+    let len = len as i64;
+    let index = if index < 0 { len + index } else { index };
+    (0..len).contains(&index).then_some(index as usize)
+}
+
+/// Computes the indices selected by `[start:end:step]` as specified in RFC 9535, section 2.3.4.2.
+fn slice_indices(
+    start: Option<i64>,
+    end: Option<i64>,
+    step: Option<i64>,
+    len: usize,
+) -> Vec<usize> {
+    let step = step.unwrap_or(1);
+    let len = len as i64;
+    let normalize = |i: i64| if i >= 0 { i } else { len + i };
+    let mut indices = Vec::new();
+
+    if step > 0 {
+        let lower = normalize(start.unwrap_or(0)).clamp(0, len);
+        let upper = normalize(end.unwrap_or(len)).clamp(0, len);
+        let mut i = lower;
+        while i < upper {
+            indices.push(i as usize);
+            i += step;
+        }
+    } else if step < 0 {
+        let upper = normalize(start.unwrap_or(len - 1)).clamp(-1, len - 1);
+        let lower = normalize(end.unwrap_or(-len - 1)).clamp(-1, len - 1);
+        let mut i = upper;
+        while lower < i {
+            indices.push(i as usize);
+            i += step;
+        }
+    }
+    indices
+}
+
+fn test(expr: &LogicalExpr, root: &JsonValue, current: &JsonValue) -> bool {
+    match expr {
+        LogicalExpr::Or(operands) => operands.iter().any(|e| test(e, root, current)),
+        LogicalExpr::And(operands) => operands.iter().all(|e| test(e, root, current)),
+        LogicalExpr::Not(operand) => !test(operand, root, current),
+        LogicalExpr::Exists(query) => !evaluate(query, root, current).is_empty(),
+        LogicalExpr::Function(function) => match call(function, root, current) {
+            FunctionResult::Logical(result) => result,
+            FunctionResult::Value(_) => false,
+        },
+        LogicalExpr::Comparison(left, op, right) => compare(
+            comparable(left, root, current).as_deref(),
+            *op,
+            comparable(right, root, current).as_deref(),
+        ),
+    }
+}
+
+fn comparable<'v>(
+    operand: &Comparable,
+    root: &'v JsonValue,
+    current: &'v JsonValue,
+) -> Option<Cow<'v, JsonValue>> {
+    match operand {
+        Comparable::Literal(value) => Some(Cow::Owned(value.clone())),
+        Comparable::Query(query) => singular(query, root, current),
+        Comparable::Function(function) => match call(function, root, current) {
+            FunctionResult::Value(value) => value,
+            FunctionResult::Logical(_) => None,
+        },
+    }
+}
+
+fn singular<'v>(
+    query: &Query,
+    root: &'v JsonValue,
+    current: &'v JsonValue,
+) -> Option<Cow<'v, JsonValue>> {
+    evaluate(query, root, current)
+        .into_iter()
+        .next()
+        .map(|(_, value)| Cow::Borrowed(value))
+}
+
+fn compare(left: Option<&JsonValue>, op: ComparisonOp, right: Option<&JsonValue>) -> bool {
+    match op {
+        ComparisonOp::Eq => left == right,
+        ComparisonOp::Ne => left != right,
+        ComparisonOp::Lt => less_than(left, right),
+        ComparisonOp::Le => less_than(left, right) || left == right,
+        ComparisonOp::Gt => less_than(right, left),
+        ComparisonOp::Ge => less_than(right, left) || left == right,
+    }
+}
+
+/// Only numbers and strings are ordered; every other comparison with `<` is false.
+fn less_than(left: Option<&JsonValue>, right: Option<&JsonValue>) -> bool {
+    match (left, right) {
+        (Some(JsonValue::Number(a)), Some(JsonValue::Number(b))) => a < b,
+        (Some(JsonValue::String(a)), Some(JsonValue::String(b))) => a < b,
+        _ => false,
+    }
+}
+
+fn call<'v>(
+    function: &FunctionExpr,
+    root: &'v JsonValue,
+    current: &'v JsonValue,
+) -> FunctionResult<'v> {
+    let value_arg = |index: usize| -> Option<Cow<'v, JsonValue>> {
+        match &function.args[index] {
+            FunctionArg::Literal(value) => Some(Cow::Owned(value.clone())),
+            FunctionArg::Query(query) => singular(query, root, current),
+            FunctionArg::Function(inner) => match call(inner, root, current) {
+                FunctionResult::Value(value) => value,
+                FunctionResult::Logical(_) => None,
+            },
+        }
+    };
+    let nodes_arg = || match &function.args[0] {
+        FunctionArg::Query(query) => evaluate(query, root, current),
+        _ => Vec::new(),
+    };
+
+    match function.function {
+        Function::Length => FunctionResult::Value(match value_arg(0).as_deref() {
+            Some(JsonValue::String(s)) => {
+                Some(Cow::Owned(JsonValue::Number(s.chars().count() as f64)))
+            }
+            Some(JsonValue::Array(items)) => {
+                Some(Cow::Owned(JsonValue::Number(items.len() as f64)))
+            }
+            Some(JsonValue::Object(map)) => Some(Cow::Owned(JsonValue::Number(map.len() as f64))),
+            _ => None,
+        }),
+        Function::Count => FunctionResult::Value(Some(Cow::Owned(JsonValue::Number(
+            nodes_arg().len() as f64,
+        )))),
+        Function::Value => {
+            let nodes = nodes_arg();
+            FunctionResult::Value(match nodes.as_slice() {
+                [(_, value)] => Some(Cow::Borrowed(*value)),
+                _ => None,
+            })
+        }
+        Function::Match | Function::Search => {
+            let matched = match (value_arg(0).as_deref(), value_arg(1).as_deref()) {
+                (Some(JsonValue::String(input)), Some(JsonValue::String(pattern))) => {
+                    let compiled;
+                    let regex = match &function.regex {
+                        Some(regex) => Some(regex.as_ref()),
+                        None => {
+                            compiled = Regex::new(pattern).ok();
+                            compiled.as_ref()
+                        }
+                    };
+                    regex.is_some_and(|regex| {
+                        if function.function == Function::Match {
+                            regex.is_full_match(input)
+                        } else {
+                            regex.is_match(input)
+                        }
+                    })
+                }
+                _ => false,
+            };
+            FunctionResult::Logical(matched)
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::ast::Query;
+use super::eval::evaluate;
+use super::normalized::NormalizedPath;
+use super::parser::PathParser;
+use crate::model::{JsonParseError, JsonValue};
+
+/// A compiled JSONPath query (RFC 9535).
+///
+/// The expression is parsed and type-checked once and can then be evaluated against any
+/// number of documents. Results are returned in document order; object members are
+/// visited in key order, since `JsonValue` objects do not keep their insertion order.
+///
+/// Supported syntax covers the whole RFC: name, wildcard, index, slice and filter
+/// selectors, child and descendant segments, and the `length`, `count`, `match`,
+/// `search` and `value` functions. Regular expressions follow I-Regexp (RFC 9485).
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_json, JsonValue};
+/// use synson::path::JsonPath;
+///
+/// let doc = parse_json(r#"{"store": {"book": [
+///     {"title": "A", "price": 8},
+///     {"title": "B", "price": 22}
+/// ]}}"#, None).unwrap();
+///
+/// let path = JsonPath::parse("$.store.book[?@.price < 10].title").unwrap();
+/// let matches = path.query(&doc);
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(matches[0].path.to_string(), "$['store']['book'][0]['title']");
+/// assert_eq!(matches[0].value, &JsonValue::String("A".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonPath {
+    source: String,
+    query: Query,
+}
+
+/// A node selected by a [`JsonPath`] query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPathMatch<'a> {
+    /// The normalized path of the node.
+    pub path: NormalizedPath,
+    /// The selected value.
+    pub value: &'a JsonValue,
+}
+
+impl JsonPath {
+    /// Parses a JSONPath expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - A query starting with `$`, e.g. `"$..book[0]"`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(JsonPath)` if the expression is valid and well-typed.
+    /// * `Err(JsonParseError)` pointing at the offending position in `expression`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::path::JsonPath;
+    ///
+    /// assert!(JsonPath::parse("$.a[1:3]").is_ok());
+    ///
+    /// let err = JsonPath::parse("$[?@.* == 1]").unwrap_err();
+    /// assert_eq!(err.message, "Only singular queries can be compared");
+    /// assert_eq!(err.index, 3);
+    /// ```
+    pub fn parse(expression: &str) -> Result<Self, JsonParseError> {
+        Ok(JsonPath {
+            source: expression.to_string(),
+            query: PathParser::parse(expression)?,
+        })
+    }
+
+    /// Evaluates the query and returns the selected nodes with their normalized paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The document (`$`) to query.
+    ///
+    /// # Returns
+    ///
+    /// The selected nodes in document order; duplicates are kept, as the RFC requires.
+    pub fn query<'a>(&self, value: &'a JsonValue) -> Vec<JsonPathMatch<'a>> {
+        evaluate(&self.query, value, value)
+            .into_iter()
+            .map(|(path, value)| JsonPathMatch { path, value })
+            .collect()
+    }
+
+    /// Evaluates the query and returns only the selected values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::{parse_json, serialize_json};
+    /// use synson::path::JsonPath;
+    ///
+    /// let doc = parse_json("[1, 2, 3, 4, 5]", None).unwrap();
+    /// let values = JsonPath::parse("$[::-2]").unwrap().query_values(&doc);
+    /// let text: Vec<_> = values.into_iter().map(serialize_json).collect();
+    /// assert_eq!(text, ["5", "3", "1"]);
+    /// ```
+    pub fn query_values<'a>(&self, value: &'a JsonValue) -> Vec<&'a JsonValue> {
+        evaluate(&self.query, value, value)
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect()
+    }
+}
+
+impl fmt::Display for JsonPath {
+    /// Writes the expression the query was parsed from.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for JsonPath {
+    type Err = JsonParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JsonPath::parse(s)
+    }
+}
+
+/// Parses `expression` and evaluates it against `value` in one step.
+///
+/// # Arguments
+///
+/// * `value` - The document to query.
+/// * `expression` - A JSONPath expression.
+///
+/// # Returns
+///
+/// * `Ok(Vec<JsonPathMatch>)` with the selected nodes.
+/// * `Err(JsonParseError)` if the expression is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::path::query_path;
+///
+/// let doc = parse_json(r#"{"a": [{"b": 1}, {"b": 2}]}"#, None).unwrap();
+/// let matches = query_path(&doc, "$..b").unwrap();
+/// let paths: Vec<_> = matches.iter().map(|m| m.path.to_string()).collect();
+/// assert_eq!(paths, ["$['a'][0]['b']", "$['a'][1]['b']"]);
+/// ```
+pub fn query_path<'a>(
+    value: &'a JsonValue,
+    expression: &str,
+) -> Result<Vec<JsonPathMatch<'a>>, JsonParseError> {
+    Ok(JsonPath::parse(expression)?.query(value))
+}
@@ -0,0 +1,10 @@
+//! JSONPath (RFC 9535) queries over [`JsonValue`](crate::JsonValue) documents.
+
+mod ast;
+mod eval;
+pub mod json_path;
+pub mod normalized;
+mod parser;
+
+pub use json_path::{query_path, JsonPath, JsonPathMatch};
+pub use normalized::{NormalizedPath, PathElement};
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use crate::pointer::JsonPointer;
+
+/// One step of a [`NormalizedPath`]: an object member name or an array index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathElement {
+    Name(String),
+    Index(usize),
+}
+
+/// The location of a node matched by a JSONPath query, as defined by RFC 9535.
+///
+/// Its textual form is the normalized path (e.g. `$['store']['book'][0]`); it can also be
+/// converted into a [`JsonPointer`] to modify the matched node.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NormalizedPath {
+    elements: Vec<PathElement>,
+}
+
+impl NormalizedPath {
+    /// Returns the steps from the root to the node.
+    pub fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+
+    /// Converts the path into the equivalent JSON Pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::path::JsonPath;
+    ///
+    /// let doc = parse_json(r#"{"a/b": [1, 2]}"#, None).unwrap();
+    /// let matches = JsonPath::parse("$['a/b'][1]").unwrap().query(&doc);
+    /// assert_eq!(matches[0].path.to_pointer().to_string(), "/a~1b/1");
+    /// ```
+    pub fn to_pointer(&self) -> JsonPointer {
+        JsonPointer::from_tokens(self.elements.iter().map(|element| match element {
+            PathElement::Name(name) => name.clone(),
+            PathElement::Index(index) => index.to_string(),
+        }))
+    }
+
+    pub(crate) fn child(&self, element: PathElement) -> Self {
+        let mut elements = self.elements.clone();
+        elements.push(element);
+        NormalizedPath { elements }
+    }
+}
+
+impl fmt::Display for NormalizedPath {
+    /// Formats the normalized path, e.g. `$['a'][0]`, escaping names as RFC 9535 requires.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("$")?;
+        for element in &self.elements {
+            match element {
+                PathElement::Index(index) => write!(f, "[{index}]")?,
+                PathElement::Name(name) => {
+                    f.write_str("['")?;
+                    for c in name.chars() {
+                        match c {
+                            '\u{0008}' => f.write_str("\\b")?,
+                            '\u{000C}' => f.write_str("\\f")?,
+                            '\n' => f.write_str("\\n")?,
+                            '\r' => f.write_str("\\r")?,
+                            '\t' => f.write_str("\\t")?,
+                            '\'' => f.write_str("\\'")?,
+                            '\\' => f.write_str("\\\\")?,
+                            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                            c => write!(f, "{c}")?,
+                        }
+                    }
+                    f.write_str("']")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,645 @@
+use std::sync::Arc;
+
+use super::ast::{
+    Comparable, ComparisonOp, Function, FunctionArg, FunctionExpr, FunctionType, LogicalExpr,
+    Query, Segment, Selector,
+};
+use crate::model::{JsonParseError, JsonValue};
+use crate::parser::parse_number;
+use crate::parser::string::{combine_surrogates, is_high_surrogate};
+use crate::regex::Regex;
+
+/// How deeply logical expressions, filters and function calls may nest. Each level takes
+/// several stack frames, so this is lower than the JSON parser's `MAX_DEPTH`.
+const MAX_DEPTH: usize = 128;
+
+/// Largest integer allowed in index and slice selectors (the I-JSON safe range).
+const MAX_INTEGER: i64 = (1 << 53) - 1;
+
+/// Recursive-descent parser for JSONPath expressions (RFC 9535).
+pub(crate) struct PathParser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// How many logical expressions and function calls enclose the current position.
+    depth: usize,
+}
+
+impl<'a> PathParser<'a> {
+    /// Parses a complete JSONPath query, which must start with `$`.
+    pub(crate) fn parse(input: &'a str) -> Result<Query, JsonParseError> {
+        let mut parser = PathParser {
+            input,
+            pos: 0,
+            depth: 0,
+        };
+
+        if !parser.eat('$') {
+            return Err(parser.error("Expected '$' to start JSONPath query"));
+        }
+        let query = Query {
+            relative: false,
+            segments: parser.segments()?,
+        };
+
+        if parser.pos < input.len() {
+            return Err(parser.error("Unexpected character in JSONPath query"));
+        }
+        Ok(query)
+    }
+
+    fn segments(&mut self) -> Result<Vec<Segment>, JsonParseError> {
+        let mut segments = Vec::new();
+        loop {
+            let save = self.pos;
+            self.skip_blank();
+
+            if self.peek() == Some('[') {
+                segments.push(Segment {
+                    descendant: false,
+                    selectors: self.bracketed_selection()?,
+                });
+            } else if self.rest().starts_with("..") {
+                self.pos += 2;
+                segments.push(Segment {
+                    descendant: true,
+                    selectors: self.descendant_selectors()?,
+                });
+            } else if self.eat('.') {
+                segments.push(Segment {
+                    descendant: false,
+                    selectors: vec![self.shorthand_selector()?],
+                });
+            } else {
+                self.pos = save;
+                return Ok(segments);
+            }
+        }
+    }
+
+    fn descendant_selectors(&mut self) -> Result<Vec<Selector>, JsonParseError> {
+        if self.peek() == Some('[') {
+            self.bracketed_selection()
+        } else {
+            Ok(vec![self.shorthand_selector()?])
+        }
+    }
+
+    /// Parses `*` or a member name after `.` or `..`.
+    fn shorthand_selector(&mut self) -> Result<Selector, JsonParseError> {
+        if self.eat('*') {
+            return Ok(Selector::Wildcard);
+        }
+        match self.peek() {
+            Some(c) if is_name_first(c) => Ok(Selector::Name(self.name())),
+            _ => Err(self.error("Expected member name or '*' after '.'")),
+        }
+    }
+
+    fn name(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| is_name_first(c) || c.is_ascii_digit())
+        {
+            self.advance();
+        }
+        self.input[start..self.pos].to_string()
+    }
+
+    fn bracketed_selection(&mut self) -> Result<Vec<Selector>, JsonParseError> {
+        self.expect('[', "Expected '['")?;
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_blank();
+            selectors.push(self.selector()?);
+            self.skip_blank();
+            if self.eat(']') {
+                return Ok(selectors);
+            }
+            self.expect(',', "Expected ',' or ']' in bracketed selection")?;
+        }
+    }
+
+    fn selector(&mut self) -> Result<Selector, JsonParseError> {
+        match self.peek() {
+            Some(quote @ ('\'' | '"')) => Ok(Selector::Name(self.string_literal(quote)?)),
+            Some('*') => {
+                self.advance();
+                Ok(Selector::Wildcard)
+            }
+            Some('?') => {
+                self.advance();
+                self.skip_blank();
+                Ok(Selector::Filter(self.logical_or()?))
+            }
+            Some(':') => self.slice(None),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let start = self.integer()?;
+                let save = self.pos;
+                self.skip_blank();
+                if self.peek() == Some(':') {
+                    self.slice(Some(start))
+                } else {
+                    self.pos = save;
+                    Ok(Selector::Index(start))
+                }
+            }
+            _ => Err(self.error("Expected selector")),
+        }
+    }
+
+    /// Parses the rest of a slice selector, positioned on its first `:`.
+    fn slice(&mut self, start: Option<i64>) -> Result<Selector, JsonParseError> {
+        self.expect(':', "Expected ':' in slice selector")?;
+        self.skip_blank();
+        let end = self.optional_integer()?;
+        self.skip_blank();
+
+        let step = if self.eat(':') {
+            self.skip_blank();
+            self.optional_integer()?
+        } else {
+            None
+        };
+
+        Ok(Selector::Slice { start, end, step })
+    }
+
+    fn optional_integer(&mut self) -> Result<Option<i64>, JsonParseError> {
+        match self.peek() {
+            Some(c) if c == '-' || c.is_ascii_digit() => self.integer().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parses an integer without leading zeros (and not `-0`) in the I-JSON range.
+    fn integer(&mut self) -> Result<i64, JsonParseError> {
+        let start = self.pos;
+        self.eat('-');
+        let digits_start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.advance();
+        }
+
+        let digits = &self.input[digits_start..self.pos];
+        let text = &self.input[start..self.pos];
+        let valid =
+            !digits.is_empty() && (!digits.starts_with('0') || digits == "0") && text != "-0";
+
+        text.parse::<i64>()
+            .ok()
+            .filter(|n| valid && n.abs() <= MAX_INTEGER)
+            .ok_or_else(|| JsonParseError::new("Invalid integer in selector", start, self.input))
+    }
+
+    /// Enters a nested expression, failing past [`MAX_DEPTH`] levels rather than
+    /// exhausting the stack.
+    fn enter(&mut self) -> Result<(), JsonParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(&format!("Nesting is deeper than {MAX_DEPTH} levels")));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn logical_or(&mut self) -> Result<LogicalExpr, JsonParseError> {
+        self.enter()?;
+        let mut operands = vec![self.logical_and()?];
+        while self.eat_operator("||") {
+            operands.push(self.logical_and()?);
+        }
+        self.depth -= 1;
+        Ok(if operands.len() == 1 {
+            operands.remove(0)
+        } else {
+            LogicalExpr::Or(operands)
+        })
+    }
+
+    fn logical_and(&mut self) -> Result<LogicalExpr, JsonParseError> {
+        let mut operands = vec![self.basic_expr()?];
+        while self.eat_operator("&&") {
+            operands.push(self.basic_expr()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.remove(0)
+        } else {
+            LogicalExpr::And(operands)
+        })
+    }
+
+    fn basic_expr(&mut self) -> Result<LogicalExpr, JsonParseError> {
+        self.skip_blank();
+
+        if self.eat('!') {
+            self.skip_blank();
+            let operand = if self.peek() == Some('(') {
+                self.paren_expr()?
+            } else {
+                self.test_expr()?
+            };
+            return Ok(LogicalExpr::Not(Box::new(operand)));
+        }
+
+        if self.peek() == Some('(') {
+            return self.paren_expr();
+        }
+
+        let start = self.pos;
+        let left = self.comparable_or_test()?;
+        match (left, self.comparison_op()) {
+            (Operand::Query(query), None) => Ok(LogicalExpr::Exists(query)),
+            (Operand::Function(function), None) => self.logical_function(function, start),
+            (Operand::Literal(_), None) => {
+                Err(self.error("Expected comparison operator after literal"))
+            }
+            (left, Some(op)) => {
+                let left = self.comparable(left, start)?;
+                self.skip_blank();
+                let right_start = self.pos;
+                let right = self.comparable_or_test()?;
+                let right = self.comparable(right, right_start)?;
+                Ok(LogicalExpr::Comparison(left, op, right))
+            }
+        }
+    }
+
+    fn paren_expr(&mut self) -> Result<LogicalExpr, JsonParseError> {
+        self.expect('(', "Expected '('")?;
+        let expr = self.logical_or()?;
+        self.skip_blank();
+        self.expect(')', "Expected ')' to close expression")?;
+        Ok(expr)
+    }
+
+    /// Parses a query or function used as an existence or logical test.
+    fn test_expr(&mut self) -> Result<LogicalExpr, JsonParseError> {
+        let start = self.pos;
+        match self.comparable_or_test()? {
+            Operand::Query(query) => Ok(LogicalExpr::Exists(query)),
+            Operand::Function(function) => self.logical_function(function, start),
+            Operand::Literal(_) => Err(JsonParseError::new(
+                "Expected query or function after '!'",
+                start,
+                self.input,
+            )),
+        }
+    }
+
+    fn logical_function(
+        &self,
+        function: FunctionExpr,
+        start: usize,
+    ) -> Result<LogicalExpr, JsonParseError> {
+        if function.function.result() == FunctionType::Value {
+            return Err(JsonParseError::new(
+                "Function result must be compared",
+                start,
+                self.input,
+            ));
+        }
+        Ok(LogicalExpr::Function(function))
+    }
+
+    /// Checks that an operand can be compared: a literal, a singular query or a value function.
+    fn comparable(&self, operand: Operand, start: usize) -> Result<Comparable, JsonParseError> {
+        match operand {
+            Operand::Literal(value) => Ok(Comparable::Literal(value)),
+            Operand::Query(query) if query.is_singular() => Ok(Comparable::Query(query)),
+            Operand::Query(_) => Err(JsonParseError::new(
+                "Only singular queries can be compared",
+                start,
+                self.input,
+            )),
+            Operand::Function(function) if function.function.result() == FunctionType::Value => {
+                Ok(Comparable::Function(function))
+            }
+            Operand::Function(_) => Err(JsonParseError::new(
+                "Function result cannot be compared",
+                start,
+                self.input,
+            )),
+        }
+    }
+
+    fn comparison_op(&mut self) -> Option<ComparisonOp> {
+        let save = self.pos;
+        self.skip_blank();
+
+        for (token, op) in [
+            ("==", ComparisonOp::Eq),
+            ("!=", ComparisonOp::Ne),
+            ("<=", ComparisonOp::Le),
+            (">=", ComparisonOp::Ge),
+            ("<", ComparisonOp::Lt),
+            (">", ComparisonOp::Gt),
+        ] {
+            if self.rest().starts_with(token) {
+                self.pos += token.len();
+                return Some(op);
+            }
+        }
+
+        self.pos = save;
+        None
+    }
+
+    fn comparable_or_test(&mut self) -> Result<Operand, JsonParseError> {
+        match self.peek() {
+            Some('@') => {
+                self.advance();
+                Ok(Operand::Query(Query {
+                    relative: true,
+                    segments: self.segments()?,
+                }))
+            }
+            Some('$') => {
+                self.advance();
+                Ok(Operand::Query(Query {
+                    relative: false,
+                    segments: self.segments()?,
+                }))
+            }
+            Some(c) if c.is_ascii_lowercase() && !self.at_keyword_literal() => {
+                Ok(Operand::Function(self.function()?))
+            }
+            _ => Ok(Operand::Literal(self.literal()?)),
+        }
+    }
+
+    fn at_keyword_literal(&self) -> bool {
+        ["true", "false", "null"].iter().any(|keyword| {
+            self.rest().strip_prefix(keyword).is_some_and(|after| {
+                !after
+                    .chars()
+                    .next()
+                    .is_some_and(|c| is_name_first(c) || c.is_ascii_digit() || c == '(')
+            })
+        })
+    }
+
+    fn literal(&mut self) -> Result<JsonValue, JsonParseError> {
+        match self.peek() {
+            Some(quote @ ('\'' | '"')) => Ok(JsonValue::String(self.string_literal(quote)?)),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let start = self.pos;
+                let (value, rest) = parse_number(self.rest())
+                    .map_err(|e| JsonParseError::new(&e.message, start + e.index, self.input))?;
+                self.pos = self.input.len() - rest.len();
+                Ok(value)
+            }
+            _ => {
+                for (keyword, value) in [
+                    ("true", JsonValue::Bool(true)),
+                    ("false", JsonValue::Bool(false)),
+                    ("null", JsonValue::Null),
+                ] {
+                    if self.at_keyword_literal() && self.rest().starts_with(keyword) {
+                        self.pos += keyword.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error("Expected literal, query or function"))
+            }
+        }
+    }
+
+    fn function(&mut self) -> Result<FunctionExpr, JsonParseError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            self.advance();
+        }
+        let name = &self.input[start..self.pos];
+
+        if self.peek() != Some('(') {
+            return Err(JsonParseError::new(
+                "Expected literal, query or function",
+                start,
+                self.input,
+            ));
+        }
+        let function = Function::from_name(name).ok_or_else(|| {
+            JsonParseError::new(&format!("Unknown function '{name}'"), start, self.input)
+        })?;
+        self.advance();
+        self.enter()?;
+
+        let mut args = Vec::new();
+        self.skip_blank();
+        if !self.eat(')') {
+            loop {
+                self.skip_blank();
+                let arg_start = self.pos;
+                let parameter = function.parameters().get(args.len()).copied();
+                let operand = self.comparable_or_test()?;
+                args.push(self.argument(operand, parameter, arg_start)?);
+                self.skip_blank();
+                if self.eat(')') {
+                    break;
+                }
+                self.expect(',', "Expected ',' or ')' in function arguments")?;
+            }
+        }
+        self.depth -= 1;
+
+        if args.len() != function.parameters().len() {
+            return Err(JsonParseError::new(
+                &format!(
+                    "Function '{name}' expects {} argument(s)",
+                    function.parameters().len()
+                ),
+                start,
+                self.input,
+            ));
+        }
+
+        let regex = match (function, args.get(1)) {
+            (
+                Function::Match | Function::Search,
+                Some(FunctionArg::Literal(JsonValue::String(p))),
+            ) => Regex::new(p).ok().map(Arc::new),
+            _ => None,
+        };
+
+        Ok(FunctionExpr {
+            function,
+            args,
+            regex,
+        })
+    }
+
+    /// Checks a function argument against the declared parameter type.
+    fn argument(
+        &self,
+        operand: Operand,
+        parameter: Option<FunctionType>,
+        start: usize,
+    ) -> Result<FunctionArg, JsonParseError> {
+        let mismatch = |message: &str| Err(JsonParseError::new(message, start, self.input));
+
+        match (parameter, operand) {
+            (None, _) => mismatch("Too many function arguments"),
+            (Some(FunctionType::Value), Operand::Literal(value)) => Ok(FunctionArg::Literal(value)),
+            (Some(FunctionType::Value), Operand::Query(query)) if query.is_singular() => {
+                Ok(FunctionArg::Query(query))
+            }
+            (Some(FunctionType::Value), Operand::Query(_)) => {
+                mismatch("Only singular queries can be used as value arguments")
+            }
+            (Some(FunctionType::Nodes), Operand::Query(query)) => Ok(FunctionArg::Query(query)),
+            (Some(expected), Operand::Function(function))
+                if function.function.result() == expected =>
+            {
+                Ok(FunctionArg::Function(function))
+            }
+            (Some(_), _) => mismatch("Function argument has the wrong type"),
+        }
+    }
+
+    fn string_literal(&mut self, quote: char) -> Result<String, JsonParseError> {
+        let start = self.pos;
+        self.advance();
+        let mut result = String::new();
+
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(JsonParseError::new(
+                    "Unterminated string literal",
+                    start,
+                    self.input,
+                ));
+            };
+            self.advance();
+
+            match c {
+                c if c == quote => return Ok(result),
+                '\\' => result.push(self.string_escape(quote)?),
+                c if (c as u32) < 0x20 => {
+                    return Err(self.error_before("Control characters must be escaped in strings"));
+                }
+                c => result.push(c),
+            }
+        }
+    }
+
+    fn string_escape(&mut self, quote: char) -> Result<char, JsonParseError> {
+        let escape_start = self.pos - 1;
+        let invalid = |parser: &Self| {
+            JsonParseError::new(
+                "Invalid escape sequence in string",
+                escape_start,
+                parser.input,
+            )
+        };
+
+        let c = self.peek().ok_or_else(|| invalid(self))?;
+        self.advance();
+        Ok(match c {
+            'b' => '\u{0008}',
+            'f' => '\u{000C}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '/' => '/',
+            '\\' => '\\',
+            c if c == quote => c,
+            'u' => {
+                let unit = self.hex4().ok_or_else(|| invalid(self))?;
+                if !is_high_surrogate(unit) {
+                    return char::from_u32(unit).ok_or_else(|| invalid(self));
+                }
+                if !self.rest().starts_with("\\u") {
+                    return Err(invalid(self));
+                }
+                self.pos += 2;
+                let low = self.hex4().ok_or_else(|| invalid(self))?;
+                combine_surrogates(unit, low).ok_or_else(|| invalid(self))?
+            }
+            _ => return Err(invalid(self)),
+        })
+    }
+
+    fn hex4(&mut self) -> Option<u32> {
+        let digits = self.input.get(self.pos..self.pos + 4)?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    fn eat_operator(&mut self, operator: &str) -> bool {
+        let save = self.pos;
+        self.skip_blank();
+        if self.rest().starts_with(operator) {
+            self.pos += operator.len();
+            true
+        } else {
+            self.pos = save;
+            false
+        }
+    }
+
+    fn skip_blank(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.advance();
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn advance(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &str) -> Result<(), JsonParseError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    fn error(&self, message: &str) -> JsonParseError {
+        JsonParseError::new(message, self.pos, self.input)
+    }
+
+    fn error_before(&self, message: &str) -> JsonParseError {
+        let previous = self.input[..self.pos]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index);
+        JsonParseError::new(message, previous, self.input)
+    }
+}
+
+/// An operand of a filter expression before its role is known.
+enum Operand {
+    Literal(JsonValue),
+    Query(Query),
+    Function(FunctionExpr),
+}
+
+fn is_name_first(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+}
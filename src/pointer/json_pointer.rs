@@ -0,0 +1,375 @@
+use std::fmt;
+use std::mem;
+use std::str::FromStr;
+
+use crate::model::{JsonPointerError, JsonValue};
+
+/// A parsed JSON Pointer (RFC 6901), i.e. a sequence of unescaped reference tokens.
+///
+/// The empty pointer (`""`) refers to the whole document. Each `/`-separated token
+/// selects an object member by key or an array element by index; `~1` and `~0` in the
+/// textual form stand for `/` and `~`. The special token `-` designates the position
+/// past the last array element and is only meaningful for insertion.
+///
+/// # Examples
+///
+/// ```
+/// use synson::pointer::JsonPointer;
+///
+/// let pointer = JsonPointer::parse("/a~1b/m~0n/0").unwrap();
+/// assert_eq!(pointer.tokens(), ["a/b", "m~n", "0"]);
+/// assert_eq!(pointer.to_string(), "/a~1b/m~0n/0");
+/// assert!(JsonPointer::parse("no-slash").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct JsonPointer {
+    tokens: Vec<String>,
+}
+
+impl JsonPointer {
+    /// Creates the empty pointer, which refers to the whole document.
+    pub fn root() -> Self {
+        JsonPointer::default()
+    }
+
+    /// Parses the textual representation of a JSON Pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `pointer` - Either `""` or a string starting with `/`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(JsonPointer)` with the unescaped reference tokens.
+    /// * `Err(JsonPointerError::Syntax)` if the pointer does not start with `/` or contains
+    ///   an invalid `~` escape.
+    pub fn parse(pointer: &str) -> Result<Self, JsonPointerError> {
+        if pointer.is_empty() {
+            return Ok(Self::root());
+        }
+
+        let Some(body) = pointer.strip_prefix('/') else {
+            return Err(JsonPointerError::Syntax {
+                pointer: pointer.to_string(),
+                message: "a non-empty pointer must start with '/'".to_string(),
+            });
+        };
+
+        let tokens = body
+            .split('/')
+            .map(|token| {
+                unescape_token(token).ok_or_else(|| JsonPointerError::Syntax {
+                    pointer: pointer.to_string(),
+                    message: "'~' must be followed by '0' or '1'".to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(JsonPointer { tokens })
+    }
+
+    /// Builds a pointer from already unescaped reference tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::pointer::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::from_tokens(["a/b", "0"]);
+    /// assert_eq!(pointer.to_string(), "/a~1b/0");
+    /// ```
+    pub fn from_tokens<I, S>(tokens: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        JsonPointer {
+            tokens: tokens.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the unescaped reference tokens.
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+
+    /// Returns `true` if this is the empty pointer referring to the whole document.
+    pub fn is_root(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Appends an unescaped reference token.
+    pub fn push(&mut self, token: impl Into<String>) {
+        self.tokens.push(token.into());
+    }
+
+    /// Returns a new pointer with `token` appended.
+    pub fn join(&self, token: impl Into<String>) -> Self {
+        let mut pointer = self.clone();
+        pointer.push(token);
+        pointer
+    }
+
+    /// Returns the pointer to the parent value, or `None` for the root.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.tokens.split_last()?;
+        Some(JsonPointer {
+            tokens: parent.to_vec(),
+        })
+    }
+
+    /// Returns `true` if `self` equals `other` or points inside the value it refers to.
+    pub fn starts_with(&self, other: &JsonPointer) -> bool {
+        self.tokens.starts_with(&other.tokens)
+    }
+
+    /// Looks up the value this pointer refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The document to search.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&JsonValue)` if every token resolves.
+    /// * `Err(JsonPointerError)` describing the first token that could not be resolved.
+    pub fn resolve<'a>(&self, value: &'a JsonValue) -> Result<&'a JsonValue, JsonPointerError> {
+        let mut current = value;
+        for depth in 0..self.tokens.len() {
+            current = match current {
+                JsonValue::Object(map) => map
+                    .get(&self.tokens[depth])
+                    .ok_or_else(|| self.key_not_found(depth))?,
+                JsonValue::Array(items) => &items[self.array_index(depth, items.len())?],
+                _ => return Err(self.not_a_container(depth)),
+            };
+        }
+        Ok(current)
+    }
+
+    /// Looks up a mutable reference to the value this pointer refers to.
+    ///
+    /// See [`resolve`](JsonPointer::resolve) for the error cases.
+    pub fn resolve_mut<'a>(
+        &self,
+        value: &'a mut JsonValue,
+    ) -> Result<&'a mut JsonValue, JsonPointerError> {
+        self.resolve_prefix_mut(value, self.tokens.len())
+    }
+
+    /// Adds a value at the location this pointer refers to, following the `add`
+    /// semantics of JSON Patch (RFC 6902).
+    ///
+    /// An existing object member is replaced, an array index inserts before the element
+    /// at that position (the array length and `-` append), and the root pointer replaces
+    /// the whole document. The parent of the target must already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The document to modify.
+    /// * `value` - The value to add.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(previous))` if an object member or the root was replaced.
+    /// * `Ok(None)` if a new member or array element was added.
+    /// * `Err(JsonPointerError)` if the parent does not exist or the index is invalid.
+    pub fn insert(
+        &self,
+        target: &mut JsonValue,
+        value: JsonValue,
+    ) -> Result<Option<JsonValue>, JsonPointerError> {
+        let Some(last) = self.tokens.len().checked_sub(1) else {
+            return Ok(Some(mem::replace(target, value)));
+        };
+
+        match self.resolve_prefix_mut(target, last)? {
+            JsonValue::Object(map) => Ok(map.insert(self.tokens[last].clone(), value)),
+            JsonValue::Array(items) => {
+                let index = if self.tokens[last] == "-" {
+                    items.len()
+                } else {
+                    self.parse_index(last)?
+                };
+                if index > items.len() {
+                    return Err(JsonPointerError::IndexOutOfBounds {
+                        path: self.prefix(last + 1),
+                        index,
+                        len: items.len(),
+                    });
+                }
+                items.insert(index, value);
+                Ok(None)
+            }
+            _ => Err(self.not_a_container(last)),
+        }
+    }
+
+    /// Removes the value this pointer refers to.
+    ///
+    /// Removing an array element shifts the following elements down.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(removed)` with the removed value.
+    /// * `Err(JsonPointerError::RootNotAllowed)` for the root pointer.
+    /// * `Err(JsonPointerError)` if the target does not exist.
+    pub fn remove(&self, target: &mut JsonValue) -> Result<JsonValue, JsonPointerError> {
+        let Some(last) = self.tokens.len().checked_sub(1) else {
+            return Err(JsonPointerError::RootNotAllowed);
+        };
+
+        match self.resolve_prefix_mut(target, last)? {
+            JsonValue::Object(map) => map
+                .remove(&self.tokens[last])
+                .ok_or_else(|| self.key_not_found(last)),
+            JsonValue::Array(items) => {
+                let index = self.array_index(last, items.len())?;
+                Ok(items.remove(index))
+            }
+            _ => Err(self.not_a_container(last)),
+        }
+    }
+
+    /// Replaces the existing value this pointer refers to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(previous)` with the replaced value.
+    /// * `Err(JsonPointerError)` if the target does not exist.
+    pub fn replace(
+        &self,
+        target: &mut JsonValue,
+        value: JsonValue,
+    ) -> Result<JsonValue, JsonPointerError> {
+        Ok(mem::replace(self.resolve_mut(target)?, value))
+    }
+
+    /// Resolves the first `depth` tokens mutably.
+    fn resolve_prefix_mut<'a>(
+        &self,
+        value: &'a mut JsonValue,
+        depth: usize,
+    ) -> Result<&'a mut JsonValue, JsonPointerError> {
+        let mut current = value;
+        for i in 0..depth {
+            current = match current {
+                JsonValue::Object(map) => map
+                    .get_mut(&self.tokens[i])
+                    .ok_or_else(|| self.key_not_found(i))?,
+                JsonValue::Array(items) => {
+                    let index = self.array_index(i, items.len())?;
+                    &mut items[index]
+                }
+                _ => return Err(self.not_a_container(i)),
+            };
+        }
+        Ok(current)
+    }
+
+    /// Parses the token at `depth` as an array index strictly below `len`.
+    fn array_index(&self, depth: usize, len: usize) -> Result<usize, JsonPointerError> {
+        let token = &self.tokens[depth];
+
+        if token == "-" {
+            return Err(JsonPointerError::IndexOutOfBounds {
+                path: self.prefix(depth + 1),
+                index: len,
+                len,
+            });
+        }
+
+        let index = self.parse_index(depth)?;
+        if index >= len {
+            return Err(JsonPointerError::IndexOutOfBounds {
+                path: self.prefix(depth + 1),
+                index,
+                len,
+            });
+        }
+        Ok(index)
+    }
+
+    /// Parses the token at `depth` as a canonical array index, without a bound.
+    fn parse_index(&self, depth: usize) -> Result<usize, JsonPointerError> {
+        let token = &self.tokens[depth];
+        let is_canonical = !token.is_empty()
+            && token.bytes().all(|b| b.is_ascii_digit())
+            && (token == "0" || !token.starts_with('0'));
+        token
+            .parse::<usize>()
+            .ok()
+            .filter(|_| is_canonical)
+            .ok_or_else(|| JsonPointerError::InvalidIndex {
+                path: self.prefix(depth + 1),
+                token: token.clone(),
+            })
+    }
+
+    fn key_not_found(&self, depth: usize) -> JsonPointerError {
+        JsonPointerError::KeyNotFound {
+            path: self.prefix(depth + 1),
+            key: self.tokens[depth].clone(),
+        }
+    }
+
+    fn not_a_container(&self, depth: usize) -> JsonPointerError {
+        JsonPointerError::NotAContainer {
+            path: self.prefix(depth + 1),
+        }
+    }
+
+    /// Formats the pointer made of the first `len` tokens.
+    fn prefix(&self, len: usize) -> String {
+        JsonPointer::from_tokens(self.tokens[..len].iter().cloned()).to_string()
+    }
+}
+
+impl fmt::Display for JsonPointer {
+    /// Formats the pointer in its textual form, escaping `~` and `/` in tokens.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.tokens {
+            write!(f, "/{}", escape_token(token))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for JsonPointer {
+    type Err = JsonPointerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JsonPointer::parse(s)
+    }
+}
+
+/// Escapes a reference token for use in the textual form of a pointer (`~` → `~0`, `/` → `~1`).
+///
+/// # Examples
+///
+/// ```
+/// use synson::pointer::escape_token;
+///
+/// assert_eq!(escape_token("a/b~c"), "a~1b~0c");
+/// ```
+pub fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Unescapes a reference token, returning `None` on a `~` not followed by `0` or `1`.
+fn unescape_token(token: &str) -> Option<String> {
+    let mut result = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('0') => result.push('~'),
+            Some('1') => result.push('/'),
+            _ => return None,
+        }
+    }
+    Some(result)
+}
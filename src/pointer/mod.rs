@@ -0,0 +1,4 @@
+pub mod json_pointer;
+pub mod value;
+
+pub use json_pointer::{escape_token, JsonPointer};
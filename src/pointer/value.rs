@@ -0,0 +1,116 @@
+use super::JsonPointer;
+use crate::model::{JsonPointerError, JsonValue};
+
+impl JsonValue {
+    /// Looks up a value by JSON Pointer (RFC 6901).
+    ///
+    /// # Arguments
+    ///
+    /// * `pointer` - A pointer such as `"/a/b/0"`, or `""` for the whole document.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&JsonValue)` if the pointer resolves.
+    /// * `Err(JsonPointerError)` if the pointer is malformed or a token cannot be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::model::{JsonPointerError, JsonValue};
+    ///
+    /// let doc = parse_json(r#"{"a": {"b": [10, 20]}, "c/d": true}"#, None).unwrap();
+    ///
+    /// assert_eq!(doc.pointer("/a/b/1"), Ok(&JsonValue::Number(20.0)));
+    /// assert_eq!(doc.pointer("/c~1d"), Ok(&JsonValue::Bool(true)));
+    /// assert_eq!(
+    ///     doc.pointer("/a/x"),
+    ///     Err(JsonPointerError::KeyNotFound { path: "/a/x".to_string(), key: "x".to_string() })
+    /// );
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Result<&JsonValue, JsonPointerError> {
+        JsonPointer::parse(pointer)?.resolve(self)
+    }
+
+    /// Looks up a mutable reference to a value by JSON Pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::model::JsonValue;
+    ///
+    /// let mut doc = parse_json(r#"{"server": {"port": 80}}"#, None).unwrap();
+    /// *doc.pointer_mut("/server/port").unwrap() = JsonValue::Number(8080.0);
+    ///
+    /// assert_eq!(doc.pointer("/server/port"), Ok(&JsonValue::Number(8080.0)));
+    /// ```
+    pub fn pointer_mut(&mut self, pointer: &str) -> Result<&mut JsonValue, JsonPointerError> {
+        JsonPointer::parse(pointer)?.resolve_mut(self)
+    }
+
+    /// Adds a value at a JSON Pointer, creating an object member or inserting into an
+    /// array (`-` appends). See [`JsonPointer::insert`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(previous))` if an existing object member or the root was replaced.
+    /// * `Ok(None)` if a new member or array element was added.
+    /// * `Err(JsonPointerError)` if the parent does not exist or the index is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::model::JsonValue;
+    ///
+    /// let mut doc = parse_json(r#"{"tags": ["a"]}"#, None).unwrap();
+    /// doc.pointer_insert("/tags/-", JsonValue::String("b".to_string())).unwrap();
+    /// doc.pointer_insert("/tags/0", JsonValue::String("first".to_string())).unwrap();
+    ///
+    /// assert_eq!(doc, parse_json(r#"{"tags": ["first", "a", "b"]}"#, None).unwrap());
+    /// ```
+    pub fn pointer_insert(
+        &mut self,
+        pointer: &str,
+        value: JsonValue,
+    ) -> Result<Option<JsonValue>, JsonPointerError> {
+        JsonPointer::parse(pointer)?.insert(self, value)
+    }
+
+    /// Removes the value at a JSON Pointer. See [`JsonPointer::remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::model::JsonValue;
+    ///
+    /// let mut doc = parse_json(r#"{"a": [1, 2, 3]}"#, None).unwrap();
+    /// assert_eq!(doc.pointer_remove("/a/1"), Ok(JsonValue::Number(2.0)));
+    /// assert_eq!(doc, parse_json(r#"{"a": [1, 3]}"#, None).unwrap());
+    /// ```
+    pub fn pointer_remove(&mut self, pointer: &str) -> Result<JsonValue, JsonPointerError> {
+        JsonPointer::parse(pointer)?.remove(self)
+    }
+
+    /// Replaces the existing value at a JSON Pointer. See [`JsonPointer::replace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::model::JsonValue;
+    ///
+    /// let mut doc = parse_json(r#"{"debug": false}"#, None).unwrap();
+    /// assert_eq!(doc.pointer_replace("/debug", JsonValue::Bool(true)), Ok(JsonValue::Bool(false)));
+    /// assert!(doc.pointer_replace("/missing", JsonValue::Null).is_err());
+    /// ```
+    pub fn pointer_replace(
+        &mut self,
+        pointer: &str,
+        value: JsonValue,
+    ) -> Result<JsonValue, JsonPointerError> {
+        JsonPointer::parse(pointer)?.replace(self, value)
+    }
+}
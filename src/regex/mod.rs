@@ -0,0 +1,45 @@
+//! A small engine for I-Regexp (RFC 9485), the interoperable regular expression
+//! subset used by JSONPath filter functions, with a slightly wider dialect for
+//! JSON Schema patterns.
+
+mod parser;
+mod program;
+
+use crate::model::JsonParseError;
+use parser::Parser;
+use program::Program;
+
+/// A compiled I-Regexp.
+#[derive(Debug, Clone)]
+pub(crate) struct Regex {
+    program: Program,
+}
+
+impl Regex {
+    /// Compiles an I-Regexp, reporting syntax errors with their position in `pattern`.
+    pub(crate) fn new(pattern: &str) -> Result<Self, JsonParseError> {
+        let node = Parser::new(pattern).parse()?;
+        Ok(Regex {
+            program: Program::compile(&node),
+        })
+    }
+
+    /// Compiles a JSON Schema `pattern`: an I-Regexp that may also use the class escapes
+    /// and the `^` / `$` assertions accepted by [`Parser::ecma`].
+    pub(crate) fn new_ecma(pattern: &str) -> Result<Self, JsonParseError> {
+        let node = Parser::new(pattern).ecma().parse()?;
+        Ok(Regex {
+            program: Program::compile(&node),
+        })
+    }
+
+    /// Returns `true` if the whole of `input` matches.
+    pub(crate) fn is_full_match(&self, input: &str) -> bool {
+        self.program.run(input, true, true)
+    }
+
+    /// Returns `true` if some substring of `input` matches.
+    pub(crate) fn is_match(&self, input: &str) -> bool {
+        self.program.run(input, false, false)
+    }
+}
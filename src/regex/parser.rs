@@ -0,0 +1,482 @@
+use crate::model::JsonParseError;
+use crate::parser::MAX_DEPTH;
+
+/// A Unicode general category (or category group) usable in `\p{..}`.
+///
+/// Categories are approximated with the character properties available in `std`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Category {
+    Letter,
+    UppercaseLetter,
+    LowercaseLetter,
+    Number,
+    DecimalNumber,
+    Punctuation,
+    Separator,
+    SpaceSeparator,
+    Other,
+    Control,
+}
+
+impl Category {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "L" => Category::Letter,
+            "Lu" => Category::UppercaseLetter,
+            "Ll" => Category::LowercaseLetter,
+            "N" => Category::Number,
+            "Nd" => Category::DecimalNumber,
+            "P" => Category::Punctuation,
+            "Z" => Category::Separator,
+            "Zs" => Category::SpaceSeparator,
+            "C" => Category::Other,
+            "Cc" => Category::Control,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn contains(self, c: char) -> bool {
+        match self {
+            Category::Letter => c.is_alphabetic() && !c.is_numeric(),
+            Category::UppercaseLetter => c.is_uppercase(),
+            Category::LowercaseLetter => c.is_lowercase(),
+            Category::Number => c.is_numeric(),
+            Category::DecimalNumber => c.is_ascii_digit() || is_nd(c),
+            Category::Punctuation => {
+                (c.is_ascii_punctuation() && !"$+<=>^`|~".contains(c))
+                    || matches!(c, '\u{2010}'..='\u{2027}' | '\u{3001}'..='\u{3003}')
+            }
+            Category::Separator => c.is_whitespace() && !c.is_control(),
+            Category::SpaceSeparator => {
+                c.is_whitespace() && !c.is_control() && !matches!(c, '\u{2028}' | '\u{2029}')
+            }
+            Category::Other | Category::Control => c.is_control(),
+        }
+    }
+}
+
+/// Decimal digits outside ASCII (`Nd`), recognized by their digit blocks.
+fn is_nd(c: char) -> bool {
+    const ZEROS: [u32; 8] = [
+        0x0660, 0x06F0, 0x0966, 0x09E6, 0x0E50, 0xFF10, 0x1D7CE, 0x1D7F6,
+    ];
+    ZEROS
+        .iter()
+        .any(|&zero| (zero..zero + 10).contains(&(c as u32)))
+}
+
+/// An ECMA-262 class escape (`\d`, `\w`, `\s`), accepted in JSON Schema patterns.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Shorthand {
+    Digit,
+    Word,
+    Space,
+}
+
+impl Shorthand {
+    fn contains(self, c: char) -> bool {
+        match self {
+            Shorthand::Digit => c.is_ascii_digit(),
+            Shorthand::Word => c.is_ascii_alphanumeric() || c == '_',
+            Shorthand::Space => c.is_whitespace() || c == '\u{FEFF}',
+        }
+    }
+}
+
+/// One member of a bracketed character class.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ClassItem {
+    Range(char, char),
+    Category(Category, bool),
+    Shorthand(Shorthand, bool),
+}
+
+/// A set of characters matched by a single atom.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum CharSet {
+    /// `.`: any character except `\n` and `\r`.
+    Any,
+    Literal(char),
+    Class {
+        negated: bool,
+        items: Vec<ClassItem>,
+    },
+}
+
+impl CharSet {
+    pub(crate) fn contains(&self, c: char) -> bool {
+        match self {
+            CharSet::Any => c != '\n' && c != '\r',
+            CharSet::Literal(expected) => c == *expected,
+            CharSet::Class { negated, items } => {
+                let found = items.iter().any(|item| match item {
+                    ClassItem::Range(low, high) => (*low..=*high).contains(&c),
+                    ClassItem::Category(category, negated) => category.contains(c) != *negated,
+                    ClassItem::Shorthand(shorthand, negated) => shorthand.contains(c) != *negated,
+                });
+                found != *negated
+            }
+        }
+    }
+}
+
+/// A zero-width assertion of the ECMA-262 dialect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Assertion {
+    /// `^`: the start of the input.
+    Start,
+    /// `$`: the end of the input.
+    End,
+}
+
+/// The syntax tree of an I-Regexp.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    Set(CharSet),
+    Assert(Assertion),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl Node {
+    /// The number of instructions the node compiles to, saturating at `u64::MAX`.
+    pub(crate) fn program_size(&self) -> u64 {
+        match self {
+            Node::Set(_) | Node::Assert(_) => 1,
+            Node::Concat(nodes) => nodes
+                .iter()
+                .fold(0, |size, node| size.saturating_add(node.program_size())),
+            Node::Alternation(branches) => branches
+                .iter()
+                .fold(2 * (branches.len() as u64 - 1), |size, branch| {
+                    size.saturating_add(branch.program_size())
+                }),
+            Node::Repeat { node, min, max } => {
+                let body = node.program_size();
+                let optional = match max {
+                    None => body.saturating_add(2),
+                    Some(max) => u64::from(max - min).saturating_mul(body.saturating_add(1)),
+                };
+                u64::from(*min)
+                    .saturating_mul(body)
+                    .saturating_add(optional)
+            }
+        }
+    }
+}
+
+/// Upper bound for `{n,m}` quantifiers, which are expanded when compiled.
+const MAX_REPEAT: u32 = 1000;
+
+/// Upper bound for the number of instructions a pattern compiles to, so that nested
+/// quantifiers such as `((a{1000}){1000}){1000}` cannot exhaust memory.
+const MAX_PROGRAM_SIZE: u64 = 100_000;
+
+/// Recursive-descent parser for the I-Regexp grammar (RFC 9485).
+pub(crate) struct Parser<'a> {
+    pattern: &'a str,
+    chars: Vec<(usize, char)>,
+    pos: usize,
+    ecma: bool,
+    /// How many groups enclose the current position.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(pattern: &'a str) -> Self {
+        Parser {
+            pattern,
+            chars: pattern.char_indices().collect(),
+            pos: 0,
+            ecma: false,
+            depth: 0,
+        }
+    }
+
+    /// Also accepts the ECMA-262 escapes common in JSON Schema patterns: `\d`, `\w`, `\s`
+    /// and their negations, plus `\/`, `\$`, `\f` and `\v`, and reads `^` and `$` outside
+    /// character classes as assertions rather than literals.
+    pub(crate) fn ecma(mut self) -> Self {
+        self.ecma = true;
+        self
+    }
+
+    pub(crate) fn parse(mut self) -> Result<Node, JsonParseError> {
+        let node = self.alternation()?;
+        match self.peek() {
+            None if node.program_size() > MAX_PROGRAM_SIZE => {
+                Err(self.error("Regular expression is too large"))
+            }
+            None => Ok(node),
+            Some(')') => Err(self.error("Unmatched ')' in regular expression")),
+            Some(_) => Err(self.error("Unexpected character in regular expression")),
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Node, JsonParseError> {
+        let mut branches = vec![self.branch()?];
+        while self.eat('|') {
+            branches.push(self.branch()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.remove(0)
+        } else {
+            Node::Alternation(branches)
+        })
+    }
+
+    fn branch(&mut self) -> Result<Node, JsonParseError> {
+        let mut pieces = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            pieces.push(self.quantifier(atom)?);
+        }
+        Ok(Node::Concat(pieces))
+    }
+
+    fn atom(&mut self) -> Result<Node, JsonParseError> {
+        let c = self
+            .next()
+            .ok_or_else(|| self.error("Unexpected end of regular expression"))?;
+        let set = match c {
+            '(' => {
+                if self.depth == MAX_DEPTH {
+                    return Err(
+                        self.error_before(&format!("Nesting is deeper than {MAX_DEPTH} levels"))
+                    );
+                }
+                self.depth += 1;
+                let inner = self.alternation()?;
+                if !self.eat(')') {
+                    return Err(self.error("Expected ')' in regular expression"));
+                }
+                self.depth -= 1;
+                return Ok(inner);
+            }
+            '^' if self.ecma => return Ok(Node::Assert(Assertion::Start)),
+            '$' if self.ecma => return Ok(Node::Assert(Assertion::End)),
+            '.' => CharSet::Any,
+            '[' => self.class()?,
+            '\\' => self.escape()?,
+            '*' | '+' | '?' | '{' => {
+                return Err(self.error_before("Quantifier without a preceding atom"));
+            }
+            ']' | '}' => return Err(self.error_before("Unescaped bracket in regular expression")),
+            c => CharSet::Literal(c),
+        };
+        Ok(Node::Set(set))
+    }
+
+    fn quantifier(&mut self, atom: Node) -> Result<Node, JsonParseError> {
+        if let Node::Assert(_) = atom {
+            return match self.peek() {
+                Some('*' | '+' | '?' | '{') => {
+                    Err(self.error("Quantifier without a preceding atom"))
+                }
+                _ => Ok(atom),
+            };
+        }
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                self.next();
+                let min = self.number()?;
+                let max = if self.eat(',') {
+                    if self.peek() == Some('}') {
+                        None
+                    } else {
+                        Some(self.number()?)
+                    }
+                } else {
+                    Some(min)
+                };
+                if !self.eat('}') {
+                    return Err(self.error("Expected '}' to close quantifier"));
+                }
+                if max.is_some_and(|max| max < min) {
+                    return Err(self.error_before("Quantifier range is out of order"));
+                }
+                let repeat = Node::Repeat {
+                    node: Box::new(atom),
+                    min,
+                    max,
+                };
+                if repeat.program_size() > MAX_PROGRAM_SIZE {
+                    return Err(self.error_before("Regular expression is too large"));
+                }
+                return Ok(repeat);
+            }
+            _ => return Ok(atom),
+        };
+        self.next();
+        Ok(Node::Repeat {
+            node: Box::new(atom),
+            min,
+            max,
+        })
+    }
+
+    fn number(&mut self) -> Result<u32, JsonParseError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.next();
+        }
+        let digits: String = self.chars[start..self.pos].iter().map(|(_, c)| c).collect();
+        digits
+            .parse::<u32>()
+            .ok()
+            .filter(|n| *n <= MAX_REPEAT)
+            .ok_or_else(|| self.error("Invalid quantifier bound"))
+    }
+
+    fn class(&mut self) -> Result<CharSet, JsonParseError> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        let mut first = true;
+
+        loop {
+            let c = self
+                .next()
+                .ok_or_else(|| self.error("Unterminated character class"))?;
+            match c {
+                ']' if first => return Err(self.error_before("Empty character class")),
+                ']' => break,
+                '-' if first || self.peek() == Some(']') => items.push(ClassItem::Range('-', '-')),
+                '-' => return Err(self.error_before("Unescaped '-' in character class")),
+                '[' => return Err(self.error_before("Unescaped '[' in character class")),
+                '\\' => match self.escape()? {
+                    CharSet::Literal(low) => items.push(self.range_from(low)?),
+                    CharSet::Class { items: escaped, .. } => items.extend(escaped),
+                    CharSet::Any => {}
+                },
+                c => items.push(self.range_from(c)?),
+            }
+            first = false;
+        }
+
+        Ok(CharSet::Class { negated, items })
+    }
+
+    /// Reads the optional `-high` part of a range starting at `low`.
+    fn range_from(&mut self, low: char) -> Result<ClassItem, JsonParseError> {
+        if self.peek() != Some('-') || self.peek_at(1) == Some(']') || self.peek_at(1).is_none() {
+            return Ok(ClassItem::Range(low, low));
+        }
+        self.next();
+
+        let high = match self.next() {
+            Some('\\') => match self.escape()? {
+                CharSet::Literal(high) => high,
+                _ => return Err(self.error_before("Invalid character class range")),
+            },
+            Some('[') => return Err(self.error_before("Unescaped '[' in character class")),
+            Some(c) => c,
+            None => return Err(self.error("Unterminated character class")),
+        };
+
+        if high < low {
+            return Err(self.error_before("Character class range is out of order"));
+        }
+        Ok(ClassItem::Range(low, high))
+    }
+
+    /// Parses the escape following a `\`.
+    fn escape(&mut self) -> Result<CharSet, JsonParseError> {
+        let c = self
+            .next()
+            .ok_or_else(|| self.error("Unterminated escape in regular expression"))?;
+        Ok(match c {
+            'n' => CharSet::Literal('\n'),
+            'r' => CharSet::Literal('\r'),
+            't' => CharSet::Literal('\t'),
+            '(' | ')' | '*' | '+' | '-' | '.' | '?' | '[' | '\\' | ']' | '^' | '{' | '|' | '}' => {
+                CharSet::Literal(c)
+            }
+            'd' | 'D' | 'w' | 'W' | 's' | 'S' if self.ecma => {
+                let shorthand = match c.to_ascii_lowercase() {
+                    'd' => Shorthand::Digit,
+                    'w' => Shorthand::Word,
+                    _ => Shorthand::Space,
+                };
+                CharSet::Class {
+                    negated: false,
+                    items: vec![ClassItem::Shorthand(shorthand, c.is_ascii_uppercase())],
+                }
+            }
+            '/' | '$' if self.ecma => CharSet::Literal(c),
+            'f' if self.ecma => CharSet::Literal('\u{000C}'),
+            'v' if self.ecma => CharSet::Literal('\u{000B}'),
+            'p' | 'P' => {
+                if !self.eat('{') {
+                    return Err(self.error("Expected '{' after \\p"));
+                }
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c != '}') {
+                    self.next();
+                }
+                let name: String = self.chars[start..self.pos].iter().map(|(_, c)| c).collect();
+                if !self.eat('}') {
+                    return Err(self.error("Expected '}' to close \\p{...}"));
+                }
+                let category = Category::from_name(&name)
+                    .ok_or_else(|| self.error_before("Unsupported Unicode category"))?;
+                CharSet::Class {
+                    negated: false,
+                    items: vec![ClassItem::Category(category, c == 'P')],
+                }
+            }
+            _ => return Err(self.error_before("Invalid escape in regular expression")),
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).map(|(_, c)| *c)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn offset(&self, pos: usize) -> usize {
+        self.chars
+            .get(pos)
+            .map_or(self.pattern.len(), |(index, _)| *index)
+    }
+
+    fn error(&self, message: &str) -> JsonParseError {
+        JsonParseError::new(message, self.offset(self.pos), self.pattern)
+    }
+
+    fn error_before(&self, message: &str) -> JsonParseError {
+        JsonParseError::new(
+            message,
+            self.offset(self.pos.saturating_sub(1)),
+            self.pattern,
+        )
+    }
+}
@@ -0,0 +1,175 @@
+use super::parser::{Assertion, CharSet, Node};
+
+/// An instruction of the compiled automaton.
+#[derive(Debug, Clone)]
+enum Inst {
+    Char(CharSet),
+    Assert(Assertion),
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+/// A compiled regular expression, executed as a Thompson NFA (Pike VM).
+///
+/// Matching runs in `O(pattern * input)` time, so no input can trigger catastrophic
+/// backtracking.
+#[derive(Debug, Clone)]
+pub(crate) struct Program {
+    insts: Vec<Inst>,
+}
+
+impl Program {
+    pub(crate) fn compile(node: &Node) -> Self {
+        let mut insts = Vec::new();
+        emit(node, &mut insts);
+        insts.push(Inst::Match);
+        Program { insts }
+    }
+
+    /// Runs the automaton. When `anchored` is false, a match may start anywhere.
+    /// Returns `true` as soon as a match is found (`full` requires it to end at the end of input).
+    pub(crate) fn run(&self, input: &str, anchored: bool, full: bool) -> bool {
+        let mut current = ThreadList::new(self.insts.len());
+        let mut next = ThreadList::new(self.insts.len());
+        self.add_thread(&mut current, 0, 0, input);
+
+        for (index, c) in input.char_indices() {
+            if !full && current.contains_match(&self.insts) {
+                return true;
+            }
+            if current.is_empty() && anchored {
+                return false;
+            }
+
+            next.clear();
+            let position = index + c.len_utf8();
+            for &pc in &current.threads {
+                if let Inst::Char(set) = &self.insts[pc] {
+                    if set.contains(c) {
+                        self.add_thread(&mut next, pc + 1, position, input);
+                    }
+                }
+            }
+            if !anchored {
+                self.add_thread(&mut next, 0, position, input);
+            }
+            std::mem::swap(&mut current, &mut next);
+        }
+
+        current.contains_match(&self.insts)
+    }
+
+    /// Adds `pc` and every instruction reachable from it without consuming input, at byte
+    /// `position` of `input`.
+    fn add_thread(&self, list: &mut ThreadList, pc: usize, position: usize, input: &str) {
+        let mut stack = vec![pc];
+        while let Some(pc) = stack.pop() {
+            if !list.mark(pc) {
+                continue;
+            }
+            match self.insts[pc] {
+                Inst::Assert(Assertion::Start) if position == 0 => stack.push(pc + 1),
+                Inst::Assert(Assertion::End) if position == input.len() => stack.push(pc + 1),
+                Inst::Assert(_) => {}
+                Inst::Jump(target) => stack.push(target),
+                Inst::Split(first, second) => {
+                    stack.push(second);
+                    stack.push(first);
+                }
+                Inst::Char(_) | Inst::Match => list.threads.push(pc),
+            }
+        }
+    }
+}
+
+/// The set of active instruction pointers for one input position.
+struct ThreadList {
+    threads: Vec<usize>,
+    seen: Vec<bool>,
+}
+
+impl ThreadList {
+    fn new(size: usize) -> Self {
+        ThreadList {
+            threads: Vec::new(),
+            seen: vec![false; size],
+        }
+    }
+
+    fn mark(&mut self, pc: usize) -> bool {
+        !std::mem::replace(&mut self.seen[pc], true)
+    }
+
+    fn clear(&mut self) {
+        self.threads.clear();
+        self.seen.iter_mut().for_each(|seen| *seen = false);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.threads.is_empty()
+    }
+
+    fn contains_match(&self, insts: &[Inst]) -> bool {
+        self.threads
+            .iter()
+            .any(|&pc| matches!(insts[pc], Inst::Match))
+    }
+}
+
+fn emit(node: &Node, insts: &mut Vec<Inst>) {
+    match node {
+        Node::Set(set) => insts.push(Inst::Char(set.clone())),
+        Node::Assert(assertion) => insts.push(Inst::Assert(*assertion)),
+        Node::Concat(nodes) => nodes.iter().for_each(|node| emit(node, insts)),
+        Node::Alternation(branches) => {
+            // split L1, next; L1: branch; jump end; next: split ...
+            let mut jumps = Vec::new();
+            for (i, branch) in branches.iter().enumerate() {
+                if i + 1 < branches.len() {
+                    let split = insts.len();
+                    insts.push(Inst::Split(split + 1, 0));
+                    emit(branch, insts);
+                    jumps.push(insts.len());
+                    insts.push(Inst::Jump(0));
+                    let next = insts.len();
+                    insts[split] = Inst::Split(split + 1, next);
+                } else {
+                    emit(branch, insts);
+                }
+            }
+            let end = insts.len();
+            for jump in jumps {
+                insts[jump] = Inst::Jump(end);
+            }
+        }
+        Node::Repeat { node, min, max } => {
+            for _ in 0..*min {
+                emit(node, insts);
+            }
+            match max {
+                None => {
+                    // loop: split body, end; body; jump loop
+                    let split = insts.len();
+                    insts.push(Inst::Split(split + 1, 0));
+                    emit(node, insts);
+                    insts.push(Inst::Jump(split));
+                    let end = insts.len();
+                    insts[split] = Inst::Split(split + 1, end);
+                }
+                Some(max) => {
+                    let mut splits = Vec::new();
+                    for _ in *min..*max {
+                        splits.push(insts.len());
+                        insts.push(Inst::Split(insts.len() + 1, 0));
+                        emit(node, insts);
+                    }
+                    let end = insts.len();
+                    for split in splits {
+                        insts[split] = Inst::Split(split + 1, end);
+                    }
+                }
+            }
+        }
+    }
+}
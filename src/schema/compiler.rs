@@ -0,0 +1,432 @@
+use std::collections::HashMap;
+
+use super::node::{Body, Keyword, Node, Rule, SimpleType};
+use crate::model::{JsonSchemaError, JsonValue};
+use crate::pointer::JsonPointer;
+use crate::regex::Regex;
+
+/// The keywords that produce rules, in the order they are evaluated.
+const KEYWORDS: [&str; 31] = [
+    "$ref",
+    "type",
+    "enum",
+    "const",
+    "multipleOf",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "contains",
+    "prefixItems",
+    "items",
+    "maxProperties",
+    "minProperties",
+    "required",
+    "dependentRequired",
+    "properties",
+    "patternProperties",
+    "additionalProperties",
+    "propertyNames",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+    "if",
+];
+
+/// Compiles the schema document `root` into a node list whose first node is the root
+/// schema.
+pub(crate) fn compile(root: &JsonValue) -> Result<Vec<Node>, JsonSchemaError> {
+    let mut compiler = Compiler {
+        root,
+        nodes: Vec::new(),
+        compiled: HashMap::new(),
+        anchors: HashMap::new(),
+    };
+    collect_anchors(root, &JsonPointer::root(), &mut compiler.anchors);
+    compiler.node(JsonPointer::root())?;
+    Ok(compiler.nodes)
+}
+
+struct Compiler<'a> {
+    root: &'a JsonValue,
+    nodes: Vec<Node>,
+    /// Node indexes by schema location, so shared and recursive references compile once.
+    compiled: HashMap<String, usize>,
+    /// `$anchor` names and the location of the schema declaring them.
+    anchors: HashMap<String, JsonPointer>,
+}
+
+impl Compiler<'_> {
+    /// Compiles the schema at `location`, or returns its index if it already was.
+    fn node(&mut self, location: JsonPointer) -> Result<usize, JsonSchemaError> {
+        let key = location.to_string();
+        if let Some(&index) = self.compiled.get(&key) {
+            return Ok(index);
+        }
+
+        let root = self.root;
+        let value = location
+            .resolve(root)
+            .map_err(|_| JsonSchemaError::new(location.clone(), "Schema not found"))?;
+
+        // Register the node before compiling its children so cycles resolve to it.
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            location: location.clone(),
+            body: Body::Always(true),
+        });
+        self.compiled.insert(key, index);
+
+        let body = match value {
+            JsonValue::Bool(b) => Body::Always(*b),
+            JsonValue::Object(map) => Body::Rules(self.rules(map, &location)?),
+            _ => {
+                return Err(JsonSchemaError::new(
+                    location,
+                    "A schema must be an object or a boolean",
+                ))
+            }
+        };
+        self.nodes[index].body = body;
+        Ok(index)
+    }
+
+    fn nodes(
+        &mut self,
+        value: &JsonValue,
+        location: &JsonPointer,
+    ) -> Result<Vec<usize>, JsonSchemaError> {
+        match value {
+            JsonValue::Array(items) if !items.is_empty() => (0..items.len())
+                .map(|i| self.node(location.join(i.to_string())))
+                .collect(),
+            _ => Err(JsonSchemaError::new(
+                location.clone(),
+                "Expected a non-empty array of schemas",
+            )),
+        }
+    }
+
+    fn rules(
+        &mut self,
+        map: &HashMap<String, JsonValue>,
+        location: &JsonPointer,
+    ) -> Result<Vec<Rule>, JsonSchemaError> {
+        let mut rules = Vec::new();
+
+        for name in KEYWORDS {
+            let Some(value) = map.get(name) else {
+                continue;
+            };
+            let at = location.join(name);
+
+            let keyword = match name {
+                "$ref" => {
+                    let reference = string(value, &at)?;
+                    let target = self.reference(reference, &at)?;
+                    Keyword::Ref(self.node(target)?)
+                }
+                "type" => Keyword::Type(types(value, &at)?),
+                "enum" => match value {
+                    JsonValue::Array(values) => Keyword::Enum(values.clone()),
+                    _ => return Err(JsonSchemaError::new(at, "Expected an array")),
+                },
+                "const" => Keyword::Const(value.clone()),
+                "multipleOf" => match value {
+                    JsonValue::Number(n) if *n > 0.0 => Keyword::MultipleOf(*n),
+                    _ => return Err(JsonSchemaError::new(at, "Expected a number greater than 0")),
+                },
+                "maximum" => Keyword::Maximum(number(value, &at)?),
+                "exclusiveMaximum" => Keyword::ExclusiveMaximum(number(value, &at)?),
+                "minimum" => Keyword::Minimum(number(value, &at)?),
+                "exclusiveMinimum" => Keyword::ExclusiveMinimum(number(value, &at)?),
+                "maxLength" => Keyword::MaxLength(count(value, &at)?),
+                "minLength" => Keyword::MinLength(count(value, &at)?),
+                "pattern" => {
+                    let source = string(value, &at)?;
+                    Keyword::Pattern(pattern(source, &at)?, source.to_string())
+                }
+                "maxItems" => Keyword::MaxItems(count(value, &at)?),
+                "minItems" => Keyword::MinItems(count(value, &at)?),
+                "uniqueItems" => match value {
+                    JsonValue::Bool(true) => Keyword::UniqueItems,
+                    JsonValue::Bool(false) => continue,
+                    _ => return Err(JsonSchemaError::new(at, "Expected a boolean")),
+                },
+                "contains" => {
+                    let min = match map.get("minContains") {
+                        Some(min) => count(min, &location.join("minContains"))?,
+                        None => 1,
+                    };
+                    let max = match map.get("maxContains") {
+                        Some(max) => Some(count(max, &location.join("maxContains"))?),
+                        None => None,
+                    };
+                    Keyword::Contains {
+                        node: self.node(at.clone())?,
+                        min,
+                        max,
+                    }
+                }
+                "prefixItems" => Keyword::PrefixItems(self.nodes(value, &at)?),
+                "items" => {
+                    if value.is_array() {
+                        return Err(JsonSchemaError::new(
+                            at,
+                            "Expected a schema; use 'prefixItems' for positional items",
+                        ));
+                    }
+                    let skip = map
+                        .get("prefixItems")
+                        .and_then(JsonValue::as_array)
+                        .map_or(0, Vec::len);
+                    Keyword::Items {
+                        node: self.node(at.clone())?,
+                        skip,
+                    }
+                }
+                "maxProperties" => Keyword::MaxProperties(count(value, &at)?),
+                "minProperties" => Keyword::MinProperties(count(value, &at)?),
+                "required" => Keyword::Required(strings(value, &at)?),
+                "dependentRequired" => {
+                    let members = object(value, &at)?;
+                    let mut dependencies = members
+                        .iter()
+                        .map(|(name, required)| {
+                            Ok((name.clone(), strings(required, &at.join(name.as_str()))?))
+                        })
+                        .collect::<Result<Vec<_>, JsonSchemaError>>()?;
+                    dependencies.sort_by(|a, b| a.0.cmp(&b.0));
+                    Keyword::DependentRequired(dependencies)
+                }
+                "properties" => {
+                    let mut names: Vec<_> = object(value, &at)?.keys().cloned().collect();
+                    names.sort();
+                    let properties = names
+                        .into_iter()
+                        .map(|name| {
+                            let node = self.node(at.join(name.as_str()))?;
+                            Ok((name, node))
+                        })
+                        .collect::<Result<_, JsonSchemaError>>()?;
+                    Keyword::Properties(properties)
+                }
+                "patternProperties" => {
+                    let mut sources: Vec<_> = object(value, &at)?.keys().cloned().collect();
+                    sources.sort();
+                    let patterns = sources
+                        .into_iter()
+                        .map(|source| {
+                            let regex = pattern(&source, &at.join(source.as_str()))?;
+                            let node = self.node(at.join(source))?;
+                            Ok((regex, node))
+                        })
+                        .collect::<Result<_, JsonSchemaError>>()?;
+                    Keyword::PatternProperties(patterns)
+                }
+                "additionalProperties" => {
+                    let properties = map
+                        .get("properties")
+                        .and_then(JsonValue::as_object)
+                        .map(|members| members.keys().cloned().collect())
+                        .unwrap_or_default();
+                    let patterns = match map.get("patternProperties").and_then(JsonValue::as_object)
+                    {
+                        Some(members) => members
+                            .keys()
+                            .map(|source| pattern(source, &location.join("patternProperties")))
+                            .collect::<Result<_, _>>()?,
+                        None => Vec::new(),
+                    };
+                    Keyword::AdditionalProperties {
+                        node: self.node(at.clone())?,
+                        properties,
+                        patterns,
+                    }
+                }
+                "propertyNames" => Keyword::PropertyNames(self.node(at.clone())?),
+                "allOf" => Keyword::AllOf(self.nodes(value, &at)?),
+                "anyOf" => Keyword::AnyOf(self.nodes(value, &at)?),
+                "oneOf" => Keyword::OneOf(self.nodes(value, &at)?),
+                "not" => Keyword::Not(self.node(at.clone())?),
+                "if" => {
+                    let condition = self.node(at.clone())?;
+                    let then = match map.get("then") {
+                        Some(_) => Some(self.node(location.join("then"))?),
+                        None => None,
+                    };
+                    let otherwise = match map.get("else") {
+                        Some(_) => Some(self.node(location.join("else"))?),
+                        None => None,
+                    };
+                    Keyword::If {
+                        condition,
+                        then,
+                        otherwise,
+                    }
+                }
+                _ => unreachable!("every keyword in KEYWORDS is handled"),
+            };
+
+            rules.push(Rule {
+                location: at,
+                keyword,
+            });
+        }
+
+        Ok(rules)
+    }
+
+    /// Resolves a local `$ref` (`#`, `#/json/pointer` or `#anchor`) to a schema location.
+    fn reference(&self, reference: &str, at: &JsonPointer) -> Result<JsonPointer, JsonSchemaError> {
+        let unresolvable =
+            || JsonSchemaError::new(at.clone(), format!("Unresolvable reference '{reference}'"));
+
+        let Some(fragment) = reference.strip_prefix('#') else {
+            return Err(JsonSchemaError::new(
+                at.clone(),
+                format!("Only local references ('#...') are supported, found '{reference}'"),
+            ));
+        };
+        let fragment = percent_decode(fragment).ok_or_else(unresolvable)?;
+
+        let target = if fragment.is_empty() || fragment.starts_with('/') {
+            JsonPointer::parse(&fragment).map_err(|_| unresolvable())?
+        } else {
+            self.anchors
+                .get(&fragment)
+                .cloned()
+                .ok_or_else(unresolvable)?
+        };
+        target.resolve(self.root).map_err(|_| unresolvable())?;
+        Ok(target)
+    }
+}
+
+/// Records the `$anchor` of every schema object in the document.
+pub(crate) fn collect_anchors(
+    value: &JsonValue,
+    location: &JsonPointer,
+    anchors: &mut HashMap<String, JsonPointer>,
+) {
+    match value {
+        JsonValue::Object(map) => {
+            if let Some(JsonValue::String(anchor)) = map.get("$anchor") {
+                anchors.insert(anchor.clone(), location.clone());
+            }
+            for (key, member) in map {
+                // Literal values are data, not subschemas.
+                if !matches!(key.as_str(), "const" | "enum" | "default" | "examples") {
+                    collect_anchors(member, &location.join(key.as_str()), anchors);
+                }
+            }
+        }
+        JsonValue::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_anchors(item, &location.join(i.to_string()), anchors);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Decodes `%XX` escapes in a URI fragment.
+pub(crate) fn percent_decode(fragment: &str) -> Option<String> {
+    let bytes = fragment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = fragment.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+fn pattern(source: &str, at: &JsonPointer) -> Result<Regex, JsonSchemaError> {
+    Regex::new_ecma(source).map_err(|e| {
+        JsonSchemaError::new(
+            at.clone(),
+            format!(
+                "Invalid pattern '{source}': {} at index {}",
+                e.message, e.index
+            ),
+        )
+    })
+}
+
+fn string<'v>(value: &'v JsonValue, at: &JsonPointer) -> Result<&'v str, JsonSchemaError> {
+    value
+        .as_str()
+        .ok_or_else(|| JsonSchemaError::new(at.clone(), "Expected a string"))
+}
+
+fn number(value: &JsonValue, at: &JsonPointer) -> Result<f64, JsonSchemaError> {
+    value
+        .as_f64()
+        .ok_or_else(|| JsonSchemaError::new(at.clone(), "Expected a number"))
+}
+
+fn count(value: &JsonValue, at: &JsonPointer) -> Result<usize, JsonSchemaError> {
+    match value {
+        JsonValue::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Ok(*n as usize),
+        _ => Err(JsonSchemaError::new(
+            at.clone(),
+            "Expected a non-negative integer",
+        )),
+    }
+}
+
+fn object<'v>(
+    value: &'v JsonValue,
+    at: &JsonPointer,
+) -> Result<&'v HashMap<String, JsonValue>, JsonSchemaError> {
+    value
+        .as_object()
+        .ok_or_else(|| JsonSchemaError::new(at.clone(), "Expected an object"))
+}
+
+fn strings(value: &JsonValue, at: &JsonPointer) -> Result<Vec<String>, JsonSchemaError> {
+    let items = value
+        .as_array()
+        .ok_or_else(|| JsonSchemaError::new(at.clone(), "Expected an array of strings"))?;
+    items
+        .iter()
+        .map(|item| string(item, at).map(str::to_string))
+        .collect()
+}
+
+fn types(value: &JsonValue, at: &JsonPointer) -> Result<Vec<SimpleType>, JsonSchemaError> {
+    let names: Vec<&str> = match value {
+        JsonValue::String(name) => vec![name],
+        JsonValue::Array(items) => items
+            .iter()
+            .map(|item| string(item, at))
+            .collect::<Result<_, _>>()?,
+        _ => {
+            return Err(JsonSchemaError::new(
+                at.clone(),
+                "Expected a type name or an array of type names",
+            ))
+        }
+    };
+    names
+        .into_iter()
+        .map(|name| {
+            SimpleType::from_name(name)
+                .ok_or_else(|| JsonSchemaError::new(at.clone(), format!("Unknown type '{name}'")))
+        })
+        .collect()
+}
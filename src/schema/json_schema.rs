@@ -0,0 +1,138 @@
+use super::compiler::compile;
+use super::node::Node;
+use super::validator::validate;
+use crate::model::{JsonSchemaError, JsonSchemaViolation, JsonValue};
+
+/// A compiled JSON Schema (draft 2020-12).
+///
+/// The schema is checked and compiled once and can then validate any number of
+/// instances. Validation does not stop at the first problem: every violation is reported
+/// with the location of the offending value and of the failing keyword.
+///
+/// Supported keywords are `type`, `enum`, `const`, the numeric (`minimum`,
+/// `multipleOf`, ...), string (`minLength`, `pattern`, ...), array (`items`,
+/// `prefixItems`, `contains`, `uniqueItems`, ...) and object (`properties`, `required`,
+/// `additionalProperties`, `patternProperties`, `propertyNames`, `dependentRequired`,
+/// ...) constraints, the `allOf` / `anyOf` / `oneOf` / `not` combinators,
+/// `if` / `then` / `else`, and `$ref` to local JSON Pointers (`#/$defs/name`) or
+/// `$anchor`s. Annotations such as `title` and `format`, and unknown keywords, are
+/// ignored. Patterns are ECMA-262 regular expressions restricted to the I-Regexp
+/// syntax plus `^` / `$` anchors and the `\d`, `\w` and `\s` classes.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::schema::JsonSchema;
+///
+/// let schema = parse_json(r#"{
+///     "type": "object",
+///     "properties": {"age": {"type": "integer", "minimum": 0}},
+///     "required": ["name"]
+/// }"#, None).unwrap();
+/// let schema = JsonSchema::compile(&schema).unwrap();
+///
+/// let violations = schema.validate(&parse_json(r#"{"age": -1}"#, None).unwrap());
+/// let messages: Vec<_> = violations.iter().map(|v| v.to_string()).collect();
+/// assert_eq!(messages, [
+///     "Missing required property 'name' at ''",
+///     "-1 is less than the minimum of 0 at '/age'",
+/// ]);
+/// assert_eq!(violations[1].schema_path.to_string(), "/properties/age/minimum");
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonSchema {
+    nodes: Vec<Node>,
+}
+
+impl JsonSchema {
+    /// Compiles a schema document.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema, an object or a boolean.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(JsonSchema)` if the schema is valid.
+    /// * `Err(JsonSchemaError)` pointing at the offending keyword otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::schema::JsonSchema;
+    ///
+    /// let schema = parse_json(r##"{"items": {"$ref": "#/$defs/missing"}}"##, None).unwrap();
+    /// let err = JsonSchema::compile(&schema).unwrap_err();
+    /// assert_eq!(err.path.to_string(), "/items/$ref");
+    /// assert_eq!(err.message, "Unresolvable reference '#/$defs/missing'");
+    /// ```
+    pub fn compile(schema: &JsonValue) -> Result<Self, JsonSchemaError> {
+        Ok(JsonSchema {
+            nodes: compile(schema)?,
+        })
+    }
+
+    /// Validates an instance against the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The value to validate.
+    ///
+    /// # Returns
+    ///
+    /// Every violation found, in schema keyword order; empty if `instance` is valid.
+    pub fn validate(&self, instance: &JsonValue) -> Vec<JsonSchemaViolation> {
+        validate(&self.nodes, 0, instance)
+    }
+
+    /// Returns `true` if `instance` satisfies the schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    /// use synson::schema::JsonSchema;
+    ///
+    /// let schema = parse_json(r#"{"type": "string", "pattern": "^\\d+$"}"#, None).unwrap();
+    /// let schema = JsonSchema::compile(&schema).unwrap();
+    /// assert!(schema.is_valid(&parse_json(r#""123""#, None).unwrap()));
+    /// assert!(!schema.is_valid(&parse_json(r#""12a""#, None).unwrap()));
+    /// ```
+    pub fn is_valid(&self, instance: &JsonValue) -> bool {
+        self.validate(instance).is_empty()
+    }
+}
+
+/// Compiles `schema` and validates `instance` against it in one step.
+///
+/// # Arguments
+///
+/// * `schema` - The schema document.
+/// * `instance` - The value to validate.
+///
+/// # Returns
+///
+/// * `Ok(Vec<JsonSchemaViolation>)` with every violation; empty if `instance` is valid.
+/// * `Err(JsonSchemaError)` if the schema is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use synson::parse_json;
+/// use synson::schema::validate_json;
+///
+/// let schema = parse_json(r#"{"type": "array", "items": {"type": "number"}}"#, None).unwrap();
+/// let instance = parse_json(r#"[1, "two", 3]"#, None).unwrap();
+/// let violations = validate_json(&schema, &instance).unwrap();
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].instance_path.to_string(), "/1");
+/// assert_eq!(violations[0].message, "Expected number, found string");
+/// ```
+pub fn validate_json(
+    schema: &JsonValue,
+    instance: &JsonValue,
+) -> Result<Vec<JsonSchemaViolation>, JsonSchemaError> {
+    Ok(JsonSchema::compile(schema)?.validate(instance))
+}
@@ -0,0 +1,9 @@
+//! JSON Schema (draft 2020-12) validation of [`JsonValue`](crate::JsonValue) documents.
+
+mod compiler;
+pub mod json_schema;
+mod node;
+mod validator;
+
+pub(crate) use compiler::{collect_anchors, percent_decode};
+pub use json_schema::{validate_json, JsonSchema};
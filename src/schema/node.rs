@@ -0,0 +1,134 @@
+use crate::model::JsonValue;
+use crate::pointer::JsonPointer;
+use crate::regex::Regex;
+
+/// A compiled (sub)schema. Subschemas are referred to by their index in
+/// [`JsonSchema`](super::JsonSchema)'s node list, which lets `$ref` cycles be represented.
+#[derive(Debug, Clone)]
+pub(crate) struct Node {
+    /// The location of the schema within the schema document.
+    pub(crate) location: JsonPointer,
+    pub(crate) body: Body,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Body {
+    /// The `true` and `false` schemas.
+    Always(bool),
+    Rules(Vec<Rule>),
+}
+
+/// One keyword of a schema object.
+#[derive(Debug, Clone)]
+pub(crate) struct Rule {
+    /// The location of the keyword within the schema document.
+    pub(crate) location: JsonPointer,
+    pub(crate) keyword: Keyword,
+}
+
+/// The primitive types of the `type` keyword.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SimpleType {
+    Null,
+    Boolean,
+    Object,
+    Array,
+    Number,
+    Integer,
+    String,
+}
+
+impl SimpleType {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "null" => SimpleType::Null,
+            "boolean" => SimpleType::Boolean,
+            "object" => SimpleType::Object,
+            "array" => SimpleType::Array,
+            "number" => SimpleType::Number,
+            "integer" => SimpleType::Integer,
+            "string" => SimpleType::String,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            SimpleType::Null => "null",
+            SimpleType::Boolean => "boolean",
+            SimpleType::Object => "object",
+            SimpleType::Array => "array",
+            SimpleType::Number => "number",
+            SimpleType::Integer => "integer",
+            SimpleType::String => "string",
+        }
+    }
+
+    /// Returns `true` if `value` has this type. Numbers with a zero fractional part
+    /// are integers.
+    pub(crate) fn matches(self, value: &JsonValue) -> bool {
+        match (self, value) {
+            (SimpleType::Null, JsonValue::Null)
+            | (SimpleType::Boolean, JsonValue::Bool(_))
+            | (SimpleType::Object, JsonValue::Object(_))
+            | (SimpleType::Array, JsonValue::Array(_))
+            | (SimpleType::Number, JsonValue::Number(_))
+            | (SimpleType::String, JsonValue::String(_)) => true,
+            (SimpleType::Integer, JsonValue::Number(n)) => n.is_finite() && n.fract() == 0.0,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Keyword {
+    Ref(usize),
+    Type(Vec<SimpleType>),
+    Enum(Vec<JsonValue>),
+    Const(JsonValue),
+    MultipleOf(f64),
+    Maximum(f64),
+    ExclusiveMaximum(f64),
+    Minimum(f64),
+    ExclusiveMinimum(f64),
+    MaxLength(usize),
+    MinLength(usize),
+    Pattern(Regex, String),
+    MaxItems(usize),
+    MinItems(usize),
+    UniqueItems,
+    Contains {
+        node: usize,
+        min: usize,
+        max: Option<usize>,
+    },
+    PrefixItems(Vec<usize>),
+    /// `items`, applied to the elements after the `prefixItems`.
+    Items {
+        node: usize,
+        skip: usize,
+    },
+    MaxProperties(usize),
+    MinProperties(usize),
+    Required(Vec<String>),
+    DependentRequired(Vec<(String, Vec<String>)>),
+    Properties(Vec<(String, usize)>),
+    PatternProperties(Vec<(Regex, usize)>),
+    /// `additionalProperties`, applied to the members matched by neither `properties`
+    /// nor `patternProperties`.
+    AdditionalProperties {
+        node: usize,
+        properties: Vec<String>,
+        patterns: Vec<Regex>,
+    },
+    PropertyNames(usize),
+    AllOf(Vec<usize>),
+    AnyOf(Vec<usize>),
+    OneOf(Vec<usize>),
+    Not(usize),
+    If {
+        condition: usize,
+        then: Option<usize>,
+        otherwise: Option<usize>,
+    },
+}
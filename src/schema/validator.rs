@@ -0,0 +1,393 @@
+use super::node::{Body, Keyword, Node, SimpleType};
+use crate::model::{JsonSchemaViolation, JsonValue};
+use crate::pointer::JsonPointer;
+use crate::serializer::serialize_json;
+
+/// Validates `instance` against the node at `root` and returns every violation found.
+pub(crate) fn validate(
+    nodes: &[Node],
+    root: usize,
+    instance: &JsonValue,
+) -> Vec<JsonSchemaViolation> {
+    let mut validator = Validator {
+        nodes,
+        path: Vec::new(),
+        refs: Vec::new(),
+    };
+    let mut violations = Vec::new();
+    validator.node(root, instance, &mut violations);
+    violations
+}
+
+struct Validator<'a> {
+    nodes: &'a [Node],
+    /// The tokens of the instance location being validated.
+    path: Vec<String>,
+    /// The `$ref` targets being validated, with the instance depth they were entered at.
+    /// Entering one again at the same depth would recurse forever.
+    refs: Vec<(usize, usize)>,
+}
+
+impl Validator<'_> {
+    fn node(&mut self, index: usize, value: &JsonValue, out: &mut Vec<JsonSchemaViolation>) {
+        let node = &self.nodes[index];
+        match &node.body {
+            Body::Always(true) => {}
+            Body::Always(false) => {
+                out.push(self.violation(&node.location, "False schema does not allow any value"))
+            }
+            Body::Rules(rules) => {
+                for rule in rules {
+                    self.keyword(&rule.keyword, &rule.location, value, out);
+                }
+            }
+        }
+    }
+
+    /// Validates `value` against the node at `index` at the child location `token`.
+    fn child(
+        &mut self,
+        token: impl Into<String>,
+        index: usize,
+        value: &JsonValue,
+        out: &mut Vec<JsonSchemaViolation>,
+    ) {
+        self.path.push(token.into());
+        self.node(index, value, out);
+        self.path.pop();
+    }
+
+    fn is_valid(&mut self, index: usize, value: &JsonValue) -> bool {
+        let mut scratch = Vec::new();
+        self.node(index, value, &mut scratch);
+        scratch.is_empty()
+    }
+
+    fn violation(
+        &self,
+        schema_path: &JsonPointer,
+        message: impl Into<String>,
+    ) -> JsonSchemaViolation {
+        JsonSchemaViolation {
+            instance_path: JsonPointer::from_tokens(&self.path),
+            schema_path: schema_path.clone(),
+            message: message.into(),
+        }
+    }
+
+    fn fail(
+        &self,
+        out: &mut Vec<JsonSchemaViolation>,
+        at: &JsonPointer,
+        message: impl Into<String>,
+    ) {
+        out.push(self.violation(at, message));
+    }
+
+    fn keyword(
+        &mut self,
+        keyword: &Keyword,
+        at: &JsonPointer,
+        value: &JsonValue,
+        out: &mut Vec<JsonSchemaViolation>,
+    ) {
+        match (keyword, value) {
+            (Keyword::Ref(node), _) => {
+                let entry = (*node, self.path.len());
+                if self.refs.contains(&entry) {
+                    self.fail(
+                        out,
+                        at,
+                        "Reference cycle does not advance into the instance",
+                    );
+                } else {
+                    self.refs.push(entry);
+                    self.node(*node, value, out);
+                    self.refs.pop();
+                }
+            }
+            (Keyword::Type(types), _) if !types.iter().any(|t| t.matches(value)) => {
+                let expected: Vec<_> = types.iter().map(|t| t.name()).collect();
+                self.fail(
+                    out,
+                    at,
+                    format!("Expected {}, found {}", expected.join(" or "), kind(value)),
+                );
+            }
+            (Keyword::Enum(values), _) if !values.contains(value) => {
+                self.fail(out, at, "Value is not one of the allowed values")
+            }
+            (Keyword::Const(constant), _) if value != constant => self.fail(
+                out,
+                at,
+                format!(
+                    "Value does not equal the constant {}",
+                    serialize_json(constant)
+                ),
+            ),
+
+            (Keyword::MultipleOf(divisor), JsonValue::Number(n))
+                if !is_multiple_of(*n, *divisor) =>
+            {
+                self.fail(
+                    out,
+                    at,
+                    format!("{} is not a multiple of {}", number(*n), number(*divisor)),
+                )
+            }
+            (Keyword::Maximum(limit), JsonValue::Number(n)) if n > limit => self.fail(
+                out,
+                at,
+                format!(
+                    "{} is greater than the maximum of {}",
+                    number(*n),
+                    number(*limit)
+                ),
+            ),
+            (Keyword::ExclusiveMaximum(limit), JsonValue::Number(n)) if n >= limit => self.fail(
+                out,
+                at,
+                format!(
+                    "{} is not less than the exclusive maximum of {}",
+                    number(*n),
+                    number(*limit)
+                ),
+            ),
+            (Keyword::Minimum(limit), JsonValue::Number(n)) if n < limit => self.fail(
+                out,
+                at,
+                format!(
+                    "{} is less than the minimum of {}",
+                    number(*n),
+                    number(*limit)
+                ),
+            ),
+            (Keyword::ExclusiveMinimum(limit), JsonValue::Number(n)) if n <= limit => self.fail(
+                out,
+                at,
+                format!(
+                    "{} is not greater than the exclusive minimum of {}",
+                    number(*n),
+                    number(*limit)
+                ),
+            ),
+
+            (Keyword::MaxLength(limit), JsonValue::String(s)) if s.chars().count() > *limit => {
+                self.fail(out, at, format!("String is longer than {limit} characters"))
+            }
+            (Keyword::MinLength(limit), JsonValue::String(s)) if s.chars().count() < *limit => self
+                .fail(
+                    out,
+                    at,
+                    format!("String is shorter than {limit} characters"),
+                ),
+            (Keyword::Pattern(regex, source), JsonValue::String(s)) if !regex.is_match(s) => self
+                .fail(
+                    out,
+                    at,
+                    format!("String does not match the pattern '{source}'"),
+                ),
+
+            (Keyword::MaxItems(limit), JsonValue::Array(items)) if items.len() > *limit => {
+                self.fail(out, at, format!("Array has more than {limit} items"))
+            }
+            (Keyword::MinItems(limit), JsonValue::Array(items)) if items.len() < *limit => {
+                self.fail(out, at, format!("Array has fewer than {limit} items"))
+            }
+            (Keyword::UniqueItems, JsonValue::Array(items)) => {
+                let duplicate = (0..items.len())
+                    .flat_map(|j| (0..j).map(move |i| (i, j)))
+                    .find(|&(i, j)| items[i] == items[j]);
+                if let Some((i, j)) = duplicate {
+                    self.fail(out, at, format!("Array items at {i} and {j} are equal"));
+                }
+            }
+            (Keyword::Contains { node, min, max }, JsonValue::Array(items)) => {
+                let count = items
+                    .iter()
+                    .filter(|item| self.is_valid(*node, item))
+                    .count();
+                if count < *min {
+                    let message = if *min == 1 {
+                        "Array does not contain a matching item".to_string()
+                    } else {
+                        format!("Array contains {count} matching items, fewer than {min}")
+                    };
+                    self.fail(out, at, message);
+                } else if let Some(max) = max.filter(|max| count > *max) {
+                    self.fail(
+                        out,
+                        at,
+                        format!("Array contains {count} matching items, more than {max}"),
+                    );
+                }
+            }
+            (Keyword::PrefixItems(nodes), JsonValue::Array(items)) => {
+                for (i, (node, item)) in nodes.iter().zip(items).enumerate() {
+                    self.child(i.to_string(), *node, item, out);
+                }
+            }
+            (Keyword::Items { node, skip }, JsonValue::Array(items)) => {
+                for (i, item) in items.iter().enumerate().skip(*skip) {
+                    self.child(i.to_string(), *node, item, out);
+                }
+            }
+
+            (Keyword::MaxProperties(limit), JsonValue::Object(map)) if map.len() > *limit => {
+                self.fail(out, at, format!("Object has more than {limit} properties"))
+            }
+            (Keyword::MinProperties(limit), JsonValue::Object(map)) if map.len() < *limit => {
+                self.fail(out, at, format!("Object has fewer than {limit} properties"))
+            }
+            (Keyword::Required(names), JsonValue::Object(map)) => {
+                for name in names.iter().filter(|name| !map.contains_key(*name)) {
+                    self.fail(out, at, format!("Missing required property '{name}'"));
+                }
+            }
+            (Keyword::DependentRequired(dependencies), JsonValue::Object(map)) => {
+                for (trigger, names) in dependencies {
+                    if !map.contains_key(trigger) {
+                        continue;
+                    }
+                    for name in names.iter().filter(|name| !map.contains_key(*name)) {
+                        self.fail(
+                            out,
+                            at,
+                            format!("Property '{name}' is required when '{trigger}' is present"),
+                        );
+                    }
+                }
+            }
+            (Keyword::Properties(properties), JsonValue::Object(map)) => {
+                for (name, node) in properties {
+                    if let Some(member) = map.get(name) {
+                        self.child(name.as_str(), *node, member, out);
+                    }
+                }
+            }
+            (Keyword::PatternProperties(patterns), JsonValue::Object(map)) => {
+                let members = sorted(map);
+                for (regex, node) in patterns {
+                    for (key, member) in members.iter().filter(|(key, _)| regex.is_match(key)) {
+                        self.child(key.as_str(), *node, member, out);
+                    }
+                }
+            }
+            (
+                Keyword::AdditionalProperties {
+                    node,
+                    properties,
+                    patterns,
+                },
+                JsonValue::Object(map),
+            ) => {
+                let additional = sorted(map).into_iter().filter(|(key, _)| {
+                    !properties.contains(key) && !patterns.iter().any(|regex| regex.is_match(key))
+                });
+                for (key, member) in additional {
+                    if let Body::Always(false) = self.nodes[*node].body {
+                        self.path.push(key.clone());
+                        out.push(
+                            self.violation(
+                                at,
+                                format!("Additional property '{key}' is not allowed"),
+                            ),
+                        );
+                        self.path.pop();
+                    } else {
+                        self.child(key.as_str(), *node, member, out);
+                    }
+                }
+            }
+            (Keyword::PropertyNames(node), JsonValue::Object(map)) => {
+                for (key, _) in sorted(map) {
+                    self.child(key.as_str(), *node, &JsonValue::String(key.clone()), out);
+                }
+            }
+
+            (Keyword::AllOf(nodes), _) => {
+                for node in nodes {
+                    self.node(*node, value, out);
+                }
+            }
+            (Keyword::AnyOf(nodes), _) if !nodes.iter().any(|node| self.is_valid(*node, value)) => {
+                self.fail(out, at, "Value does not match any of the 'anyOf' schemas")
+            }
+            (Keyword::OneOf(nodes), _) => {
+                let matched = nodes
+                    .iter()
+                    .filter(|node| self.is_valid(**node, value))
+                    .count();
+                if matched == 0 {
+                    self.fail(out, at, "Value does not match any of the 'oneOf' schemas");
+                } else if matched > 1 {
+                    self.fail(
+                        out,
+                        at,
+                        "Value matches more than one of the 'oneOf' schemas",
+                    );
+                }
+            }
+            (Keyword::Not(node), _) if self.is_valid(*node, value) => {
+                self.fail(out, at, "Value must not match the 'not' schema")
+            }
+            (
+                Keyword::If {
+                    condition,
+                    then,
+                    otherwise,
+                },
+                _,
+            ) => {
+                let branch = if self.is_valid(*condition, value) {
+                    then
+                } else {
+                    otherwise
+                };
+                if let Some(node) = branch {
+                    self.node(*node, value, out);
+                }
+            }
+
+            // Type-specific keywords ignore values of other types.
+            _ => {}
+        }
+    }
+}
+
+/// Names the JSON type of `value`, as the `type` keyword would.
+fn kind(value: &JsonValue) -> &'static str {
+    let kind = match value {
+        JsonValue::Null => SimpleType::Null,
+        JsonValue::Bool(_) => SimpleType::Boolean,
+        JsonValue::Number(_) => SimpleType::Number,
+        JsonValue::String(_) => SimpleType::String,
+        JsonValue::Array(_) => SimpleType::Array,
+        JsonValue::Object(_) => SimpleType::Object,
+    };
+    kind.name()
+}
+
+fn number(n: f64) -> String {
+    serialize_json(&JsonValue::Number(n))
+}
+
+/// Checks `multipleOf`. Integers are compared exactly, since `%` is exact on `f64`s;
+/// otherwise the quotient may be off an integer by a few ulps of rounding error, so that
+/// `0.3` is a multiple of `0.1`.
+fn is_multiple_of(n: f64, divisor: f64) -> bool {
+    if n.fract() == 0.0 && divisor.fract() == 0.0 {
+        return n % divisor == 0.0;
+    }
+    let quotient = n / divisor;
+    quotient.is_finite()
+        && (quotient - quotient.round()).abs() <= 4.0 * f64::EPSILON * quotient.abs().max(1.0)
+}
+
+/// Returns the members of an object in key order, so violations are reported
+/// deterministically.
+fn sorted(map: &std::collections::HashMap<String, JsonValue>) -> Vec<(&String, &JsonValue)> {
+    let mut members: Vec<_> = map.iter().collect();
+    members.sort_by(|a, b| a.0.cmp(b.0));
+    members
+}
@@ -0,0 +1,531 @@
+use std::fmt;
+
+use ::serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use ::serde::forward_to_deserialize_any;
+
+use crate::model::{JsonParseError, JsonValue};
+use crate::parser::value::skip_whitespace;
+use crate::parser::{parse_bool, parse_null, parse_number, parse_string, MAX_DEPTH};
+
+/// Deserializes an instance of `T` directly from JSON text.
+///
+/// The input is read with synson's token parsers and fed straight to `T`'s `Deserialize`
+/// implementation, without building an intermediate [`JsonValue`]. As with
+/// [`parse_json`](crate::parse_json) in strict mode, nothing but whitespace may follow
+/// the value, and arrays and objects may nest at most [`MAX_DEPTH`] levels deep. Errors
+/// from the data type (wrong type, missing field, unknown variant...)
+/// are reported at the start of the offending value.
+///
+/// # Arguments
+///
+/// * `input` - A complete JSON document.
+///
+/// # Returns
+///
+/// * `Ok(T)` if the document is valid JSON and matches `T`.
+/// * `Err(JsonParseError)` with the position of the first syntax or data error.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// struct Point {
+///     x: i64,
+///     y: i64,
+/// }
+///
+/// let point: Point = synson::from_str(r#"{"x": 1, "y": -2}"#).unwrap();
+/// assert_eq!(point, Point { x: 1, y: -2 });
+///
+/// let err = synson::from_str::<Point>("{\"x\": 1,\n \"y\": \"2\"}").unwrap_err();
+/// assert_eq!((err.line, err.column), (2, 7));
+/// assert!(err.message.starts_with("invalid type: string \"2\""));
+/// ```
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, JsonParseError> {
+    let mut deserializer = Deserializer {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+
+    let value = T::deserialize(&mut deserializer).map_err(|e| {
+        let index = e.index.unwrap_or(deserializer.pos);
+        JsonParseError::new(&e.message, index, input)
+    })?;
+
+    deserializer.skip_whitespace();
+    if deserializer.pos < input.len() {
+        return Err(JsonParseError::new(
+            "Trailing characters after JSON value",
+            deserializer.pos,
+            input,
+        ));
+    }
+
+    Ok(value)
+}
+
+/// A deserialization error, located once the failing value is known.
+#[derive(Debug)]
+pub(crate) struct Error {
+    message: String,
+    index: Option<usize>,
+}
+
+impl Error {
+    /// Locates the error at `index` unless an inner value already did.
+    fn locate(mut self, index: usize) -> Self {
+        self.index.get_or_insert(index);
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error {
+            message: msg.to_string(),
+            index: None,
+        }
+    }
+}
+
+/// A token parser from [`crate::parser`].
+type TokenParser = fn(&str) -> Result<(JsonValue, &str), JsonParseError>;
+
+struct Deserializer<'de> {
+    input: &'de str,
+    pos: usize,
+    /// How many arrays and objects enclose the current position.
+    depth: usize,
+}
+
+impl<'de> Deserializer<'de> {
+    fn rest(&self) -> &'de str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - skip_whitespace(rest).len();
+    }
+
+    /// Skips whitespace and returns the next character without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error {
+            message: message.to_string(),
+            index: Some(self.pos),
+        }
+    }
+
+    /// Consumes the `[` or `{` opening a nested value, failing past [`MAX_DEPTH`] levels
+    /// rather than exhausting the stack. The caller calls [`Deserializer::leave`] once
+    /// the value is read.
+    fn enter(&mut self) -> Result<(), Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(&format!("Nesting is deeper than {MAX_DEPTH} levels")));
+        }
+        self.depth += 1;
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn leave<T>(&mut self, result: Result<T, Error>) -> Result<T, Error> {
+        self.depth -= 1;
+        result
+    }
+
+    /// Consumes `expected` or fails with `message`.
+    fn expect(&mut self, expected: char, message: &str) -> Result<(), Error> {
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    /// Runs a token parser at the current position, returning the parsed token and its
+    /// source text.
+    fn token(&mut self, parser: TokenParser) -> Result<(JsonValue, &'de str), Error> {
+        let rest = self.rest();
+        match parser(rest) {
+            Ok((value, after)) => {
+                let token = &rest[..rest.len() - after.len()];
+                self.pos += token.len();
+                Ok((value, token))
+            }
+            Err(e) => Err(Error {
+                message: e.message,
+                index: Some(self.pos + e.index),
+            }),
+        }
+    }
+
+    /// Reads a string, borrowing it from the input when it contains no escapes.
+    fn string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value, Error> {
+        let (value, token) = self.token(parse_string)?;
+        let raw = &token[1..token.len() - 1];
+        match value {
+            _ if !raw.contains('\\') => visitor.visit_borrowed_str(raw),
+            JsonValue::String(s) => visitor.visit_string(s),
+            _ => unreachable!("parse_string returns strings"),
+        }
+    }
+
+    /// Reads a number, keeping integers that fit in 64 bits exact. `-0` is read as a float
+    /// so that its sign survives, except by [`Deserializer::integer`].
+    fn number<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value, Error> {
+        let (value, token) = self.token(parse_number)?;
+        if !token.contains(['.', 'e', 'E']) && token != "-0" {
+            if let Ok(n) = token.parse::<u64>() {
+                return visitor.visit_u64(n);
+            }
+            if let Ok(n) = token.parse::<i64>() {
+                return visitor.visit_i64(n);
+            }
+        }
+        match value {
+            JsonValue::Number(n) => visitor.visit_f64(n),
+            _ => unreachable!("parse_number returns numbers"),
+        }
+    }
+
+    /// Reads a value for an integer type, where `-0` is simply `0`.
+    fn integer<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value, Error> {
+        self.skip_whitespace();
+        let is_negative_zero = self.rest().strip_prefix("-0").is_some_and(|after| {
+            !after.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E'))
+        });
+        if is_negative_zero {
+            self.pos += 2;
+            return visitor.visit_u64(0);
+        }
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+
+    /// Consumes the `]` closing an array whose elements have all been read.
+    fn end_array(&mut self) -> Result<(), Error> {
+        match self.peek() {
+            Some(',') => Err(self.error("Unexpected extra elements in array")),
+            _ => self.expect(']', "Expected ',' or ']' after array element"),
+        }
+    }
+
+    /// Consumes the `}` closing an object whose members have all been read.
+    fn end_object(&mut self) -> Result<(), Error> {
+        match self.peek() {
+            Some(',') => Err(self.error("Unexpected extra members in object")),
+            _ => self.expect('}', "Expected ',' or '}' after object entry"),
+        }
+    }
+}
+
+macro_rules! deserialize_integer {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                self.integer(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let next = self.peek();
+        let start = self.pos;
+        let result = match next {
+            Some('n') => self.token(parse_null).and_then(|_| visitor.visit_unit()),
+            Some('t' | 'f') => self
+                .token(parse_bool)
+                .and_then(|(value, _)| visitor.visit_bool(value == JsonValue::Bool(true))),
+            Some('"') => self.string(visitor),
+            Some('-' | '0'..='9') => self.number(visitor),
+            Some('[') => self.enter().and_then(|()| {
+                let result = visitor
+                    .visit_seq(Elements {
+                        de: &mut *self,
+                        first: true,
+                    })
+                    .and_then(|value| self.end_array().map(|()| value));
+                self.leave(result)
+            }),
+            Some('{') => self.enter().and_then(|()| {
+                let result = visitor
+                    .visit_map(Members {
+                        de: &mut *self,
+                        first: true,
+                    })
+                    .and_then(|value| self.end_object().map(|()| value));
+                self.leave(result)
+            }),
+            Some(_) => Err(self.error("Expected a JSON value")),
+            None => Err(self.error("Unexpected end of input")),
+        };
+        result.map_err(|e| e.locate(start))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.peek() == Some('n') {
+            self.token(parse_null)?;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let next = self.peek();
+        let start = self.pos;
+        let result = match next {
+            Some('"') => self.token(parse_string).and_then(|(value, _)| match value {
+                JsonValue::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+                _ => unreachable!("parse_string returns strings"),
+            }),
+            Some('{') => self.enter().and_then(|()| {
+                let result = visitor
+                    .visit_enum(Variant { de: &mut *self })
+                    .and_then(|value| {
+                        self.expect('}', "Expected '}' after enum variant")
+                            .map(|()| value)
+                    });
+                self.leave(result)
+            }),
+            _ => Err(self.error("Expected a string or an object for an enum")),
+        };
+        result.map_err(|e| e.locate(start))
+    }
+
+    deserialize_integer! {
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_i128
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf unit unit_struct seq tuple tuple_struct
+        map struct identifier ignored_any
+    }
+}
+
+/// Reads the elements of an array after its opening `[`.
+struct Elements<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    first: bool,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.de.peek() {
+            Some(']') => return Ok(None),
+            Some(',') if !self.first => {
+                self.de.pos += 1;
+                if self.de.peek() == Some(']') {
+                    return Err(self.de.error("Trailing comma not allowed before ']'"));
+                }
+            }
+            _ if !self.first => {
+                return Err(self.de.error("Expected ',' or ']' after array element"));
+            }
+            _ => {}
+        }
+        self.first = false;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+/// Reads the members of an object after its opening `{`.
+struct Members<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    first: bool,
+}
+
+impl<'de> MapAccess<'de> for Members<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.de.peek() {
+            Some('}') => return Ok(None),
+            Some(',') if !self.first => {
+                self.de.pos += 1;
+                if self.de.peek() == Some('}') {
+                    return Err(self.de.error("Trailing comma not allowed before '}'"));
+                }
+            }
+            _ if !self.first => {
+                return Err(self.de.error("Expected ',' or '}' after object entry"));
+            }
+            _ => {}
+        }
+        self.first = false;
+        seed.deserialize(Key { de: &mut *self.de }).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        self.de.expect(':', "Expected ':' after key in object")?;
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+/// Reads an object key, which may also stand for an integer or a unit variant.
+struct Key<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+}
+
+impl Key<'_, '_> {
+    fn owned(self) -> Result<(String, usize), Error> {
+        if self.de.peek() != Some('"') {
+            return Err(self.de.error("Expected string key in object"));
+        }
+        let start = self.de.pos;
+        match self.de.token(parse_string)? {
+            (JsonValue::String(key), _) => Ok((key, start)),
+            _ => unreachable!("parse_string returns strings"),
+        }
+    }
+}
+
+macro_rules! deserialize_integer_key {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                let (key, start) = self.owned()?;
+                match key.parse() {
+                    Ok(n) => visitor.$visit(n),
+                    Err(_) => Err(Error {
+                        message: format!("Expected an integer object key, found \"{key}\""),
+                        index: Some(start),
+                    }),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Key<'_, 'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.de.peek() != Some('"') {
+            return Err(self.de.error("Expected string key in object"));
+        }
+        let start = self.de.pos;
+        self.de.string(visitor).map_err(|e| e.locate(start))
+    }
+
+    deserialize_integer_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let (key, start) = self.owned()?;
+        visitor
+            .visit_enum(key.into_deserializer())
+            .map_err(|e: Error| e.locate(start))
+    }
+
+    forward_to_deserialize_any! {
+        bool i128 u128 f32 f64 char str string bytes byte_buf option unit unit_struct seq
+        tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Reads an externally tagged enum variant, `{"Variant": content}`, after the `{`.
+struct Variant<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+}
+
+impl<'de> EnumAccess<'de> for Variant<'_, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+        let variant = seed.deserialize(Key { de: &mut *self.de })?;
+        self.de.expect(':', "Expected ':' after key in object")?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Variant<'_, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        de::Deserialize::deserialize(self.de)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(self.de, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(self.de, visitor)
+    }
+}
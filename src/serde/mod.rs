@@ -0,0 +1,6 @@
+pub mod de;
+pub mod ser;
+mod value;
+
+pub use de::from_str;
+pub use ser::to_string;
@@ -0,0 +1,557 @@
+use ::serde::ser::{self, Error as _, Impossible, Serialize};
+
+use crate::model::JsonSerializeError;
+use crate::serializer::json::{write_number, write_string};
+
+/// Serializes any `Serialize` value into compact JSON text.
+///
+/// Structs keep their field order, enums are externally tagged (`"Unit"` or
+/// `{"Variant": content}`), `None` and unit values become `null`, and non-finite floats
+/// become `null` as in [`serialize_json`](crate::serialize_json). 64-bit integers are
+/// written exactly. Map keys must be strings, characters, integers or unit variants.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize.
+///
+/// # Returns
+///
+/// * `Ok(String)` with the compact JSON text.
+/// * `Err(JsonSerializeError)` if the value has no JSON representation.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Release {
+///     name: &'static str,
+///     downloads: u64,
+///     tags: Option<Vec<&'static str>>,
+/// }
+///
+/// let release = Release { name: "synson", downloads: u64::MAX, tags: None };
+/// assert_eq!(
+///     synson::to_string(&release).unwrap(),
+///     r#"{"name":"synson","downloads":18446744073709551615,"tags":null}"#
+/// );
+///
+/// let mut by_id = BTreeMap::new();
+/// by_id.insert(7, "seven");
+/// assert_eq!(synson::to_string(&by_id).unwrap(), r#"{"7":"seven"}"#);
+/// ```
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, JsonSerializeError> {
+    let mut serializer = Serializer { out: String::new() };
+    value.serialize(&mut serializer)?;
+    Ok(serializer.out)
+}
+
+struct Serializer {
+    out: String,
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = JsonSerializeError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), JsonSerializeError> {
+        self.out.push_str(if v { "true" } else { "false" });
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), JsonSerializeError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), JsonSerializeError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), JsonSerializeError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), JsonSerializeError> {
+        self.out.push_str(&v.to_string());
+        Ok(())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), JsonSerializeError> {
+        self.out.push_str(&v.to_string());
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), JsonSerializeError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), JsonSerializeError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), JsonSerializeError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), JsonSerializeError> {
+        self.out.push_str(&v.to_string());
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), JsonSerializeError> {
+        self.out.push_str(&v.to_string());
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), JsonSerializeError> {
+        // Formatting as f32 keeps the shortest representation (0.1, not 0.10000000149...).
+        if v.is_finite() {
+            self.out.push_str(&v.to_string());
+        } else {
+            self.out.push_str("null");
+        }
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), JsonSerializeError> {
+        write_number(&mut self.out, v);
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), JsonSerializeError> {
+        write_string(&mut self.out, v.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), JsonSerializeError> {
+        write_string(&mut self.out, v);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), JsonSerializeError> {
+        self.collect_seq(v)
+    }
+
+    fn serialize_none(self) -> Result<(), JsonSerializeError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), JsonSerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), JsonSerializeError> {
+        self.out.push_str("null");
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), JsonSerializeError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), JsonSerializeError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.out.push('{');
+        write_string(&mut self.out, variant);
+        self.out.push(':');
+        value.serialize(&mut *self)?;
+        self.out.push('}');
+        Ok(())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>, JsonSerializeError> {
+        self.out.push('[');
+        Ok(Compound::new(self, "]"))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Compound<'a>, JsonSerializeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>, JsonSerializeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, JsonSerializeError> {
+        self.out.push('{');
+        write_string(&mut self.out, variant);
+        self.out.push_str(":[");
+        Ok(Compound::new(self, "]}"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>, JsonSerializeError> {
+        self.out.push('{');
+        Ok(Compound::new(self, "}"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>, JsonSerializeError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, JsonSerializeError> {
+        self.out.push('{');
+        write_string(&mut self.out, variant);
+        self.out.push_str(":{");
+        Ok(Compound::new(self, "}}"))
+    }
+}
+
+/// Writes the elements or members of an array, object or tagged variant.
+struct Compound<'a> {
+    ser: &'a mut Serializer,
+    first: bool,
+    close: &'static str,
+}
+
+impl<'a> Compound<'a> {
+    fn new(ser: &'a mut Serializer, close: &'static str) -> Self {
+        Compound {
+            ser,
+            first: true,
+            close,
+        }
+    }
+
+    fn separate(&mut self) {
+        if !self.first {
+            self.ser.out.push(',');
+        }
+        self.first = false;
+    }
+
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JsonSerializeError> {
+        self.separate();
+        value.serialize(&mut *self.ser)
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.separate();
+        write_string(&mut self.ser.out, key);
+        self.ser.out.push(':');
+        value.serialize(&mut *self.ser)
+    }
+
+    fn finish(self) -> Result<(), JsonSerializeError> {
+        self.ser.out.push_str(self.close);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), JsonSerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), JsonSerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), JsonSerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), JsonSerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), JsonSerializeError> {
+        self.separate();
+        key.serialize(Key {
+            ser: &mut *self.ser,
+        })
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.ser.out.push(':');
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<(), JsonSerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), JsonSerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), JsonSerializeError> {
+        self.finish()
+    }
+}
+
+/// Writes a map key as a JSON string.
+struct Key<'a> {
+    ser: &'a mut Serializer,
+}
+
+impl Key<'_> {
+    fn write(self, key: &str) -> Result<(), JsonSerializeError> {
+        write_string(&mut self.ser.out, key);
+        Ok(())
+    }
+}
+
+fn key_error() -> JsonSerializeError {
+    JsonSerializeError::custom("Map keys must be strings, characters, integers or unit variants")
+}
+
+macro_rules! serialize_display_key {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<(), JsonSerializeError> {
+                self.write(&v.to_string())
+            }
+        )*
+    };
+}
+
+macro_rules! reject_key {
+    ($($method:ident($($arg:ty),*)),*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<(), JsonSerializeError> {
+                Err(key_error())
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for Key<'_> {
+    type Ok = ();
+    type Error = JsonSerializeError;
+    type SerializeSeq = Impossible<(), JsonSerializeError>;
+    type SerializeTuple = Impossible<(), JsonSerializeError>;
+    type SerializeTupleStruct = Impossible<(), JsonSerializeError>;
+    type SerializeTupleVariant = Impossible<(), JsonSerializeError>;
+    type SerializeMap = Impossible<(), JsonSerializeError>;
+    type SerializeStruct = Impossible<(), JsonSerializeError>;
+    type SerializeStructVariant = Impossible<(), JsonSerializeError>;
+
+    serialize_display_key! {
+        serialize_i8: i8, serialize_i16: i16, serialize_i32: i32, serialize_i64: i64,
+        serialize_i128: i128, serialize_u8: u8, serialize_u16: u16, serialize_u32: u32,
+        serialize_u64: u64, serialize_u128: u128, serialize_char: char
+    }
+
+    reject_key! {
+        serialize_bool(bool), serialize_f32(f32), serialize_f64(f64), serialize_bytes(&[u8]),
+        serialize_none(), serialize_unit(), serialize_unit_struct(&'static str)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), JsonSerializeError> {
+        self.write(v)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<(), JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), JsonSerializeError> {
+        self.write(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, JsonSerializeError> {
+        Err(key_error())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, JsonSerializeError> {
+        Err(key_error())
+    }
+}
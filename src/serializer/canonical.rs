@@ -0,0 +1,97 @@
+use super::json::write_string;
+use crate::model::{JsonSerializeError, JsonValue};
+use crate::number::format::write_f64;
+
+/// Serializes a `JsonValue` into its canonical form as defined by the JSON
+/// Canonicalization Scheme (RFC 8785), e.g. to sign or hash it.
+///
+/// The output has no whitespace, object keys sorted by their UTF-16 code units, strings
+/// with only the escapes JSON requires, and numbers formatted as ECMAScript's
+/// `Number.prototype.toString` does (`1e+30`, `0.002`, `-0` as `0`). Equal values
+/// therefore always produce the same bytes.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize.
+///
+/// # Returns
+///
+/// * `Ok(String)` with the canonical JSON text; its UTF-8 bytes are the canonical bytes.
+/// * `Err(JsonSerializeError)` if `value` contains `NaN` or an infinite number, which
+///   JSON cannot represent.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_json, serialize_canonical, JsonValue};
+///
+/// let value = parse_json(r#"{"b": [1E30, 4.50, 2e-3], "a": "€"}"#, None).unwrap();
+/// assert_eq!(serialize_canonical(&value).unwrap(), r#"{"a":"€","b":[1e+30,4.5,0.002]}"#);
+///
+/// let err = serialize_canonical(&JsonValue::Number(f64::NAN)).unwrap_err();
+/// assert_eq!(err.message, "NaN has no JSON representation");
+/// ```
+pub fn serialize_canonical(value: &JsonValue) -> Result<String, JsonSerializeError> {
+    let mut out = String::new();
+    write_value(&mut out, value)?;
+    Ok(out)
+}
+
+fn write_value(out: &mut String, value: &JsonValue) -> Result<(), JsonSerializeError> {
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Bool(true) => out.push_str("true"),
+        JsonValue::Bool(false) => out.push_str("false"),
+        JsonValue::Number(n) => write_number(out, *n)?,
+        JsonValue::String(s) => write_string(out, s),
+        JsonValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, item)?;
+            }
+            out.push(']');
+        }
+        JsonValue::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.encode_utf16().cmp(b.0.encode_utf16()));
+
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, key);
+                out.push(':');
+                write_value(out, item)?;
+            }
+            out.push('}');
+        }
+    }
+    Ok(())
+}
+
+/// Writes a number as ECMAScript's `Number.prototype.toString` does.
+fn write_number(out: &mut String, n: f64) -> Result<(), JsonSerializeError> {
+    if !n.is_finite() {
+        let name = if n.is_nan() {
+            "NaN"
+        } else if n > 0.0 {
+            "Infinity"
+        } else {
+            "-Infinity"
+        };
+        return Err(JsonSerializeError {
+            message: format!("{name} has no JSON representation"),
+        });
+    }
+    // ECMAScript prints -0 as 0; otherwise the layouts are the same.
+    if n == 0.0 {
+        out.push('0');
+    } else {
+        write_f64(out, n);
+    }
+    Ok(())
+}
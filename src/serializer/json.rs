@@ -0,0 +1,178 @@
+use std::fmt;
+
+use crate::model::JsonValue;
+use crate::number::format::write_f64;
+
+/// Serializes a `JsonValue` into compact JSON text (no insignificant whitespace).
+///
+/// Object keys are written in sorted order so that the output is deterministic.
+/// Strings are escaped as required by RFC 8259, and non-finite numbers (`NaN`,
+/// infinities), which JSON cannot represent, are written as `null`.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize.
+///
+/// # Returns
+///
+/// The compact JSON representation of `value`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_json, serialize_json, JsonValue};
+///
+/// let value = parse_json("{ \"b\": [1, 2.5], \"a\": \"x\\ny\" }", None).unwrap();
+/// assert_eq!(serialize_json(&value), "{\"a\":\"x\\ny\",\"b\":[1,2.5]}");
+/// assert_eq!(serialize_json(&JsonValue::Number(f64::NAN)), "null");
+/// ```
+pub fn serialize_json(value: &JsonValue) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value);
+    out
+}
+
+/// Serializes a `JsonValue` into indented, human-readable JSON text.
+///
+/// Every array element and object member goes on its own line, indented by `indent`
+/// spaces per nesting level, with a space after each `:`. Empty arrays and objects stay
+/// on one line (`[]`, `{}`). Keys are sorted and numbers and strings are written as by
+/// [`serialize_json`]. There is no trailing newline.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize.
+/// * `indent` - The number of spaces per nesting level.
+///
+/// # Returns
+///
+/// The indented JSON representation of `value`.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{parse_json, serialize_json_pretty};
+///
+/// let value = parse_json(r#"{"b": [1, {}], "a": []}"#, None).unwrap();
+/// assert_eq!(
+///     serialize_json_pretty(&value, 2),
+///     "{\n  \"a\": [],\n  \"b\": [\n    1,\n    {}\n  ]\n}"
+/// );
+/// ```
+pub fn serialize_json_pretty(value: &JsonValue, indent: usize) -> String {
+    let mut out = String::new();
+    write_pretty(&mut out, value, indent, 0);
+    out
+}
+
+fn write_pretty(out: &mut String, value: &JsonValue, indent: usize, depth: usize) {
+    let newline = |out: &mut String, depth: usize| {
+        out.push('\n');
+        out.extend(std::iter::repeat_n(' ', indent * depth));
+    };
+
+    match value {
+        JsonValue::Array(items) if !items.is_empty() => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(out, depth + 1);
+                write_pretty(out, item, indent, depth + 1);
+            }
+            newline(out, depth);
+            out.push(']');
+        }
+        JsonValue::Object(map) if !map.is_empty() => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(out, depth + 1);
+                write_string(out, key);
+                out.push_str(": ");
+                write_pretty(out, item, indent, depth + 1);
+            }
+            newline(out, depth);
+            out.push('}');
+        }
+        _ => write_value(out, value),
+    }
+}
+
+fn write_value(out: &mut String, value: &JsonValue) {
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Bool(true) => out.push_str("true"),
+        JsonValue::Bool(false) => out.push_str("false"),
+        JsonValue::Number(n) => write_number(out, *n),
+        JsonValue::String(s) => write_string(out, s),
+        JsonValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, item);
+            }
+            out.push(']');
+        }
+        JsonValue::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, key);
+                out.push(':');
+                write_value(out, item);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Writes a number using the shortest representation that parses back to the same `f64`,
+/// see [`format_f64`](crate::number::format_f64).
+pub(crate) fn write_number(out: &mut String, n: f64) {
+    write_f64(out, n);
+}
+
+/// Writes a quoted string, escaping quotes, backslashes and control characters.
+pub(crate) fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+impl fmt::Display for JsonValue {
+    /// Formats the value as compact JSON, see [`serialize_json`], or with the alternate
+    /// flag (`{:#}`) as JSON indented by two spaces, see [`serialize_json_pretty`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(&serialize_json_pretty(self, 2))
+        } else {
+            f.write_str(&serialize_json(self))
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod canonical;
+pub mod json;
+pub mod ndjson;
+
+pub use canonical::serialize_canonical;
+pub use json::{serialize_json, serialize_json_pretty};
+pub use ndjson::{serialize_ndjson, NdjsonWriter};
@@ -0,0 +1,87 @@
+use std::io::{self, Write};
+
+use super::serialize_json;
+use crate::model::JsonValue;
+
+/// Writes values as newline-delimited JSON (NDJSON / JSON Lines).
+///
+/// Each value is serialized compactly with [`serialize_json`] and followed by `\n`.
+/// Compact output never contains a raw newline, so every value occupies exactly one line.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{JsonValue, NdjsonWriter};
+///
+/// let mut writer = NdjsonWriter::new(Vec::new());
+/// writer.write_value(&JsonValue::Number(1.0)).unwrap();
+/// writer.write_value(&JsonValue::Array(vec![JsonValue::Null])).unwrap();
+///
+/// assert_eq!(writer.into_inner(), b"1\n[null]\n");
+/// ```
+pub struct NdjsonWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    /// Creates a writer emitting NDJSON into `writer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination. Wrap it in a `BufWriter` when writing many small values.
+    pub fn new(writer: W) -> Self {
+        NdjsonWriter { writer }
+    }
+
+    /// Writes one value followed by a newline.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to write.
+    ///
+    /// # Returns
+    ///
+    /// Any I/O error raised by the underlying writer.
+    pub fn write_value(&mut self, value: &JsonValue) -> io::Result<()> {
+        let mut line = serialize_json(value);
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Consumes the writer, returning the underlying destination.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Serializes a sequence of values into an NDJSON string, one compact value per line.
+///
+/// # Arguments
+///
+/// * `values` - The values to serialize, in order.
+///
+/// # Returns
+///
+/// The NDJSON text, with a trailing newline after every value.
+///
+/// # Examples
+///
+/// ```
+/// use synson::{serialize_ndjson, JsonValue};
+///
+/// let values = [JsonValue::Bool(true), JsonValue::String("a\nb".to_string())];
+/// assert_eq!(serialize_ndjson(&values), "true\n\"a\\nb\"\n");
+/// ```
+pub fn serialize_ndjson<'a>(values: impl IntoIterator<Item = &'a JsonValue>) -> String {
+    let mut out = String::new();
+    for value in values {
+        out.push_str(&serialize_json(value));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::mem;
+
+use super::JsonIndex;
+use crate::model::JsonValue;
+
+impl JsonValue {
+    /// Returns `true` if the value is `null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, JsonValue::Null)
+    }
+
+    /// Returns `true` if the value is a boolean.
+    pub fn is_bool(&self) -> bool {
+        matches!(self, JsonValue::Bool(_))
+    }
+
+    /// Returns `true` if the value is a number.
+    pub fn is_number(&self) -> bool {
+        matches!(self, JsonValue::Number(_))
+    }
+
+    /// Returns `true` if the value is a string.
+    pub fn is_string(&self) -> bool {
+        matches!(self, JsonValue::String(_))
+    }
+
+    /// Returns `true` if the value is an array.
+    pub fn is_array(&self) -> bool {
+        matches!(self, JsonValue::Array(_))
+    }
+
+    /// Returns `true` if the value is an object.
+    pub fn is_object(&self) -> bool {
+        matches!(self, JsonValue::Object(_))
+    }
+
+    /// Returns the boolean, or `None` if the value is not a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the number, or `None` if the value is not a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the number as an `i64` if it is an integer within the `i64` range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::JsonValue;
+    ///
+    /// assert_eq!(JsonValue::Number(-3.0).as_i64(), Some(-3));
+    /// assert_eq!(JsonValue::Number(1.5).as_i64(), None);
+    /// assert_eq!(JsonValue::String("3".to_string()).as_i64(), None);
+    /// ```
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            // 2^63 is exactly representable; every f64 below it converts without overflow.
+            JsonValue::Number(n)
+                if n.fract() == 0.0 && *n >= -(2f64.powi(63)) && *n < 2f64.powi(63) =>
+            {
+                Some(*n as i64)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number as a `u64` if it is a non-negative integer within the `u64` range.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JsonValue::Number(n) if n.fract() == 0.0 && *n >= 0.0 && *n < 2f64.powi(64) => {
+                Some(*n as u64)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the string slice, or `None` if the value is not a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements, or `None` if the value is not an array.
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements mutably, or `None` if the value is not an array.
+    pub fn as_array_mut(&mut self) -> Option<&mut Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the members, or `None` if the value is not an object.
+    pub fn as_object(&self) -> Option<&HashMap<String, JsonValue>> {
+        match self {
+            JsonValue::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Returns the members mutably, or `None` if the value is not an object.
+    pub fn as_object_mut(&mut self) -> Option<&mut HashMap<String, JsonValue>> {
+        match self {
+            JsonValue::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Looks up an object member by key or an array element by index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - A key (`&str` or `String`) or an index (`usize`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&JsonValue)` if the member or element exists.
+    /// * `None` if it does not, or if the value is not a container of the matching kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    ///
+    /// let doc = parse_json(r#"{"tags": ["a", "b"]}"#, None).unwrap();
+    ///
+    /// assert_eq!(doc.get("tags").and_then(|t| t.get(1)).and_then(|t| t.as_str()), Some("b"));
+    /// assert!(doc.get("missing").is_none());
+    /// assert!(doc.get(0).is_none());
+    /// ```
+    pub fn get<I: JsonIndex>(&self, index: I) -> Option<&JsonValue> {
+        index.index_into(self)
+    }
+
+    /// Like [`get`](JsonValue::get), but returns a mutable reference.
+    pub fn get_mut<I: JsonIndex>(&mut self, index: I) -> Option<&mut JsonValue> {
+        index.index_into_mut(self)
+    }
+
+    /// Takes the value out, leaving `null` in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    ///
+    /// let mut doc = parse_json(r#"{"a": [1, 2]}"#, None).unwrap();
+    /// let a = doc["a"].take();
+    ///
+    /// assert_eq!(a.len(), 2);
+    /// assert!(doc["a"].is_null());
+    /// ```
+    pub fn take(&mut self) -> JsonValue {
+        mem::replace(self, JsonValue::Null)
+    }
+
+    /// Returns the number of elements of an array or members of an object, and `0` for
+    /// any other value.
+    pub fn len(&self) -> usize {
+        match self {
+            JsonValue::Array(items) => items.len(),
+            JsonValue::Object(map) => map.len(),
+            _ => 0,
+        }
+    }
+
+    /// Returns `true` if [`len`](JsonValue::len) is `0`.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the elements of an array; yields nothing for any other value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    ///
+    /// let doc = parse_json("[1, 2, 3]", None).unwrap();
+    /// let sum: f64 = doc.elements().filter_map(|v| v.as_f64()).sum();
+    /// assert_eq!(sum, 6.0);
+    /// ```
+    pub fn elements(&self) -> std::slice::Iter<'_, JsonValue> {
+        match self {
+            JsonValue::Array(items) => items.iter(),
+            _ => [].iter(),
+        }
+    }
+
+    /// Iterates over the members of an object in key order; yields nothing for any other
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    ///
+    /// let doc = parse_json(r#"{"b": 2, "a": 1}"#, None).unwrap();
+    /// let keys: Vec<_> = doc.members().map(|(key, _)| key).collect();
+    /// assert_eq!(keys, ["a", "b"]);
+    /// ```
+    pub fn members(&self) -> impl Iterator<Item = (&str, &JsonValue)> {
+        let mut members: Vec<_> = match self {
+            JsonValue::Object(map) => map.iter().map(|(k, v)| (k.as_str(), v)).collect(),
+            _ => Vec::new(),
+        };
+        members.sort_by_key(|(key, _)| *key);
+        members.into_iter()
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::model::{JsonParseOptions, JsonValue};
+
+/// How deeply generated arrays and objects are nested at most.
+const MAX_DEPTH: usize = 8;
+
+/// How many elements or members a generated array or object has at most.
+const MAX_LEN: usize = 8;
+
+impl<'a> Arbitrary<'a> for JsonValue {
+    /// Generates a random document from fuzzer input, for fuzz targets and property tests.
+    ///
+    /// Values are nested at most 8 levels deep with at most 8 elements or members per
+    /// container, and numbers are always finite, so every generated value can be
+    /// serialized and parsed back unchanged. Strings may contain any character, including
+    /// ones that need escaping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use synson::{parse_json, serialize_json, JsonValue};
+    ///
+    /// let bytes: Vec<u8> = (0..=255).collect();
+    /// let value = JsonValue::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    /// assert_eq!(parse_json(&serialize_json(&value), None), Ok(value));
+    /// ```
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        value(u, 0)
+    }
+}
+
+fn value(u: &mut Unstructured<'_>, depth: usize) -> Result<JsonValue> {
+    let kinds = if depth < MAX_DEPTH { 6 } else { 4 };
+    Ok(match u.choose_index(kinds)? {
+        0 => JsonValue::Null,
+        1 => JsonValue::Bool(u.arbitrary()?),
+        2 => JsonValue::Number(number(u)?),
+        3 => JsonValue::String(u.arbitrary()?),
+        4 => {
+            let len = u.int_in_range(0..=MAX_LEN)?;
+            let items = (0..len)
+                .map(|_| value(u, depth + 1))
+                .collect::<Result<_>>()?;
+            JsonValue::Array(items)
+        }
+        _ => {
+            let len = u.int_in_range(0..=MAX_LEN)?;
+            let mut map = HashMap::with_capacity(len);
+            for _ in 0..len {
+                map.insert(u.arbitrary()?, value(u, depth + 1)?);
+            }
+            JsonValue::Object(map)
+        }
+    })
+}
+
+/// Mixes small integers, short decimals and arbitrary finite `f64`s, since the interesting
+/// cases for printing and parsing differ between them.
+fn number(u: &mut Unstructured<'_>) -> Result<f64> {
+    Ok(match u.choose_index(3)? {
+        0 => f64::from(u.arbitrary::<i32>()?),
+        1 => f64::from(u.arbitrary::<i16>()?) / 100.0,
+        _ => {
+            let n = f64::from_bits(u.arbitrary()?);
+            if n.is_finite() {
+                n
+            } else {
+                0.0
+            }
+        }
+    })
+}
+
+impl<'a> Arbitrary<'a> for JsonParseOptions {
+    /// Generates any combination of parse options.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(JsonParseOptions::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
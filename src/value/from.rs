@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use crate::model::JsonValue;
+
+// JSON numbers are stored as `f64`: integers beyond 2^53 in magnitude lose precision.
+macro_rules! from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for JsonValue {
+                fn from(n: $ty) -> Self {
+                    JsonValue::Number(n as f64)
+                }
+            }
+        )*
+    };
+}
+
+from_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+impl From<bool> for JsonValue {
+    fn from(b: bool) -> Self {
+        JsonValue::Bool(b)
+    }
+}
+
+impl From<&str> for JsonValue {
+    fn from(s: &str) -> Self {
+        JsonValue::String(s.to_string())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(s: String) -> Self {
+        JsonValue::String(s)
+    }
+}
+
+impl From<char> for JsonValue {
+    fn from(c: char) -> Self {
+        JsonValue::String(c.to_string())
+    }
+}
+
+impl From<()> for JsonValue {
+    fn from(_: ()) -> Self {
+        JsonValue::Null
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    /// Converts `None` into `null` and `Some(value)` into the converted value.
+    fn from(option: Option<T>) -> Self {
+        option.map_or(JsonValue::Null, Into::into)
+    }
+}
+
+impl<T: Into<JsonValue>> From<Vec<T>> for JsonValue {
+    fn from(items: Vec<T>) -> Self {
+        JsonValue::Array(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Clone + Into<JsonValue>> From<&[T]> for JsonValue {
+    fn from(items: &[T]) -> Self {
+        JsonValue::Array(items.iter().cloned().map(Into::into).collect())
+    }
+}
+
+impl<K: Into<String>, V: Into<JsonValue>> From<HashMap<K, V>> for JsonValue {
+    fn from(map: HashMap<K, V>) -> Self {
+        JsonValue::Object(map.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+    }
+}
+
+impl<T: Into<JsonValue>> FromIterator<T> for JsonValue {
+    /// Collects values into a JSON array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::JsonValue;
+    ///
+    /// let squares: JsonValue = (1..=3).map(|n| n * n).collect();
+    /// assert_eq!(squares.to_string(), "[1,4,9]");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        JsonValue::Array(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<K: Into<String>, V: Into<JsonValue>> FromIterator<(K, V)> for JsonValue {
+    /// Collects key-value pairs into a JSON object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::JsonValue;
+    ///
+    /// let object: JsonValue = [("a", 1), ("b", 2)].into_iter().collect();
+    /// assert_eq!(object.to_string(), r#"{"a":1,"b":2}"#);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        JsonValue::Object(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,164 @@
+use std::ops;
+
+use crate::model::JsonValue;
+
+static NULL: JsonValue = JsonValue::Null;
+
+/// A type that can index into a [`JsonValue`]: `usize` for array elements, `str` and
+/// `String` for object members.
+///
+/// Used by [`JsonValue::get`], [`JsonValue::get_mut`] and the `[]` operator. This trait is
+/// sealed and cannot be implemented outside of this crate.
+pub trait JsonIndex: private::Sealed {
+    #[doc(hidden)]
+    fn index_into<'v>(&self, value: &'v JsonValue) -> Option<&'v JsonValue>;
+
+    #[doc(hidden)]
+    fn index_into_mut<'v>(&self, value: &'v mut JsonValue) -> Option<&'v mut JsonValue>;
+
+    /// Returns the slot for `[]=`, creating an object member if needed. Panics if the
+    /// value cannot be indexed this way.
+    #[doc(hidden)]
+    fn index_or_insert<'v>(&self, value: &'v mut JsonValue) -> &'v mut JsonValue;
+}
+
+impl JsonIndex for usize {
+    fn index_into<'v>(&self, value: &'v JsonValue) -> Option<&'v JsonValue> {
+        match value {
+            JsonValue::Array(items) => items.get(*self),
+            _ => None,
+        }
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut JsonValue) -> Option<&'v mut JsonValue> {
+        match value {
+            JsonValue::Array(items) => items.get_mut(*self),
+            _ => None,
+        }
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut JsonValue) -> &'v mut JsonValue {
+        match value {
+            JsonValue::Array(items) => {
+                let len = items.len();
+                items.get_mut(*self).unwrap_or_else(|| {
+                    panic!("cannot access index {self} of a JSON array of length {len}")
+                })
+            }
+            _ => panic!("cannot access index {self} of a JSON value that is not an array"),
+        }
+    }
+}
+
+impl JsonIndex for str {
+    fn index_into<'v>(&self, value: &'v JsonValue) -> Option<&'v JsonValue> {
+        match value {
+            JsonValue::Object(map) => map.get(self),
+            _ => None,
+        }
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut JsonValue) -> Option<&'v mut JsonValue> {
+        match value {
+            JsonValue::Object(map) => map.get_mut(self),
+            _ => None,
+        }
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut JsonValue) -> &'v mut JsonValue {
+        if value.is_null() {
+            *value = JsonValue::Object(Default::default());
+        }
+        match value {
+            JsonValue::Object(map) => map.entry(self.to_string()).or_insert(JsonValue::Null),
+            _ => panic!("cannot access key {self:?} of a JSON value that is not an object"),
+        }
+    }
+}
+
+impl JsonIndex for String {
+    fn index_into<'v>(&self, value: &'v JsonValue) -> Option<&'v JsonValue> {
+        self.as_str().index_into(value)
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut JsonValue) -> Option<&'v mut JsonValue> {
+        self.as_str().index_into_mut(value)
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut JsonValue) -> &'v mut JsonValue {
+        self.as_str().index_or_insert(value)
+    }
+}
+
+impl<T: JsonIndex + ?Sized> JsonIndex for &T {
+    fn index_into<'v>(&self, value: &'v JsonValue) -> Option<&'v JsonValue> {
+        (**self).index_into(value)
+    }
+
+    fn index_into_mut<'v>(&self, value: &'v mut JsonValue) -> Option<&'v mut JsonValue> {
+        (**self).index_into_mut(value)
+    }
+
+    fn index_or_insert<'v>(&self, value: &'v mut JsonValue) -> &'v mut JsonValue {
+        (**self).index_or_insert(value)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for usize {}
+    impl Sealed for str {}
+    impl Sealed for String {}
+    impl<T: Sealed + ?Sized> Sealed for &T {}
+}
+
+impl<I: JsonIndex> ops::Index<I> for JsonValue {
+    type Output = JsonValue;
+
+    /// Returns the member or element, or a shared `null` if it does not exist (including
+    /// when the value is not a container), so lookups can be chained without panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::parse_json;
+    ///
+    /// let doc = parse_json(r#"{"user": {"roles": ["admin"]}}"#, None).unwrap();
+    ///
+    /// assert_eq!(doc["user"]["roles"][0].as_str(), Some("admin"));
+    /// assert!(doc["user"]["email"].is_null());
+    /// assert!(doc["nope"][3]["deeper"].is_null());
+    /// ```
+    fn index(&self, index: I) -> &JsonValue {
+        index.index_into(self).unwrap_or(&NULL)
+    }
+}
+
+impl<I: JsonIndex> ops::IndexMut<I> for JsonValue {
+    /// Returns a mutable reference to the member or element.
+    ///
+    /// Indexing an object by key inserts `null` if the key is missing, and indexing `null`
+    /// by key first turns it into an empty object, so nested members can be assigned
+    /// directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an array index is out of bounds, or if the value is not an array (for
+    /// `usize`) or not an object or `null` (for keys).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use synson::{parse_json, JsonValue};
+    ///
+    /// let mut doc = JsonValue::Null;
+    /// doc["server"]["port"] = JsonValue::from(8080);
+    /// doc["tags"] = JsonValue::from(vec!["a"]);
+    /// doc["tags"][0] = JsonValue::from("b");
+    ///
+    /// assert_eq!(doc, parse_json(r#"{"server": {"port": 8080}, "tags": ["b"]}"#, None).unwrap());
+    /// ```
+    fn index_mut(&mut self, index: I) -> &mut JsonValue {
+        index.index_or_insert(self)
+    }
+}
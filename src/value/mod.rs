@@ -0,0 +1,7 @@
+mod access;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod from;
+pub mod index;
+
+pub use index::JsonIndex;
@@ -0,0 +1,155 @@
+use syn::{Attribute, ExprPath, LitStr};
+
+/// How an enum is represented in JSON.
+pub enum Tagging {
+    /// `{"Variant": content}`, or `"Variant"` for unit variants.
+    External,
+    /// `{"tag": "Variant", ...fields}`.
+    Internal { tag: String },
+    /// `{"tag": "Variant", "content": content}`.
+    Adjacent { tag: String, content: String },
+    /// The content alone; variants are tried in declaration order.
+    Untagged,
+}
+
+/// Container attributes: `#[json(tag = "...", content = "...")]` or `#[json(untagged)]`.
+pub struct ContainerAttrs {
+    pub tagging: Tagging,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut tag = None;
+        let mut content = None;
+        let mut untagged = None;
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("json")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    tag = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("content") {
+                    content = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("untagged") {
+                    untagged = Some(meta.path.clone());
+                } else {
+                    return Err(meta.error("unknown container attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        let tagging = match (tag, content, untagged) {
+            (None, None, None) => Tagging::External,
+            (Some(tag), None, None) => Tagging::Internal { tag },
+            (Some(tag), Some(content), None) => Tagging::Adjacent { tag, content },
+            (None, None, Some(_)) => Tagging::Untagged,
+            (None, Some(_), None) => {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "`content` requires `tag`",
+                ))
+            }
+            (_, _, Some(path)) => {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    "`untagged` cannot be combined with `tag` or `content`",
+                ))
+            }
+        };
+        Ok(ContainerAttrs { tagging })
+    }
+
+    /// Rejects container attributes on structs, which have no tagging to configure.
+    pub fn reject(attrs: &[Attribute]) -> syn::Result<()> {
+        match attrs.iter().find(|a| a.path().is_ident("json")) {
+            Some(attr) => Err(syn::Error::new_spanned(
+                attr,
+                "json container attributes are only supported on enums",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Variant attributes: `#[json(rename = "...")]`.
+pub struct VariantAttrs {
+    pub rename: Option<String>,
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut rename = None;
+        for attr in attrs.iter().filter(|a| a.path().is_ident("json")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown variant attribute"))
+                }
+            })?;
+        }
+        Ok(VariantAttrs { rename })
+    }
+}
+
+/// Where a missing member's value comes from.
+pub enum FieldDefault {
+    /// `#[json(default)]`: `Default::default()`.
+    Trait,
+    /// `#[json(default = "path")]`: a call to the named function.
+    Path(ExprPath),
+}
+
+/// Field attributes: `rename`, `default`, `skip` and `flatten`.
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub default: Option<FieldDefault>,
+    pub skip: bool,
+    pub flatten: bool,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = FieldAttrs::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("json")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    parsed.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("default") {
+                    parsed.default = Some(if meta.input.peek(syn::Token![=]) {
+                        FieldDefault::Path(meta.value()?.parse::<LitStr>()?.parse()?)
+                    } else {
+                        FieldDefault::Trait
+                    });
+                } else if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                } else if meta.path.is_ident("flatten") {
+                    parsed.flatten = true;
+                } else {
+                    return Err(meta.error("unknown field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        if parsed.flatten && (parsed.rename.is_some() || parsed.skip) {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "`flatten` cannot be combined with `rename` or `skip`",
+            ));
+        }
+        Ok(parsed)
+    }
+
+    /// Rejects attributes on tuple fields, which have no member name to act on.
+    pub fn reject(attrs: &[Attribute]) -> syn::Result<()> {
+        match attrs.iter().find(|a| a.path().is_ident("json")) {
+            Some(attr) => Err(syn::Error::new_spanned(
+                attr,
+                "json attributes are only supported on named fields",
+            )),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use syn::{parse_quote, Fields, Generics, Index, Member, Path, Type};
+
+use crate::attr::{FieldAttrs, FieldDefault};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The shape of a struct or variant body.
+pub enum Style {
+    Unit,
+    Newtype,
+    Tuple,
+    Struct,
+}
+
+/// A field together with its JSON member name and attributes.
+pub struct Field<'a> {
+    pub member: Member,
+    pub ty: &'a Type,
+    pub key: String,
+    pub attrs: FieldAttrs,
+}
+
+impl Field<'_> {
+    /// The value of a skipped or defaulted field.
+    pub fn default_value(&self) -> TokenStream {
+        match &self.attrs.default {
+            Some(FieldDefault::Path(path)) => quote!(#path()),
+            Some(FieldDefault::Trait) | None => quote!(::core::default::Default::default()),
+        }
+    }
+}
+
+pub struct Body<'a> {
+    pub style: Style,
+    pub fields: Vec<Field<'a>>,
+}
+
+impl<'a> Body<'a> {
+    pub fn parse(fields: &'a Fields) -> syn::Result<Self> {
+        let style = match fields {
+            Fields::Unit => Style::Unit,
+            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => Style::Newtype,
+            Fields::Unnamed(_) => Style::Tuple,
+            Fields::Named(_) => Style::Struct,
+        };
+
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let (member, key, attrs) = match &field.ident {
+                    Some(ident) => {
+                        let attrs = FieldAttrs::parse(&field.attrs)?;
+                        let key = attrs.rename.clone().unwrap_or_else(|| {
+                            let name = ident.to_string();
+                            name.strip_prefix("r#").map(str::to_string).unwrap_or(name)
+                        });
+                        (Member::Named(ident.clone()), key, attrs)
+                    }
+                    None => {
+                        FieldAttrs::reject(&field.attrs)?;
+                        let member = Member::Unnamed(Index::from(i));
+                        (member, i.to_string(), FieldAttrs::default())
+                    }
+                };
+                Ok(Field {
+                    member,
+                    ty: &field.ty,
+                    key,
+                    attrs,
+                })
+            })
+            .collect::<syn::Result<_>>()?;
+
+        Ok(Body { style, fields })
+    }
+}
+
+/// Adds `T: bound` for every type parameter.
+pub fn bounded(generics: &Generics, bound: Path) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+    generics
+}
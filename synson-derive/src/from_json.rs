@@ -0,0 +1,274 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DataEnum, DeriveInput, Ident};
+
+use crate::attr::{ContainerAttrs, Tagging, VariantAttrs};
+use crate::body::{bounded, Body, Style};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let generics = bounded(&input.generics, parse_quote!(::synson::convert::FromJson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            ContainerAttrs::reject(&input.attrs)?;
+            construct(&quote!(Self), &Body::parse(&data.fields)?, &quote!(value))
+        }
+        Data::Enum(data) => expand_enum(name, data, &ContainerAttrs::parse(&input.attrs)?)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "FromJson cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::synson::convert::FromJson for #name #ty_generics #where_clause {
+            #[allow(clippy::redundant_closure_call)]
+            fn from_json(
+                value: &::synson::JsonValue,
+            ) -> ::core::result::Result<Self, ::synson::model::FromJsonError> {
+                #body
+            }
+        }
+    })
+}
+
+/// Builds `ctor` from the JSON expression `value`, as an expression of type
+/// `Result<Self, FromJsonError>`. The code may return early with an error, so callers that
+/// need to intercept errors wrap it with [`attempt`].
+fn construct(ctor: &TokenStream, body: &Body, value: &TokenStream) -> TokenStream {
+    match body.style {
+        Style::Unit => quote! {
+            match #value {
+                ::synson::JsonValue::Null => ::core::result::Result::Ok(#ctor),
+                other => ::core::result::Result::Err(
+                    ::synson::model::FromJsonError::invalid_type("null", other),
+                ),
+            }
+        },
+        Style::Newtype => {
+            let ty = body.fields[0].ty;
+            quote! {
+                ::core::result::Result::Ok(#ctor(
+                    <#ty as ::synson::convert::FromJson>::from_json(#value)?,
+                ))
+            }
+        }
+        Style::Tuple => {
+            let len = body.fields.len();
+            let elements = body.fields.iter().enumerate().map(|(i, field)| {
+                let ty = field.ty;
+                let key = &field.key;
+                quote! {
+                    <#ty as ::synson::convert::FromJson>::from_json(&items[#i])
+                        .map_err(|e| e.at(#key))?
+                }
+            });
+            quote! {
+                match #value {
+                    ::synson::JsonValue::Array(items) if items.len() == #len => {
+                        ::core::result::Result::Ok(#ctor(#(#elements),*))
+                    }
+                    ::synson::JsonValue::Array(items) => ::core::result::Result::Err(
+                        ::synson::model::FromJsonError::new(::std::format!(
+                            "expected an array of length {}, found length {}",
+                            #len,
+                            items.len()
+                        )),
+                    ),
+                    other => ::core::result::Result::Err(
+                        ::synson::model::FromJsonError::invalid_type("an array", other),
+                    ),
+                }
+            }
+        }
+        Style::Struct => {
+            let members = body.fields.iter().map(|field| {
+                let member = &field.member;
+                let ty = field.ty;
+                let key = &field.key;
+                let expr = if field.attrs.skip {
+                    field.default_value()
+                } else if field.attrs.flatten {
+                    quote!(<#ty as ::synson::convert::FromJson>::from_json(#value)?)
+                } else {
+                    let missing = if field.attrs.default.is_some() {
+                        field.default_value()
+                    } else {
+                        quote! {
+                            <#ty as ::synson::convert::FromJson>::from_missing().ok_or_else(
+                                || ::synson::model::FromJsonError::missing_field(#key),
+                            )?
+                        }
+                    };
+                    quote! {
+                        match object.get(#key) {
+                            ::core::option::Option::Some(member) => {
+                                <#ty as ::synson::convert::FromJson>::from_json(member)
+                                    .map_err(|e| e.at(#key))?
+                            }
+                            ::core::option::Option::None => #missing,
+                        }
+                    }
+                };
+                quote!(#member: #expr)
+            });
+            quote! {{
+                let object = match #value {
+                    ::synson::JsonValue::Object(object) => object,
+                    other => {
+                        return ::core::result::Result::Err(
+                            ::synson::model::FromJsonError::invalid_type("an object", other),
+                        )
+                    }
+                };
+                let _ = object;
+                ::core::result::Result::Ok(#ctor { #(#members),* })
+            }}
+        }
+    }
+}
+
+/// Runs `code` in a closure so early returns produce a value instead of leaving
+/// `from_json`.
+fn attempt(code: TokenStream) -> TokenStream {
+    quote! {
+        (|| -> ::core::result::Result<Self, ::synson::model::FromJsonError> { #code })()
+    }
+}
+
+fn expand_enum(name: &Ident, data: &DataEnum, attrs: &ContainerAttrs) -> syn::Result<TokenStream> {
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+        let key = variant_attrs
+            .rename
+            .unwrap_or_else(|| variant.ident.to_string());
+        let body = Body::parse(&variant.fields)?;
+        if let (Tagging::Internal { .. }, Style::Tuple) = (&attrs.tagging, &body.style) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "internally tagged enums cannot contain tuple variants",
+            ));
+        }
+        let ident = &variant.ident;
+        variants.push((key, quote!(Self::#ident), body));
+    }
+
+    let expected = variants
+        .iter()
+        .map(|(key, _, _)| format!("'{key}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let unknown = quote! {
+        ::synson::model::FromJsonError::new(::std::format!(
+            "unknown variant '{}', expected one of {}",
+            variant,
+            #expected
+        ))
+    };
+
+    let code = match &attrs.tagging {
+        Tagging::External => {
+            let units = variants
+                .iter()
+                .filter(|(_, _, body)| matches!(body.style, Style::Unit))
+                .map(|(key, ctor, _)| quote!(#key => ::core::result::Result::Ok(#ctor)));
+            let others = variants
+                .iter()
+                .filter(|(_, _, body)| !matches!(body.style, Style::Unit))
+                .map(|(key, ctor, body)| {
+                    let code = attempt(construct(ctor, body, &quote!(content)));
+                    quote!(#key => #code.map_err(|e| e.at(#key)))
+                });
+            quote! {
+                match value {
+                    ::synson::JsonValue::String(variant) => match variant.as_str() {
+                        #(#units,)*
+                        _ => ::core::result::Result::Err(#unknown),
+                    },
+                    ::synson::JsonValue::Object(object) if object.len() == 1 => {
+                        let (variant, content) = object.iter().next().unwrap();
+                        let _ = content;
+                        match variant.as_str() {
+                            #(#others,)*
+                            _ => ::core::result::Result::Err(#unknown),
+                        }
+                    }
+                    other => ::core::result::Result::Err(
+                        ::synson::model::FromJsonError::invalid_type(
+                            "a string or an object with a single member",
+                            other,
+                        ),
+                    ),
+                }
+            }
+        }
+        Tagging::Internal { tag } | Tagging::Adjacent { tag, .. } => {
+            let arms = variants.iter().map(|(key, ctor, body)| {
+                let code = match (&attrs.tagging, &body.style) {
+                    (_, Style::Unit) => quote!(::core::result::Result::Ok(#ctor)),
+                    (Tagging::Adjacent { content, .. }, _) => {
+                        let code = attempt(construct(ctor, body, &quote!(content)));
+                        quote! {{
+                            let content = object.get(#content).ok_or_else(
+                                || ::synson::model::FromJsonError::missing_field(#content),
+                            )?;
+                            #code.map_err(|e| e.at(#content))
+                        }}
+                    }
+                    _ => construct(ctor, body, &quote!(value)),
+                };
+                quote!(#key => #code)
+            });
+            quote! {
+                let object = match value {
+                    ::synson::JsonValue::Object(object) => object,
+                    other => {
+                        return ::core::result::Result::Err(
+                            ::synson::model::FromJsonError::invalid_type("an object", other),
+                        )
+                    }
+                };
+                let variant = match object.get(#tag) {
+                    ::core::option::Option::Some(::synson::JsonValue::String(variant)) => variant,
+                    ::core::option::Option::Some(other) => {
+                        return ::core::result::Result::Err(
+                            ::synson::model::FromJsonError::invalid_type("a string", other)
+                                .at(#tag),
+                        )
+                    }
+                    ::core::option::Option::None => {
+                        return ::core::result::Result::Err(
+                            ::synson::model::FromJsonError::missing_field(#tag),
+                        )
+                    }
+                };
+                match variant.as_str() {
+                    #(#arms,)*
+                    _ => ::core::result::Result::Err(#unknown.at(#tag)),
+                }
+            }
+        }
+        Tagging::Untagged => {
+            let tries = variants.iter().map(|(_, ctor, body)| {
+                let code = attempt(construct(ctor, body, &quote!(value)));
+                quote! {
+                    if let ::core::result::Result::Ok(result) = #code {
+                        return ::core::result::Result::Ok(result);
+                    }
+                }
+            });
+            let message = format!("data did not match any variant of untagged enum {name}");
+            quote! {
+                #(#tries)*
+                ::core::result::Result::Err(::synson::model::FromJsonError::new(#message))
+            }
+        }
+    };
+    Ok(code)
+}
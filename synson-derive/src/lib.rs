@@ -0,0 +1,65 @@
+//! Derive macros for the `synson` [`FromJson`] and [`ToJson`] traits.
+//!
+//! Enable the `derive` feature of `synson` and import the macros from there rather than
+//! depending on this crate directly:
+//!
+//! ```ignore
+//! use synson::{FromJson, ToJson};
+//!
+//! #[derive(FromJson, ToJson)]
+//! struct User {
+//!     #[json(rename = "userName")]
+//!     name: String,
+//!     #[json(default)]
+//!     admin: bool,
+//! }
+//! ```
+//!
+//! # Representation
+//!
+//! - Structs with named fields map to objects, newtype structs to their single field,
+//!   tuple structs to arrays and unit structs to `null`.
+//! - Enums are externally tagged by default (`"Unit"` or `{"Variant": content}`).
+//!   `#[json(tag = "type")]` selects internal tagging, `#[json(tag = "t", content = "c")]`
+//!   adjacent tagging, and `#[json(untagged)]` tries each variant in declaration order.
+//!   These container attributes are rejected on structs.
+//!
+//! # Field attributes
+//!
+//! - `#[json(rename = "name")]` uses a different member name (also valid on variants).
+//! - `#[json(default)]` or `#[json(default = "path::to::fn")]` fills in absent members.
+//! - `#[json(skip)]` leaves the field out of the output and fills it from its default.
+//! - `#[json(flatten)]` reads the field from the enclosing object and merges its members
+//!   into the output.
+//!
+//! Absent members are otherwise filled by `FromJson::from_missing`, so `Option` fields are
+//! optional; unknown members are ignored.
+//!
+//! [`FromJson`]: https://docs.rs/synson/latest/synson/convert/trait.FromJson.html
+//! [`ToJson`]: https://docs.rs/synson/latest/synson/convert/trait.ToJson.html
+
+mod attr;
+mod body;
+mod from_json;
+mod to_json;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `synson::convert::FromJson`.
+#[proc_macro_derive(FromJson, attributes(json))]
+pub fn derive_from_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_json::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `synson::convert::ToJson`.
+#[proc_macro_derive(ToJson, attributes(json))]
+pub fn derive_to_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_json::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
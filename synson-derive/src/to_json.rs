@@ -0,0 +1,202 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DataEnum, DeriveInput, Member};
+
+use crate::attr::{ContainerAttrs, Tagging, VariantAttrs};
+use crate::body::{bounded, Body, Style};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let generics = bounded(&input.generics, parse_quote!(::synson::convert::ToJson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            ContainerAttrs::reject(&input.attrs)?;
+            let body = Body::parse(&data.fields)?;
+            let access: Vec<_> = body
+                .fields
+                .iter()
+                .map(|field| {
+                    let member = &field.member;
+                    quote!(&self.#member)
+                })
+                .collect();
+            serialize(&body, &access, None)
+        }
+        Data::Enum(data) => expand_enum(data, &ContainerAttrs::parse(&input.attrs)?)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "ToJson cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::synson::convert::ToJson for #name #ty_generics #where_clause {
+            fn to_json(&self) -> ::synson::JsonValue {
+                #body
+            }
+        }
+    })
+}
+
+/// Serializes a body whose fields are reachable through the `access` expressions. `tag`
+/// is an extra `(name, value)` member placed first in struct bodies.
+fn serialize(body: &Body, access: &[TokenStream], tag: Option<(&str, &str)>) -> TokenStream {
+    match body.style {
+        Style::Unit => quote!(::synson::JsonValue::Null),
+        Style::Newtype => {
+            let field = &access[0];
+            quote!(::synson::convert::ToJson::to_json(#field))
+        }
+        Style::Tuple => quote! {
+            ::synson::JsonValue::Array(::std::vec![
+                #(::synson::convert::ToJson::to_json(#access)),*
+            ])
+        },
+        Style::Struct => {
+            let tag = tag.map(|(name, value)| {
+                quote! {
+                    object.insert(
+                        ::std::string::String::from(#name),
+                        ::synson::JsonValue::String(::std::string::String::from(#value)),
+                    );
+                }
+            });
+            let members = body
+                .fields
+                .iter()
+                .zip(access)
+                .filter(|(field, _)| !field.attrs.skip)
+                .map(|(field, access)| {
+                    let key = &field.key;
+                    if field.attrs.flatten {
+                        quote! {
+                            if let ::synson::JsonValue::Object(members) =
+                                ::synson::convert::ToJson::to_json(#access)
+                            {
+                                object.extend(members);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            object.insert(
+                                ::std::string::String::from(#key),
+                                ::synson::convert::ToJson::to_json(#access),
+                            );
+                        }
+                    }
+                });
+            quote! {{
+                #[allow(unused_mut)]
+                let mut object = ::std::collections::HashMap::new();
+                #tag
+                #(#members)*
+                ::synson::JsonValue::Object(object)
+            }}
+        }
+    }
+}
+
+/// Builds a single-member object.
+fn member(key: &str, value: TokenStream) -> TokenStream {
+    quote! {{
+        let mut object = ::std::collections::HashMap::new();
+        object.insert(::std::string::String::from(#key), #value);
+        ::synson::JsonValue::Object(object)
+    }}
+}
+
+fn expand_enum(data: &DataEnum, attrs: &ContainerAttrs) -> syn::Result<TokenStream> {
+    if data.variants.is_empty() {
+        return Ok(quote!(match *self {}));
+    }
+
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+        let key = variant_attrs
+            .rename
+            .unwrap_or_else(|| variant.ident.to_string());
+        let body = Body::parse(&variant.fields)?;
+        let ident = &variant.ident;
+
+        let bindings: Vec<_> = (0..body.fields.len())
+            .map(|i| format_ident!("field{}", i))
+            .collect();
+        let access: Vec<_> = bindings.iter().map(|b| quote!(#b)).collect();
+        let pattern = match body.style {
+            Style::Unit => quote!(Self::#ident),
+            Style::Newtype | Style::Tuple => quote!(Self::#ident(#(#bindings),*)),
+            Style::Struct => {
+                let members = body.fields.iter().zip(&bindings).map(|(field, binding)| {
+                    let Member::Named(name) = &field.member else {
+                        unreachable!("struct variants have named fields")
+                    };
+                    if field.attrs.skip {
+                        quote!(#name: _)
+                    } else {
+                        quote!(#name: #binding)
+                    }
+                });
+                quote!(Self::#ident { #(#members),* })
+            }
+        };
+
+        let value = match (&attrs.tagging, &body.style) {
+            (Tagging::External, Style::Unit) => {
+                quote!(::synson::JsonValue::String(::std::string::String::from(#key)))
+            }
+            (Tagging::External, _) => member(&key, serialize(&body, &access, None)),
+            (Tagging::Internal { tag } | Tagging::Adjacent { tag, .. }, Style::Unit) => member(
+                tag,
+                quote!(::synson::JsonValue::String(::std::string::String::from(#key))),
+            ),
+            (Tagging::Internal { tag }, Style::Newtype) => {
+                let content = serialize(&body, &access, None);
+                quote! {{
+                    let mut value = #content;
+                    if let ::synson::JsonValue::Object(object) = &mut value {
+                        object.insert(
+                            ::std::string::String::from(#tag),
+                            ::synson::JsonValue::String(::std::string::String::from(#key)),
+                        );
+                    }
+                    value
+                }}
+            }
+            (Tagging::Internal { tag }, Style::Struct) => {
+                serialize(&body, &access, Some((tag, &key)))
+            }
+            (Tagging::Internal { .. }, Style::Tuple) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "internally tagged enums cannot contain tuple variants",
+                ))
+            }
+            (Tagging::Adjacent { tag, content }, _) => {
+                let value = serialize(&body, &access, None);
+                quote! {{
+                    let mut object = ::std::collections::HashMap::new();
+                    object.insert(
+                        ::std::string::String::from(#tag),
+                        ::synson::JsonValue::String(::std::string::String::from(#key)),
+                    );
+                    object.insert(::std::string::String::from(#content), #value);
+                    ::synson::JsonValue::Object(object)
+                }}
+            }
+            (Tagging::Untagged, _) => serialize(&body, &access, None),
+        };
+        arms.push(quote!(#pattern => #value));
+    }
+
+    Ok(quote! {
+        match self {
+            #(#arms,)*
+        }
+    })
+}
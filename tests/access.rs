@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use synson::{parse_json, JsonValue};
+
+fn sample() -> JsonValue {
+    parse_json(
+        r#"{"name": "synson", "version": 3, "ratio": 0.5, "stable": false,
+            "tags": ["json", "parser"], "meta": {"owner": null}}"#,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn should_check_value_types() {
+    let doc = sample();
+
+    assert!(doc.is_object());
+    assert!(doc["name"].is_string());
+    assert!(doc["version"].is_number());
+    assert!(doc["stable"].is_bool());
+    assert!(doc["tags"].is_array());
+    assert!(doc["meta"]["owner"].is_null());
+    assert!(!doc["tags"].is_object());
+}
+
+#[test]
+fn should_convert_with_as_accessors() {
+    let doc = sample();
+
+    assert_eq!(doc["name"].as_str(), Some("synson"));
+    assert_eq!(doc["version"].as_i64(), Some(3));
+    assert_eq!(doc["version"].as_u64(), Some(3));
+    assert_eq!(doc["ratio"].as_f64(), Some(0.5));
+    assert_eq!(doc["ratio"].as_i64(), None);
+    assert_eq!(doc["stable"].as_bool(), Some(false));
+    assert_eq!(doc["tags"].as_array().map(Vec::len), Some(2));
+    assert_eq!(doc["meta"].as_object().map(HashMap::len), Some(1));
+    assert_eq!(doc["name"].as_bool(), None);
+    assert_eq!(JsonValue::Number(-1.0).as_u64(), None);
+    assert_eq!(JsonValue::Number(1e300).as_i64(), None);
+}
+
+#[test]
+fn should_mutate_with_as_mut_accessors() {
+    let mut doc = sample();
+
+    doc["tags"]
+        .as_array_mut()
+        .unwrap()
+        .push(JsonValue::from("fast"));
+    doc.as_object_mut().unwrap().remove("meta");
+
+    assert_eq!(doc["tags"].len(), 3);
+    assert!(doc.get("meta").is_none());
+    assert!(doc["name"].as_array_mut().is_none());
+}
+
+#[test]
+fn should_get_by_key_and_index() {
+    let mut doc = sample();
+
+    assert_eq!(
+        doc.get("tags").and_then(|t| t.get(0)),
+        Some(&JsonValue::from("json"))
+    );
+    assert_eq!(doc.get(String::from("version")), Some(&JsonValue::from(3)));
+    assert!(doc.get("tags").unwrap().get(5).is_none());
+    assert!(doc.get("name").unwrap().get("x").is_none());
+
+    *doc.get_mut("version").unwrap() = JsonValue::from(4);
+    assert_eq!(doc["version"], JsonValue::from(4));
+}
+
+#[test]
+fn should_return_shared_null_for_missing_index() {
+    let doc = sample();
+
+    assert!(doc["missing"].is_null());
+    assert!(doc["tags"][10].is_null());
+    assert!(doc["name"]["not-an-object"].is_null());
+    assert!(doc[0].is_null());
+}
+
+#[test]
+fn should_insert_through_index_mut() {
+    let mut doc = sample();
+
+    doc["meta"]["owner"] = JsonValue::from("team");
+    doc["meta"]["new"]["nested"] = JsonValue::from(true);
+    doc["tags"][1] = JsonValue::from("library");
+
+    assert_eq!(doc["meta"]["owner"].as_str(), Some("team"));
+    assert_eq!(doc["meta"]["new"]["nested"].as_bool(), Some(true));
+    assert_eq!(doc["tags"][1].as_str(), Some("library"));
+}
+
+#[test]
+#[should_panic(expected = "cannot access index 5 of a JSON array of length 2")]
+fn should_panic_on_out_of_bounds_index_mut() {
+    let mut doc = sample();
+    doc["tags"][5] = JsonValue::Null;
+}
+
+#[test]
+#[should_panic(expected = "not an object")]
+fn should_panic_on_key_index_mut_of_scalar() {
+    let mut doc = sample();
+    doc["name"]["x"] = JsonValue::Null;
+}
+
+#[test]
+fn should_take_value_and_leave_null() {
+    let mut doc = sample();
+    let tags = doc["tags"].take();
+
+    assert_eq!(tags, JsonValue::from(vec!["json", "parser"]));
+    assert!(doc["tags"].is_null());
+    assert_eq!(doc.len(), 6);
+}
+
+#[test]
+fn should_report_len_and_iterate() {
+    let doc = sample();
+
+    assert_eq!(doc.len(), 6);
+    assert_eq!(doc["tags"].len(), 2);
+    assert_eq!(doc["name"].len(), 0);
+    assert!(doc["name"].is_empty());
+
+    let tags: Vec<_> = doc["tags"]
+        .elements()
+        .filter_map(JsonValue::as_str)
+        .collect();
+    assert_eq!(tags, ["json", "parser"]);
+    assert_eq!(doc["name"].elements().count(), 0);
+
+    let keys: Vec<_> = doc.members().map(|(key, _)| key).collect();
+    assert_eq!(keys, ["meta", "name", "ratio", "stable", "tags", "version"]);
+    assert_eq!(doc["tags"].members().count(), 0);
+}
+
+#[test]
+fn should_convert_from_rust_values() {
+    assert_eq!(JsonValue::from(true), JsonValue::Bool(true));
+    assert_eq!(JsonValue::from(7u8), JsonValue::Number(7.0));
+    assert_eq!(JsonValue::from(-7i64), JsonValue::Number(-7.0));
+    assert_eq!(JsonValue::from(1.5f32), JsonValue::Number(1.5));
+    assert_eq!(JsonValue::from("a"), JsonValue::String("a".to_string()));
+    assert_eq!(
+        JsonValue::from(String::from("b")),
+        JsonValue::String("b".to_string())
+    );
+    assert_eq!(JsonValue::from('c'), JsonValue::String("c".to_string()));
+    assert_eq!(JsonValue::from(()), JsonValue::Null);
+    assert_eq!(JsonValue::from(None::<i32>), JsonValue::Null);
+    assert_eq!(JsonValue::from(Some(2)), JsonValue::Number(2.0));
+    assert_eq!(
+        JsonValue::from(vec![Some(1), None]),
+        JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Null])
+    );
+    assert_eq!(JsonValue::from(&[1, 2][..]).len(), 2);
+
+    let mut map = HashMap::new();
+    map.insert("k", vec![true]);
+    assert_eq!(
+        JsonValue::from(map),
+        parse_json(r#"{"k": [true]}"#, None).unwrap()
+    );
+}
+
+#[test]
+fn should_collect_into_arrays_and_objects() {
+    let array: JsonValue = vec!["x", "y"].into_iter().collect();
+    assert_eq!(array, parse_json(r#"["x", "y"]"#, None).unwrap());
+
+    let object: JsonValue = vec![("a".to_string(), 1), ("b".to_string(), 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(object, parse_json(r#"{"a": 1, "b": 2}"#, None).unwrap());
+}
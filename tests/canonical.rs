@@ -0,0 +1,107 @@
+use synson::{json, parse_json, serialize_canonical, JsonValue};
+
+fn canonical(input: &str) -> String {
+    serialize_canonical(&parse_json(input, None).unwrap()).unwrap()
+}
+
+#[test]
+fn should_match_the_rfc_example() {
+    // RFC 8785, section 3.2.2.
+    let input = r#"{
+        "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+        "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+        "literals": [null, true, false]
+    }"#;
+
+    assert_eq!(
+        canonical(input),
+        r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+    );
+}
+
+#[test]
+fn should_sort_keys_by_utf16_code_units() {
+    // RFC 8785, section 3.2.3.
+    let input = r#"{
+        "\u20ac": "Euro Sign",
+        "\r": "Carriage Return",
+        "\ufb33": "Hebrew Letter Dalet With Dagesh",
+        "1": "One",
+        "\ud83d\ude00": "Emoji: Grinning Face",
+        "\u0080": "Control",
+        "\u00f6": "Latin Small Letter O With Diaeresis"
+    }"#;
+
+    let output = canonical(input);
+    assert_eq!(
+        output,
+        "{\"\\r\":\"Carriage Return\",\"1\":\"One\",\"\u{80}\":\"Control\",\
+         \"ö\":\"Latin Small Letter O With Diaeresis\",\"€\":\"Euro Sign\",\
+         \"😀\":\"Emoji: Grinning Face\",\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+    );
+}
+
+#[test]
+fn should_format_numbers_like_ecmascript() {
+    // RFC 8785, appendix B.
+    let vectors: [(u64, &str); 24] = [
+        (0x0000000000000000, "0"),
+        (0x8000000000000000, "0"),
+        (0x0000000000000001, "5e-324"),
+        (0x8000000000000001, "-5e-324"),
+        (0x7fefffffffffffff, "1.7976931348623157e+308"),
+        (0xffefffffffffffff, "-1.7976931348623157e+308"),
+        (0x4340000000000000, "9007199254740992"),
+        (0xc340000000000000, "-9007199254740992"),
+        (0x4430000000000000, "295147905179352830000"),
+        (0x44b52d02c7e14af5, "9.999999999999997e+22"),
+        (0x44b52d02c7e14af6, "1e+23"),
+        (0x44b52d02c7e14af7, "1.0000000000000001e+23"),
+        (0x444b1ae4d6e2ef4e, "999999999999999700000"),
+        (0x444b1ae4d6e2ef4f, "999999999999999900000"),
+        (0x444b1ae4d6e2ef50, "1e+21"),
+        (0x3eb0c6f7a0b5ed8c, "9.999999999999997e-7"),
+        (0x3eb0c6f7a0b5ed8d, "0.000001"),
+        (0x41b3de4355555553, "333333333.3333332"),
+        (0x41b3de4355555554, "333333333.33333325"),
+        (0x41b3de4355555555, "333333333.3333333"),
+        (0x41b3de4355555556, "333333333.3333334"),
+        (0x41b3de4355555557, "333333333.33333343"),
+        (0xbecbf647612f3696, "-0.0000033333333333333333"),
+        (0x43143ff3c1cb0959, "1424953923781206.2"),
+    ];
+
+    for (bits, expected) in vectors {
+        let value = JsonValue::Number(f64::from_bits(bits));
+        assert_eq!(
+            serialize_canonical(&value).unwrap(),
+            expected,
+            "{bits:#018x}"
+        );
+    }
+}
+
+#[test]
+fn should_reject_non_finite_numbers() {
+    let error = |n: f64| {
+        serialize_canonical(&json!([1, {"a": n}]))
+            .unwrap_err()
+            .message
+    };
+
+    assert_eq!(error(f64::NAN), "NaN has no JSON representation");
+    assert_eq!(error(f64::INFINITY), "Infinity has no JSON representation");
+    assert_eq!(
+        error(f64::NEG_INFINITY),
+        "-Infinity has no JSON representation"
+    );
+}
+
+#[test]
+fn should_be_stable_across_equal_values() {
+    let a = parse_json(r#"{"b": 1.0, "a": [1e2, "x"]}"#, None).unwrap();
+    let b = parse_json(r#"{ "a" : [ 100, "\u0078" ], "b" : 1 }"#, None).unwrap();
+
+    assert_eq!(serialize_canonical(&a), serialize_canonical(&b));
+    assert_eq!(serialize_canonical(&a).unwrap(), r#"{"a":[100,"x"],"b":1}"#);
+}
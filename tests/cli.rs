@@ -0,0 +1,267 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+use std::{env, fs};
+
+/// Runs the `synson` binary with `args`, feeding `stdin` to it.
+fn synson(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_synson"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Commands that fail early exit without reading their input.
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+/// Writes `contents` to a file unique to the calling test.
+fn file(name: &str, contents: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("synson-cli-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn should_validate_documents() {
+    let good = file("validate-good.json", "{\"a\": [1, 2]}\n");
+    let bad = file("validate-bad.json", "{\n  \"a\": [1,\n  \"b\": tru\n}\n");
+
+    let output = synson(&["validate", good.to_str().unwrap()], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stderr(&output), "");
+
+    let output = synson(
+        &["validate", good.to_str().unwrap(), bad.to_str().unwrap()],
+        "",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        format!(
+            "{}:3:6: Expected ',' or ']' after array element\n  |\n3 |   \"b\": tru\n  |      ^\n",
+            bad.display()
+        )
+    );
+
+    let output = synson(&["validate"], "[1,]");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).starts_with("<stdin>:1:4: Trailing comma not allowed before ']'"));
+}
+
+#[test]
+fn should_format_documents() {
+    let output = synson(&["fmt"], r#"{"b": [1, {"c": null}], "a": "x"}"#);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "{\n  \"a\": \"x\",\n  \"b\": [\n    1,\n    {\n      \"c\": null\n    }\n  ]\n}\n"
+    );
+
+    let output = synson(&["fmt", "--compact"], "{ \"b\": 1.50, \"a\": [ ] }");
+    assert_eq!(stdout(&output), "{\"a\":[],\"b\":1.5}\n");
+
+    let output = synson(&["fmt", "--indent", "4"], "[true]");
+    assert_eq!(stdout(&output), "[\n    true\n]\n");
+}
+
+#[test]
+fn should_check_and_rewrite_files_in_place() {
+    let path = file("fmt.json", "{\"b\":1,\"a\":2}");
+    let name = path.to_str().unwrap();
+
+    let output = synson(&["fmt", "--check", name], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), format!("{name}: not formatted\n"));
+
+    let output = synson(&["fmt", "--write", name], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "{\n  \"a\": 2,\n  \"b\": 1\n}\n"
+    );
+
+    assert_eq!(synson(&["fmt", "--check", name], "").status.code(), Some(0));
+    assert_eq!(synson(&["fmt", "--write"], "{}").status.code(), Some(2));
+}
+
+#[test]
+fn should_refuse_to_rewrite_numbers_that_would_change() {
+    for (contents, column) in [
+        ("{\"a\":[1e400]}", 7),
+        ("[\"9\", 12345678901234567890]", 7),
+        ("[1e-400]", 2),
+        ("[0.30000000000000001]", 2),
+    ] {
+        let path = file("lossy.json", contents);
+        let name = path.to_str().unwrap();
+
+        let output = synson(&["fmt", "--write", name], "");
+        assert_eq!(output.status.code(), Some(1), "{contents}");
+        assert!(stderr(&output).starts_with(&format!(
+            "{name}:1:{column}: Number cannot be written back without changing its value"
+        )));
+        assert_eq!(fs::read_to_string(&path).unwrap(), contents);
+
+        let output = synson(&["patch", "--write", "-", name], "[]");
+        assert_eq!(output.status.code(), Some(1), "{contents}");
+        assert_eq!(fs::read_to_string(&path).unwrap(), contents);
+    }
+
+    let output = synson(
+        &["fmt", "--compact"],
+        "[1.50, 1E2, -0.0, 9007199254740992, 5e-324]",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "[1.5,100,-0,9007199254740992,5e-324]\n");
+    assert_eq!(synson(&["fmt"], "[2e308]").status.code(), Some(1));
+    let path = file("lossy-read-only.json", "[2e308]");
+    let output = synson(&["patch", "-", path.to_str().unwrap()], "[]");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn should_extract_values_by_pointer() {
+    let doc = r#"{"users": [{"name": "Ada", "tags": ["x"]}]}"#;
+
+    let output = synson(&["get", "/users/0/name"], doc);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "\"Ada\"\n");
+    assert_eq!(
+        stdout(&synson(&["get", "--raw", "/users/0/name"], doc)),
+        "Ada\n"
+    );
+    assert_eq!(
+        stdout(&synson(&["get", "--compact", "/users/0"], doc)),
+        "{\"name\":\"Ada\",\"tags\":[\"x\"]}\n"
+    );
+
+    let output = synson(&["get", "/users/1"], doc);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "<stdin>: Index 1 out of bounds at '/users/1' (array length is 1)\n"
+    );
+
+    assert_eq!(synson(&["get", "users"], doc).status.code(), Some(2));
+}
+
+#[test]
+fn should_query_values_by_path() {
+    let doc = r#"{"books": [{"title": "A", "price": 8}, {"title": "B", "price": 22}]}"#;
+
+    let output = synson(&["query", "--raw", "$.books[*].title"], doc);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "A\nB\n");
+    assert_eq!(
+        stdout(&synson(&["query", "--paths", "$..[?@.price < 10]"], doc)),
+        "$['books'][0]\n"
+    );
+
+    let output = synson(&["query", "$.magazines"], doc);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+
+    let output = synson(&["query", "$.books["], doc);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("<path>:1:9: "));
+}
+
+#[test]
+fn should_diff_documents() {
+    let old = file("diff-old.json", r#"{"name": "api", "port": 80}"#);
+    let new = file("diff-new.json", r#"{"port": 8080, "name": "api"}"#);
+    let (old, new) = (old.to_str().unwrap(), new.to_str().unwrap());
+
+    let output = synson(&["diff", old, new], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "--- old\n+++ new\n@@ /port @@\n-80\n+8080\n"
+    );
+
+    let output = synson(
+        &["diff", "--patch", old, "-"],
+        r#"{"name": "web", "port": 80}"#,
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "[\n  {\n    \"op\": \"replace\",\n    \"path\": \"/name\",\n    \"value\": \"web\"\n  }\n]\n"
+    );
+
+    let output = synson(&["diff", old, "-"], r#"{"port": 80, "name": "api"}"#);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn should_apply_patches() {
+    let patch = file(
+        "patch.json",
+        r#"[{"op": "add", "path": "/tags/-", "value": "new"}, {"op": "remove", "path": "/old"}]"#,
+    );
+    let patch = patch.to_str().unwrap();
+
+    let output = synson(&["patch", "--compact", patch], r#"{"tags": [], "old": 1}"#);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "{\"tags\":[\"new\"]}\n");
+
+    let output = synson(&["patch", patch], r#"{"tags": []}"#);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "<stdin>: Patch operation #1 failed: Key 'old' not found at '/old'\n"
+    );
+
+    let merge = file("merge.json", r#"{"a": null, "b": {"c": 1}}"#);
+    let target = file("target.json", r#"{"a": 1, "b": {"d": 2}}"#);
+    let output = synson(
+        &[
+            "patch",
+            "--merge",
+            "--write",
+            merge.to_str().unwrap(),
+            target.to_str().unwrap(),
+        ],
+        "",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        fs::read_to_string(&target).unwrap(),
+        "{\n  \"b\": {\n    \"c\": 1,\n    \"d\": 2\n  }\n}\n"
+    );
+}
+
+#[test]
+fn should_reject_bad_usage() {
+    for args in [
+        &[][..],
+        &["frobnicate"],
+        &["fmt", "--pretty"],
+        &["fmt", "--indent"],
+        &["get"],
+        &["diff", "-", "-"],
+    ] {
+        let output = synson(args, "{}");
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(stderr(&output).contains("usage: synson"), "{args:?}");
+    }
+
+    let output = synson(&["validate", "/nonexistent/synson.json"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("/nonexistent/synson.json: "));
+}
@@ -0,0 +1,200 @@
+use synson::model::{CodegenDerive, JsonCodegenOptions};
+use synson::{generate_rust, infer_shape, json, JsonShape};
+
+fn serde(name: &str) -> JsonCodegenOptions {
+    JsonCodegenOptions::new(name, CodegenDerive::Serde)
+}
+
+#[test]
+fn should_generate_structs_with_renames_and_options() {
+    let samples = [
+        json!({"orderId": 1, "first name": "Ada", "type": "a", "self": true, "lines": [{"sku": "x"}]}),
+        json!({"orderId": 2, "first name": null, "type": "b", "lines": []}),
+    ];
+
+    assert_eq!(
+        generate_rust(&infer_shape(&samples), &serde("order")),
+        r#"use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    #[serde(rename = "first name")]
+    pub first_name: Option<String>,
+    pub lines: Vec<Line>,
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+    #[serde(rename = "self", default, skip_serializing_if = "Option::is_none")]
+    pub self_: Option<bool>,
+    pub r#type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Line {
+    pub sku: String,
+}
+"#
+    );
+}
+
+#[test]
+fn should_generate_untagged_enums_for_unions() {
+    let shape = infer_shape(&[
+        json!({"value": 1}),
+        json!({"value": "one"}),
+        json!({"value": [true]}),
+    ]);
+
+    assert_eq!(
+        generate_rust(&shape, &JsonCodegenOptions::default()),
+        r#"use synson::{FromJson, ToJson};
+
+#[derive(Debug, Clone, PartialEq, FromJson, ToJson)]
+pub struct Root {
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, FromJson, ToJson)]
+#[json(untagged)]
+pub enum Value {
+    Integer(i64),
+    String(String),
+    Array(Vec<bool>),
+}
+"#
+    );
+}
+
+#[test]
+fn should_alias_non_object_roots() {
+    let shape = infer_shape(&[json!([{"HTTPStatus": 200, "data": []}])]);
+
+    assert_eq!(
+        generate_rust(&shape, &JsonCodegenOptions::default()),
+        r#"use synson::{FromJson, ToJson};
+
+pub type Root = Vec<RootItem>;
+
+#[derive(Debug, Clone, PartialEq, FromJson, ToJson)]
+pub struct RootItem {
+    #[json(rename = "HTTPStatus")]
+    pub http_status: i64,
+    pub data: Vec<synson::JsonValue>,
+}
+"#
+    );
+}
+
+#[test]
+fn should_keep_type_and_field_names_unique() {
+    let shape = infer_shape(&[json!({
+        "a": {"item": {"x": 1}},
+        "b": {"item": {"y": 1}},
+        "user_id": 1,
+        "userId": 2,
+        "string": {"z": 1}
+    })]);
+
+    let code = generate_rust(&shape, &JsonCodegenOptions::default());
+    assert!(code.contains("pub struct Item {\n    pub x: i64,\n}"));
+    assert!(code.contains("pub struct Item2 {\n    pub y: i64,\n}"));
+    assert!(code.contains("pub string: String2,"));
+    assert!(code.contains("    #[json(rename = \"userId\")]\n    pub user_id: i64,"));
+    assert!(code.contains("    #[json(rename = \"user_id\")]\n    pub user_id_2: i64,"));
+}
+
+#[test]
+fn should_generate_from_schemas() {
+    let schema = json!({
+        "$defs": {
+            "point": {
+                "type": "object",
+                "properties": {"x": {"type": "number"}, "y": {"type": "number"}},
+                "required": ["x", "y"]
+            }
+        },
+        "type": "object",
+        "properties": {
+            "path": {"type": "array", "items": {"$ref": "#/$defs/point"}},
+            "kind": {"enum": ["open", "closed"]},
+            "label": {"type": ["string", "null"]}
+        },
+        "required": ["path", "kind", "label"]
+    });
+
+    let shape = JsonShape::from_schema(&schema).unwrap();
+    assert_eq!(
+        shape.to_string(),
+        "{kind: string, label: string | null, path: [{x: number, y: number}]}"
+    );
+    assert_eq!(
+        generate_rust(&shape, &serde("Shape")),
+        r#"use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shape {
+    pub kind: String,
+    pub label: Option<String>,
+    pub path: Vec<PathItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PathItem {
+    pub x: f64,
+    pub y: f64,
+}
+"#
+    );
+}
+
+#[test]
+fn should_intersect_all_of_and_references_with_siblings() {
+    let schema = json!({
+        "$defs": {
+            "base": {
+                "type": "object",
+                "properties": {"id": {"type": "integer"}, "note": {"type": "string"}},
+                "required": ["id"]
+            }
+        },
+        "type": "object",
+        "properties": {
+            "all": {
+                "allOf": [
+                    {"$ref": "#/$defs/base"},
+                    {
+                        "properties": {"name": {"type": "string"}, "id": {"type": "number"}},
+                        "required": ["name"]
+                    },
+                    {"required": ["note"]}
+                ]
+            },
+            "sibling": {
+                "$ref": "#/$defs/base",
+                "properties": {"extra": {"type": ["boolean", "null"]}},
+                "required": ["extra"]
+            },
+            "either": {"anyOf": [{"$ref": "#/$defs/base"}, {"type": "string"}]}
+        },
+        "required": ["all", "sibling", "either"]
+    });
+
+    let shape = JsonShape::from_schema(&schema).unwrap();
+    assert_eq!(
+        shape.to_string(),
+        "{all: {id: integer, name: string, note: string}, \
+         either: string | {id: integer, note?: string}, \
+         sibling: {extra: boolean | null, id: integer, note?: string}}"
+    );
+
+    let shape = JsonShape::from_schema(
+        &json!({"allOf": [{"type": "number"}, {"type": ["integer", "null"]}]}),
+    );
+    assert_eq!(shape.unwrap().to_string(), "integer");
+}
+
+#[test]
+fn should_reject_invalid_schemas() {
+    let err = JsonShape::from_schema(&json!({"properties": {"a": 1}})).unwrap_err();
+
+    assert_eq!(err.path.to_string(), "/properties/a");
+}
@@ -0,0 +1,113 @@
+use std::collections::{BTreeMap, HashMap};
+
+use synson::convert::{FromJson, ToJson};
+use synson::{json, parse_json, JsonValue};
+
+#[test]
+fn should_convert_scalars() {
+    assert!(bool::from_json(&json!(true)).unwrap());
+    assert_eq!(i32::from_json(&json!(-42)).unwrap(), -42);
+    assert_eq!(u64::from_json(&json!(9007199254740992.0)).unwrap(), 1 << 53);
+    assert_eq!(f64::from_json(&json!(1.5)).unwrap(), 1.5);
+    assert_eq!(f32::from_json(&json!(0.25)).unwrap(), 0.25);
+    assert_eq!(String::from_json(&json!("hi")).unwrap(), "hi");
+    assert_eq!(char::from_json(&json!("é")).unwrap(), 'é');
+    assert_eq!(<()>::from_json(&json!(null)).unwrap(), ());
+    assert_eq!(JsonValue::from_json(&json!([1])).unwrap(), json!([1]));
+}
+
+#[test]
+fn should_check_integer_ranges() {
+    assert_eq!(u8::from_json(&json!(255)).unwrap(), 255);
+    assert_eq!(
+        u8::from_json(&json!(256)).unwrap_err().message,
+        "number 256 is out of range for u8"
+    );
+    assert_eq!(
+        u32::from_json(&json!(-1)).unwrap_err().message,
+        "number -1 is out of range for u32"
+    );
+    assert_eq!(i8::from_json(&json!(-128)).unwrap(), -128);
+    assert!(i8::from_json(&json!(-129)).is_err());
+    assert!(i64::from_json(&json!(9223372036854775808.0)).is_err());
+    assert_eq!(
+        i32::from_json(&json!(1.5)).unwrap_err().message,
+        "expected an integer, found 1.5"
+    );
+    assert_eq!(
+        i32::from_json(&json!("1")).unwrap_err().message,
+        "expected an integer, found string"
+    );
+}
+
+#[test]
+fn should_convert_containers() {
+    let doc = json!({"a": [1, 2], "b": []});
+    let map = HashMap::<String, Vec<u8>>::from_json(&doc).unwrap();
+    assert_eq!(map["a"], vec![1, 2]);
+    assert!(map["b"].is_empty());
+
+    let sorted =
+        BTreeMap::<String, Option<bool>>::from_json(&json!({"x": null, "y": true})).unwrap();
+    assert_eq!(
+        sorted.into_iter().collect::<Vec<_>>(),
+        vec![("x".to_string(), None), ("y".to_string(), Some(true))]
+    );
+
+    assert_eq!(
+        <(u8, String, bool)>::from_json(&json!([1, "two", false])).unwrap(),
+        (1, "two".to_string(), false)
+    );
+    assert_eq!(<[i32; 3]>::from_json(&json!([1, 2, 3])).unwrap(), [1, 2, 3]);
+    assert_eq!(
+        <[i32; 3]>::from_json(&json!([1, 2])).unwrap_err().message,
+        "expected an array of length 3, found length 2"
+    );
+    assert_eq!(*Box::<u8>::from_json(&json!(7)).unwrap(), 7);
+}
+
+#[test]
+fn should_report_error_paths() {
+    let doc = parse_json(r#"{"users": [{"age": 1}, {"age": "x"}]}"#, None).unwrap();
+    let err = HashMap::<String, Vec<HashMap<String, u8>>>::from_json(&doc).unwrap_err();
+
+    assert_eq!(err.path.to_string(), "/users/1/age");
+    assert_eq!(
+        err.to_string(),
+        "expected an integer, found string at '/users/1/age'"
+    );
+
+    let err = <(u8, u8)>::from_json(&json!([1, null])).unwrap_err();
+    assert_eq!(err.path.to_string(), "/1");
+}
+
+#[test]
+fn should_convert_to_json() {
+    let mut map = HashMap::new();
+    map.insert("k".to_string(), vec![Some(1u8), None]);
+
+    assert_eq!(map.to_json(), json!({"k": [1, null]}));
+    assert_eq!(("a", 2.5, true).to_json(), json!(["a", 2.5, true]));
+    assert_eq!([1u16, 2].to_json(), json!([1, 2]));
+    assert_eq!("s".to_json(), json!("s"));
+    assert_eq!('c'.to_json(), json!("c"));
+    assert_eq!(().to_json(), json!(null));
+    assert_eq!(Box::new(-3i64).to_json(), json!(-3));
+
+    let mut tree = BTreeMap::new();
+    tree.insert("z", json!({"nested": true}));
+    assert_eq!(tree.to_json(), json!({"z": {"nested": true}}));
+}
+
+#[test]
+fn should_round_trip_std_types() {
+    let original: HashMap<String, (u32, Vec<Option<String>>)> = [
+        ("a".to_string(), (1, vec![Some("x".to_string()), None])),
+        ("b".to_string(), (2, vec![])),
+    ]
+    .into_iter()
+    .collect();
+
+    let value = original.to_json();
+    assert_eq!(HashMap::from_json(&value).unwrap(), original);
+}
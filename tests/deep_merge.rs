@@ -0,0 +1,148 @@
+use synson::{
+    deep_merge, parse_json, ArrayMergeStrategy, JsonMergeOptions, JsonValue, NullMergeStrategy,
+};
+
+fn json(input: &str) -> JsonValue {
+    parse_json(input, None).unwrap()
+}
+
+fn options(arrays: ArrayMergeStrategy, nulls: NullMergeStrategy) -> Option<JsonMergeOptions> {
+    Some(JsonMergeOptions::new(arrays, nulls))
+}
+
+#[test]
+fn should_merge_objects_recursively() {
+    let mut base = json(r#"{"a": {"b": 1, "c": {"d": 2}}, "e": 3}"#);
+    let conflicts = deep_merge(&mut base, &json(r#"{"a": {"c": {"f": 4}}, "g": 5}"#), None);
+
+    assert_eq!(
+        base,
+        json(r#"{"a": {"b": 1, "c": {"d": 2, "f": 4}}, "e": 3, "g": 5}"#)
+    );
+    assert!(conflicts.is_empty());
+}
+
+#[test]
+fn should_layer_configurations_in_order() {
+    let mut config = json(r#"{"log": {"level": "info", "file": "app.log"}, "port": 80}"#);
+    for layer in [r#"{"port": 8080}"#, r#"{"log": {"level": "debug"}}"#] {
+        deep_merge(&mut config, &json(layer), None);
+    }
+
+    assert_eq!(
+        config,
+        json(r#"{"log": {"level": "debug", "file": "app.log"}, "port": 8080}"#)
+    );
+}
+
+#[test]
+fn should_replace_arrays_by_default() {
+    let mut base = json(r#"{"a": [1, 2, 3]}"#);
+    let conflicts = deep_merge(&mut base, &json(r#"{"a": [4]}"#), None);
+
+    assert_eq!(base, json(r#"{"a": [4]}"#));
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].path.to_string(), "/a");
+    assert_eq!(conflicts[0].base, json("[1, 2, 3]"));
+}
+
+#[test]
+fn should_append_arrays() {
+    let mut base = json("[1, 2]");
+    let conflicts = deep_merge(
+        &mut base,
+        &json("[2, 3]"),
+        options(ArrayMergeStrategy::Append, NullMergeStrategy::Set),
+    );
+
+    assert_eq!(base, json("[1, 2, 2, 3]"));
+    assert!(conflicts.is_empty());
+}
+
+#[test]
+fn should_merge_arrays_by_index() {
+    let mut base = json(r#"[{"a": 1}, {"b": 2}]"#);
+    let conflicts = deep_merge(
+        &mut base,
+        &json(r#"[{"c": 3}, {"b": 4}, {"d": 5}]"#),
+        options(ArrayMergeStrategy::MergeByIndex, NullMergeStrategy::Set),
+    );
+
+    assert_eq!(base, json(r#"[{"a": 1, "c": 3}, {"b": 4}, {"d": 5}]"#));
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].path.to_string(), "/1/b");
+}
+
+#[test]
+fn should_merge_arrays_by_key() {
+    let mut base =
+        json(r#"{"services": [{"name": "web", "port": 80}, {"name": "db", "port": 5432}]}"#);
+    let overlay = json(
+        r#"{"services": [{"name": "db", "replicas": 2}, {"name": "cache", "port": 6379}, 7]}"#,
+    );
+    let conflicts = deep_merge(
+        &mut base,
+        &overlay,
+        options(
+            ArrayMergeStrategy::MergeByKey("name".to_string()),
+            NullMergeStrategy::Set,
+        ),
+    );
+
+    assert_eq!(
+        base,
+        json(
+            r#"{"services": [
+                {"name": "web", "port": 80},
+                {"name": "db", "port": 5432, "replicas": 2},
+                {"name": "cache", "port": 6379},
+                7
+            ]}"#
+        )
+    );
+    assert!(conflicts.is_empty());
+}
+
+#[test]
+fn should_delete_keys_for_null_with_delete_strategy() {
+    let mut base = json(r#"{"a": 1, "b": {"c": 2, "d": 3}}"#);
+    let conflicts = deep_merge(
+        &mut base,
+        &json(r#"{"a": null, "b": {"c": null}, "e": {"f": null, "g": 1}, "x": null}"#),
+        options(ArrayMergeStrategy::Replace, NullMergeStrategy::Delete),
+    );
+
+    assert_eq!(base, json(r#"{"b": {"d": 3}, "e": {"g": 1}}"#));
+    assert!(conflicts.is_empty());
+}
+
+#[test]
+fn should_store_null_with_set_strategy() {
+    let mut base = json(r#"{"a": 1, "b": null}"#);
+    let conflicts = deep_merge(&mut base, &json(r#"{"a": null, "b": 2}"#), None);
+
+    assert_eq!(base, json(r#"{"a": null, "b": 2}"#));
+    // Overwriting a value with null is a conflict; filling a null is not.
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].path.to_string(), "/a");
+}
+
+#[test]
+fn should_report_type_conflicts_with_paths() {
+    let mut base = json(r#"{"a": {"b": [1]}, "c": "x", "d": {"e": 1}}"#);
+    let conflicts = deep_merge(
+        &mut base,
+        &json(r#"{"a": {"b": {"k": 1}}, "c": "x", "d": 5}"#),
+        None,
+    );
+
+    let described: Vec<_> = conflicts.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        described,
+        [
+            r#"Value at '/a/b' changed from [1] to {"k":1}"#,
+            r#"Value at '/d' changed from {"e":1} to 5"#
+        ]
+    );
+    assert_eq!(base, json(r#"{"a": {"b": {"k": 1}}, "c": "x", "d": 5}"#));
+}
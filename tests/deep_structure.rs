@@ -1,6 +1,7 @@
 use std::fs;
 use synson::model::JsonParseOptions;
 use synson::parse_json;
+use synson::parser::MAX_DEPTH;
 
 fn load_json_from_file(file_path: &str) -> String {
     fs::read_to_string(file_path).expect("Failed to read JSON file")
@@ -33,3 +34,19 @@ fn should_handle_invalid_json_syntax() {
     let result = parse_json(&json_data, Some(&JsonParseOptions::default()));
     assert!(result.is_err());
 }
+
+#[test]
+fn should_limit_nesting_depth() {
+    let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+    assert!(parse_json(&nested(MAX_DEPTH), None).is_ok());
+    let objects = "{\"a\":".repeat(MAX_DEPTH) + "1" + &"}".repeat(MAX_DEPTH);
+    assert!(parse_json(&objects, None).is_ok());
+
+    let err = parse_json(&nested(MAX_DEPTH + 1), None).unwrap_err();
+    assert_eq!(err.message, "Nesting is deeper than 512 levels");
+    assert_eq!(err.index, MAX_DEPTH);
+
+    let err = parse_json(&"{\"a\":[".repeat(50_000), None).unwrap_err();
+    assert_eq!(err.message, "Nesting is deeper than 512 levels");
+    assert_eq!(err.column, 6 * (MAX_DEPTH / 2) + 1);
+}
@@ -0,0 +1,249 @@
+use synson::convert::{FromJson, ToJson};
+use synson::{json, JsonValue};
+use synson_derive::{FromJson, ToJson};
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+struct User {
+    #[json(rename = "userName")]
+    name: String,
+    age: u8,
+    email: Option<String>,
+    #[json(default)]
+    admin: bool,
+    #[json(default = "default_theme")]
+    theme: String,
+    #[json(skip)]
+    cache: Vec<u8>,
+}
+
+fn default_theme() -> String {
+    "light".to_string()
+}
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+struct Meta {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+struct Record {
+    #[json(flatten)]
+    meta: Meta,
+    label: String,
+}
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+struct Wrapper(u16);
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+struct Pair(String, f64);
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+struct Marker;
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+struct Generic<T> {
+    items: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Line(f64, f64),
+    #[json(rename = "rect")]
+    Rect {
+        w: f64,
+        h: f64,
+    },
+}
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+#[json(tag = "type")]
+enum Event {
+    Ping,
+    Login(Meta),
+    Message { text: String },
+}
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+#[json(tag = "t", content = "c")]
+enum Command {
+    Stop,
+    Move(i32, i32),
+    Say { words: String },
+}
+
+#[derive(Debug, PartialEq, FromJson, ToJson)]
+#[json(untagged)]
+enum Scalar {
+    Flag(bool),
+    Number(f64),
+    Text(String),
+    Point { x: f64, y: f64 },
+}
+
+#[test]
+fn should_derive_named_structs() {
+    let user = User::from_json(&json!({"userName": "ann", "age": 30, "extra": 1})).unwrap();
+    assert_eq!(
+        user,
+        User {
+            name: "ann".to_string(),
+            age: 30,
+            email: None,
+            admin: false,
+            theme: "light".to_string(),
+            cache: vec![],
+        }
+    );
+    assert_eq!(
+        user.to_json(),
+        json!({"userName": "ann", "age": 30, "email": null, "admin": false, "theme": "light"})
+    );
+}
+
+#[test]
+fn should_report_missing_and_invalid_fields() {
+    let err = User::from_json(&json!({"age": 30})).unwrap_err();
+    assert_eq!(err.to_string(), "missing field 'userName'");
+
+    let err = User::from_json(&json!({"userName": "a", "age": 300})).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "number 300 is out of range for u8 at '/age'"
+    );
+
+    let err =
+        Vec::<Generic<u8>>::from_json(&json!([{"items": [1]}, {"items": [true]}])).unwrap_err();
+    assert_eq!(err.path.to_string(), "/1/items/0");
+
+    let err = User::from_json(&json!([])).unwrap_err();
+    assert_eq!(err.to_string(), "expected an object, found array");
+}
+
+#[test]
+fn should_flatten_fields() {
+    let value = json!({"id": 7, "label": "x"});
+    let record = Record::from_json(&value).unwrap();
+    assert_eq!(
+        record,
+        Record {
+            meta: Meta { id: 7 },
+            label: "x".to_string()
+        }
+    );
+    assert_eq!(record.to_json(), value);
+}
+
+#[test]
+fn should_derive_tuple_and_unit_structs() {
+    assert_eq!(Wrapper::from_json(&json!(5)).unwrap(), Wrapper(5));
+    assert_eq!(Wrapper(5).to_json(), json!(5));
+    assert_eq!(
+        Pair::from_json(&json!(["a", 1.5])).unwrap(),
+        Pair("a".to_string(), 1.5)
+    );
+    assert_eq!(Pair("a".to_string(), 1.5).to_json(), json!(["a", 1.5]));
+    assert_eq!(
+        Pair::from_json(&json!(["a", "b"]))
+            .unwrap_err()
+            .path
+            .to_string(),
+        "/1"
+    );
+    assert_eq!(Marker::from_json(&JsonValue::Null).unwrap(), Marker);
+    assert_eq!(Marker.to_json(), JsonValue::Null);
+}
+
+#[test]
+fn should_derive_externally_tagged_enums() {
+    let cases = [
+        (Shape::Empty, json!("Empty")),
+        (Shape::Circle(1.0), json!({"Circle": 1})),
+        (Shape::Line(1.0, 2.0), json!({"Line": [1, 2]})),
+        (
+            Shape::Rect { w: 1.0, h: 2.0 },
+            json!({"rect": {"w": 1, "h": 2}}),
+        ),
+    ];
+    for (shape, value) in cases {
+        assert_eq!(shape.to_json(), value);
+        assert_eq!(Shape::from_json(&value).unwrap(), shape);
+    }
+
+    let err = Shape::from_json(&json!("Square")).unwrap_err();
+    assert_eq!(
+        err.message,
+        "unknown variant 'Square', expected one of 'Empty', 'Circle', 'Line', 'rect'"
+    );
+    let err = Shape::from_json(&json!({"rect": {"w": 1}})).unwrap_err();
+    assert_eq!(err.to_string(), "missing field 'h' at '/rect'");
+}
+
+#[test]
+fn should_derive_internally_tagged_enums() {
+    let cases = [
+        (Event::Ping, json!({"type": "Ping"})),
+        (
+            Event::Login(Meta { id: 3 }),
+            json!({"type": "Login", "id": 3}),
+        ),
+        (
+            Event::Message {
+                text: "hi".to_string(),
+            },
+            json!({"type": "Message", "text": "hi"}),
+        ),
+    ];
+    for (event, value) in cases {
+        assert_eq!(event.to_json(), value);
+        assert_eq!(Event::from_json(&value).unwrap(), event);
+    }
+
+    let err = Event::from_json(&json!({"text": "hi"})).unwrap_err();
+    assert_eq!(err.to_string(), "missing field 'type'");
+    let err = Event::from_json(&json!({"type": "Nope"})).unwrap_err();
+    assert_eq!(err.path.to_string(), "/type");
+}
+
+#[test]
+fn should_derive_adjacently_tagged_enums() {
+    let cases = [
+        (Command::Stop, json!({"t": "Stop"})),
+        (Command::Move(1, -2), json!({"t": "Move", "c": [1, -2]})),
+        (
+            Command::Say {
+                words: "go".to_string(),
+            },
+            json!({"t": "Say", "c": {"words": "go"}}),
+        ),
+    ];
+    for (command, value) in cases {
+        assert_eq!(command.to_json(), value);
+        assert_eq!(Command::from_json(&value).unwrap(), command);
+    }
+
+    let err = Command::from_json(&json!({"t": "Move", "c": [1, 2.5]})).unwrap_err();
+    assert_eq!(err.path.to_string(), "/c/1");
+}
+
+#[test]
+fn should_derive_untagged_enums() {
+    let cases = [
+        (Scalar::Flag(true), json!(true)),
+        (Scalar::Number(2.0), json!(2)),
+        (Scalar::Text("t".to_string()), json!("t")),
+        (Scalar::Point { x: 1.0, y: 2.0 }, json!({"x": 1, "y": 2})),
+    ];
+    for (scalar, value) in cases {
+        assert_eq!(scalar.to_json(), value);
+        assert_eq!(Scalar::from_json(&value).unwrap(), scalar);
+    }
+
+    let err = Scalar::from_json(&json!(null)).unwrap_err();
+    assert_eq!(
+        err.message,
+        "data did not match any variant of untagged enum Scalar"
+    );
+}
@@ -0,0 +1,176 @@
+use synson::{
+    diff_json, parse_json, render_diff, ArrayDiffStrategy, JsonChange, JsonDiffOptions, JsonValue,
+};
+
+fn json(input: &str) -> JsonValue {
+    parse_json(input, None).unwrap()
+}
+
+fn paths(changes: &[JsonChange]) -> Vec<String> {
+    changes.iter().map(|c| c.path().to_string()).collect()
+}
+
+fn with_strategy(strategy: ArrayDiffStrategy) -> Option<JsonDiffOptions> {
+    Some(JsonDiffOptions::new(strategy, 0.0))
+}
+
+#[test]
+fn should_report_no_changes_for_equal_documents() {
+    let doc = json(r#"{"a": [1, {"b": null}], "c": "x"}"#);
+    assert!(diff_json(&doc, &doc.clone(), None).is_empty());
+    assert_eq!(render_diff(&[]), "");
+}
+
+#[test]
+fn should_report_object_member_changes() {
+    let old = json(r#"{"a": 1, "b": {"c": true, "d": "x"}, "e/f": 0}"#);
+    let new = json(r#"{"a": 1, "b": {"c": false}, "e/f": 0, "g": []}"#);
+
+    assert_eq!(
+        diff_json(&old, &new, None),
+        [
+            JsonChange::Modified {
+                path: "/b/c".parse().unwrap(),
+                old: JsonValue::Bool(true),
+                new: JsonValue::Bool(false),
+            },
+            JsonChange::Removed {
+                path: "/b/d".parse().unwrap(),
+                value: JsonValue::String("x".to_string()),
+            },
+            JsonChange::Added {
+                path: "/g".parse().unwrap(),
+                value: JsonValue::Array(vec![]),
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_report_type_changes_as_modifications() {
+    let changes = diff_json(&json(r#"{"a": [1]}"#), &json(r#"{"a": {"0": 1}}"#), None);
+    assert_eq!(paths(&changes), ["/a"]);
+    assert!(matches!(changes[0], JsonChange::Modified { .. }));
+
+    let changes = diff_json(&json("1"), &json(r#""1""#), None);
+    assert_eq!(paths(&changes), [""]);
+}
+
+#[test]
+fn should_diff_arrays_by_index() {
+    let old = json("[1, 2, 3]");
+    let new = json("[0, 1, 2, 3]");
+
+    let changes = diff_json(&old, &new, with_strategy(ArrayDiffStrategy::Index));
+    assert_eq!(paths(&changes), ["/0", "/1", "/2", "/3"]);
+    assert!(matches!(changes[3], JsonChange::Added { .. }));
+
+    let changes = diff_json(&new, &old, with_strategy(ArrayDiffStrategy::Index));
+    assert!(matches!(changes[3], JsonChange::Removed { .. }));
+}
+
+#[test]
+fn should_diff_arrays_by_lcs() {
+    let old = json(r#"["a", "b", "c", "d"]"#);
+    let new = json(r#"["x", "a", "c", "d", "e"]"#);
+
+    let changes = diff_json(&old, &new, None);
+    assert_eq!(
+        changes,
+        [
+            JsonChange::Added {
+                path: "/0".parse().unwrap(),
+                value: JsonValue::String("x".to_string()),
+            },
+            JsonChange::Removed {
+                path: "/1".parse().unwrap(),
+                value: JsonValue::String("b".to_string()),
+            },
+            JsonChange::Added {
+                path: "/4".parse().unwrap(),
+                value: JsonValue::String("e".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_recurse_into_changed_array_elements_with_lcs() {
+    let old = json(r#"[{"n": 1}, {"n": 2}, 3]"#);
+    let new = json(r#"[{"n": 1}, {"n": 5}, 3]"#);
+
+    assert_eq!(paths(&diff_json(&old, &new, None)), ["/1/n"]);
+}
+
+#[test]
+fn should_diff_arrays_keyed_by_id() {
+    let old = json(r#"[{"id": "a", "v": 1}, {"id": "b", "v": 2}, {"id": "c", "v": 3}]"#);
+    let new = json(r#"[{"id": "c", "v": 3}, {"id": "a", "v": 9}, {"id": "d", "v": 4}]"#);
+    let keyed = || with_strategy(ArrayDiffStrategy::Keyed("id".to_string()));
+
+    let changes = diff_json(&old, &new, keyed());
+    assert_eq!(paths(&changes), ["/0/v", "/1", "/2"]);
+    assert!(matches!(changes[1], JsonChange::Removed { .. }));
+    assert!(matches!(changes[2], JsonChange::Added { .. }));
+
+    // Changes inside a matched element use its old index, even if it moved.
+    let moved = json(r#"[{"id": "b", "v": 2, "w": 0}, {"id": "a"}]"#);
+    let changes = diff_json(
+        &json(r#"[{"id": "a"}, {"id": "b", "v": 1}]"#),
+        &moved,
+        keyed(),
+    );
+    assert_eq!(paths(&changes), ["/1/v", "/1/w"]);
+    assert!(matches!(changes[1], JsonChange::Added { .. }));
+
+    // Reordering alone is not a change.
+    let reordered = json(r#"[{"id": "c", "v": 3}, {"id": "b", "v": 2}, {"id": "a", "v": 1}]"#);
+    assert!(diff_json(&old, &reordered, keyed()).is_empty());
+}
+
+#[test]
+fn should_fall_back_to_lcs_when_keys_are_missing_or_duplicated() {
+    let keyed = || with_strategy(ArrayDiffStrategy::Keyed("id".to_string()));
+
+    let changes = diff_json(
+        &json(r#"[{"id": 1}, 2]"#),
+        &json(r#"[0, {"id": 1}, 2]"#),
+        keyed(),
+    );
+    assert_eq!(paths(&changes), ["/0"]);
+
+    let old = json(r#"[{"id": 1, "v": 1}, {"id": 1, "v": 2}]"#);
+    let new = json(r#"[{"id": 1, "v": 2}]"#);
+    assert_eq!(paths(&diff_json(&old, &new, keyed())), ["/0"]);
+}
+
+#[test]
+fn should_ignore_numeric_differences_within_tolerance() {
+    let old = json(r#"{"pi": 3.14159, "items": [0.1, 0.2], "n": 10}"#);
+    let new = json(r#"{"pi": 3.1416, "items": [0.10000001, 0.2], "n": 11}"#);
+    let options = Some(JsonDiffOptions::new(ArrayDiffStrategy::Lcs, 1e-3));
+
+    assert_eq!(paths(&diff_json(&old, &new, options)), ["/n"]);
+    assert_eq!(
+        paths(&diff_json(&old, &new, None)),
+        ["/items/0", "/n", "/pi"]
+    );
+}
+
+#[test]
+fn should_render_unified_style_text() {
+    let old = json(r#"{"tags": ["a", "b"], "count": 1}"#);
+    let new = json(r#"{"tags": ["a", "c"], "count": 2, "extra": {"k": null}}"#);
+
+    assert_eq!(
+        render_diff(&diff_json(&old, &new, None)),
+        "--- old\n+++ new\n\
+         @@ /count @@\n-1\n+2\n\
+         @@ /extra @@\n+{\"k\":null}\n\
+         @@ /tags/1 @@\n-\"b\"\n+\"c\"\n"
+    );
+    assert_eq!(
+        render_diff(&diff_json(&json("true"), &json("false"), None)),
+        "--- old\n+++ new\n@@ (root) @@\n-true\n+false\n"
+    );
+}
@@ -45,3 +45,28 @@ fn should_report_leading_zero_error() {
     let err = parse_json("01", None).unwrap_err();
     assert_eq!(err.message, "Leading zeros are not allowed in numbers");
 }
+
+#[test]
+fn should_point_at_the_offending_character_in_nested_values() {
+    let position = |input: &str| {
+        let err = parse_json(input, None).unwrap_err();
+        (err.message, err.line, err.column)
+    };
+
+    assert_eq!(
+        position("{\"a\": [1, tru]}"),
+        ("Expected 'boolean' literal".to_string(), 1, 11)
+    );
+    assert_eq!(
+        position("[\n  {\"é\": 1,}\n]"),
+        ("Trailing comma not allowed before '}'".to_string(), 2, 11)
+    );
+    assert_eq!(
+        position("{\"a\": 1 \"b\": 2}"),
+        ("Expected ',' or '}' after object entry".to_string(), 1, 9)
+    );
+    assert_eq!(
+        position("[1, ?]"),
+        ("Expected 'value' literal".to_string(), 1, 5)
+    );
+}
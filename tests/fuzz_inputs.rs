@@ -47,3 +47,24 @@ fn should_handle_fuzz_inputs() {
         }))
     );
 }
+
+#[test]
+fn should_reject_inputs_found_by_fuzzing_without_panicking() {
+    for input in [
+        "\"é",
+        "[\"\\u",
+        "[\"\\ud800\\u",
+        "{\"a\":1,",
+        "-",
+        "1e",
+        "\u{a0}1",
+        "[1,\u{1f600}]",
+        &"[".repeat(100_000),
+        &"{\"a\":".repeat(100_000),
+    ] {
+        assert!(parse_json(input, None).is_err(), "{input:?}");
+        for offset in 0..input.len().min(8) {
+            let _ = synson::parse_json_at(input, offset);
+        }
+    }
+}
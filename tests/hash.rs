@@ -0,0 +1,138 @@
+use std::collections::{BTreeSet, HashMap};
+
+use synson::hash::{stable_bytes, write_stable_bytes};
+use synson::{json, parse_json, stable_hash, JsonKey, JsonValue};
+
+#[test]
+fn should_ignore_member_order() {
+    let a = parse_json(r#"{"a": 1, "b": {"x": [1, 2], "y": null}}"#, None).unwrap();
+    let b = parse_json(r#"{"b": {"y": null, "x": [1, 2]}, "a": 1}"#, None).unwrap();
+
+    assert_eq!(stable_hash(&a), stable_hash(&b));
+    assert_eq!(stable_bytes(&a), stable_bytes(&b));
+    assert_eq!(JsonKey::new(a), JsonKey::new(b));
+}
+
+#[test]
+fn should_normalize_zeros_nans_and_integer_valued_floats() {
+    let same = [
+        (json!(-0.0), json!(0)),
+        (json!(f64::NAN), JsonValue::Number(-f64::NAN)),
+        (json!(1), json!(1.0)),
+        (json!([1e2]), json!([100])),
+    ];
+
+    for (a, b) in same {
+        assert_eq!(stable_hash(&a), stable_hash(&b), "{a} vs {b}");
+        assert_eq!(JsonKey::new(a), JsonKey::new(b));
+    }
+    assert_eq!(JsonKey::new(json!(-0.0)).get(), &json!(0));
+}
+
+#[test]
+fn should_tell_different_values_apart() {
+    let values = [
+        json!(null),
+        json!(false),
+        json!(true),
+        json!(0),
+        json!(1),
+        json!(""),
+        json!("0"),
+        json!([]),
+        json!([null]),
+        json!([[]]),
+        json!(["a", "b"]),
+        json!(["ab"]),
+        json!({}),
+        json!({"a": null}),
+        json!({"a": []}),
+        json!({"": "a"}),
+    ];
+
+    let hashes: BTreeSet<u64> = values.iter().map(stable_hash).collect();
+    assert_eq!(hashes.len(), values.len());
+    let keys: BTreeSet<JsonKey> = values.iter().cloned().map(JsonKey::new).collect();
+    assert_eq!(keys.len(), values.len());
+}
+
+#[test]
+fn should_order_keys_by_type_then_content() {
+    let mut keys: Vec<JsonKey> = [
+        json!({"b": 1}),
+        json!({"a": 2}),
+        json!([1, 2]),
+        json!([1]),
+        json!("b"),
+        json!("a"),
+        json!(f64::NAN),
+        json!(10),
+        json!(-1),
+        json!(true),
+        json!(false),
+        json!(null),
+    ]
+    .into_iter()
+    .map(JsonKey::new)
+    .collect();
+    keys.sort();
+
+    assert!(keys[5].get().as_f64().unwrap().is_nan());
+
+    let keys: Vec<String> = keys.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        keys,
+        [
+            "null",
+            "false",
+            "true",
+            "-1",
+            "10",
+            "null",
+            "\"a\"",
+            "\"b\"",
+            "[1]",
+            "[1,2]",
+            "{\"a\":2}",
+            "{\"b\":1}",
+        ]
+    );
+}
+
+#[test]
+fn should_work_as_a_map_key() {
+    let mut counts: HashMap<JsonKey, usize> = HashMap::new();
+    for line in [
+        r#"{"id": 1, "tags": []}"#,
+        r#"{"tags": [], "id": 1.0}"#,
+        r#"{"id": 2}"#,
+    ] {
+        *counts
+            .entry(parse_json(line, None).unwrap().into())
+            .or_default() += 1;
+    }
+
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&JsonKey::new(json!({"id": 1, "tags": []}))], 2);
+}
+
+#[test]
+fn should_keep_the_encoding_and_hash_stable() {
+    let value = json!({"b": -0.0, "a": "é"});
+    let mut written = Vec::new();
+    write_stable_bytes(&value, &mut written).unwrap();
+
+    assert_eq!(written, stable_bytes(&value));
+    assert_eq!(
+        written,
+        [
+            6, 0, 0, 0, 0, 0, 0, 0, 2, // object with 2 members
+            0, 0, 0, 0, 0, 0, 0, 1, b'a', // key "a"
+            4, 0, 0, 0, 0, 0, 0, 0, 2, 0xc3, 0xa9, // string "é"
+            0, 0, 0, 0, 0, 0, 0, 1, b'b', // key "b"
+            3, 0, 0, 0, 0, 0, 0, 0, 0, // number 0
+        ]
+    );
+    assert_eq!(stable_hash(&json!(null)), 0xaf63bd4c8601b7df);
+    assert_eq!(stable_hash(&value), stable_hash(&json!({"a": "é", "b": 0})));
+}
@@ -0,0 +1,139 @@
+use synson::{
+    infer_schema, infer_shape, json, serialize_json, JsonSchema, JsonShape, ShapeType, StringFormat,
+};
+
+#[test]
+fn should_describe_primitives() {
+    let shape = |value| JsonShape::of(&value).to_string();
+
+    assert_eq!(shape(json!(null)), "null");
+    assert_eq!(shape(json!(true)), "boolean");
+    assert_eq!(shape(json!(3)), "integer");
+    assert_eq!(shape(json!(3.0)), "integer");
+    assert_eq!(shape(json!(3.25)), "number");
+    assert_eq!(shape(json!("text")), "string");
+    assert_eq!(shape(json!([])), "[unknown]");
+    assert_eq!(
+        shape(json!({"first name": 1})),
+        r#"{"first name": integer}"#
+    );
+}
+
+#[test]
+fn should_detect_string_formats() {
+    let format = |s: &str| match JsonShape::of(&json!(s)).types.as_slice() {
+        [ShapeType::String(format)] => *format,
+        other => panic!("unexpected shape {other:?}"),
+    };
+
+    assert_eq!(format("2024-05-01T12:30:00Z"), Some(StringFormat::DateTime));
+    assert_eq!(
+        format("2024-05-01t12:30:00.125+02:00"),
+        Some(StringFormat::DateTime)
+    );
+    assert_eq!(format("2024-05-01"), Some(StringFormat::Date));
+    assert_eq!(
+        format("123E4567-e89b-12d3-a456-426614174000"),
+        Some(StringFormat::Uuid)
+    );
+    assert_eq!(format("ada@example.com"), Some(StringFormat::Email));
+
+    assert_eq!(format("2024-13-01"), None);
+    assert_eq!(format("2024-05-01T25:00:00Z"), None);
+    assert_eq!(format("2024-05-01T12:30:00"), None);
+    assert_eq!(format("2024-05-01T12:30:00ÀÀÀ"), None);
+    assert_eq!(format("123e4567e89b12d3a456426614174000"), None);
+    assert_eq!(format("ada@localhost"), None);
+}
+
+#[test]
+fn should_detect_optional_and_nullable_fields() {
+    let samples = [
+        json!({"id": 1, "name": "Ada", "deleted": null}),
+        json!({"id": 2, "name": "Bob", "deleted": "2024-05-01T00:00:00Z", "admin": true}),
+    ];
+
+    assert_eq!(
+        infer_shape(&samples).to_string(),
+        "{admin?: boolean, deleted: date-time | null, id: integer, name: string}"
+    );
+}
+
+#[test]
+fn should_unify_numbers_and_formats() {
+    let samples = [
+        json!({"n": 1, "s": "2024-05-01", "d": "2024-05-01"}),
+        json!({"n": 2.5, "s": "soon", "d": "2024-06-01"}),
+    ];
+
+    assert_eq!(
+        infer_shape(&samples).to_string(),
+        "{d: date, n: number, s: string}"
+    );
+}
+
+#[test]
+fn should_keep_unions_of_different_kinds() {
+    let samples = [
+        json!([1, "a"]),
+        json!({"x": [1]}),
+        json!({"x": [{"y": 1}], "z": false}),
+        json!(null),
+    ];
+
+    assert_eq!(
+        infer_shape(&samples).to_string(),
+        "[integer | string] | {x: [integer | {y: integer}], z?: boolean} | null"
+    );
+}
+
+#[test]
+fn should_return_an_unknown_shape_without_samples() {
+    let shape = infer_shape(&[]);
+
+    assert_eq!(shape, JsonShape::default());
+    assert_eq!(shape.to_string(), "unknown");
+    assert_eq!(serialize_json(&shape.to_schema()), "{}");
+}
+
+#[test]
+fn should_infer_a_schema() {
+    let samples = [
+        json!({"id": 1, "tags": ["a"], "owner": {"email": "ada@example.com"}}),
+        json!({"id": 2, "tags": [], "owner": null, "score": 0.5}),
+    ];
+
+    assert_eq!(
+        infer_schema(&samples),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "owner": {
+                    "type": ["object", "null"],
+                    "properties": {"email": {"type": "string", "format": "email"}},
+                    "required": ["email"]
+                },
+                "score": {"type": "number"},
+                "tags": {"type": "array", "items": {"type": "string"}}
+            },
+            "required": ["id", "owner", "tags"]
+        })
+    );
+}
+
+#[test]
+fn should_infer_schemas_the_samples_satisfy() {
+    let samples = [
+        json!({"a": [1, "x", null], "b": {"c": 1}}),
+        json!({"a": [], "b": "text"}),
+        json!([{"a": 1.5}, true]),
+    ];
+
+    let schema = JsonSchema::compile(&infer_schema(&samples)).unwrap();
+    for sample in &samples {
+        assert_eq!(schema.validate(sample), []);
+    }
+    assert!(!schema.is_valid(&json!({"a": 1})));
+}
@@ -1,5 +1,5 @@
 use synson::model::JsonParseOptions;
-use synson::{parse_json, JsonValue};
+use synson::{parse_json, parse_json_at, parse_json_prefix, JsonValue};
 
 #[test]
 fn should_parse_valid_json_values() {
@@ -68,3 +68,60 @@ fn should_not_report_trailing_characters_in_tolerant_mode() {
     let result = parse_json("true false", Some(&JsonParseOptions::tolerant()));
     assert!(result.is_ok());
 }
+
+#[test]
+fn should_return_remainder_after_json_prefix() {
+    let (value, rest) = parse_json_prefix("  [1, 2]\r\nContent-Length: 0").unwrap();
+    assert_eq!(
+        value,
+        JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Number(2.0)])
+    );
+    assert_eq!(rest, "\r\nContent-Length: 0");
+
+    let (value, rest) = parse_json_prefix("\"done\"").unwrap();
+    assert_eq!(value, JsonValue::String("done".to_string()));
+    assert_eq!(rest, "");
+
+    assert!(parse_json_prefix("  ").is_err());
+}
+
+#[test]
+fn should_parse_value_at_offset() {
+    let buffer = "id=7;body={\"ok\": true};end";
+    let (value, end) = parse_json_at(buffer, 10).unwrap();
+    assert!(matches!(value, JsonValue::Object(_)));
+    assert_eq!(end, 22);
+    assert_eq!(&buffer[end..], ";end");
+
+    let (value, end) = parse_json_at(buffer, 3).unwrap();
+    assert_eq!(value, JsonValue::Number(7.0));
+    assert_eq!(end, 4);
+}
+
+#[test]
+fn should_report_absolute_positions_at_offset() {
+    let err = parse_json_at("line one\nline two: 01", 19).unwrap_err();
+    assert_eq!(err.message, "Leading zeros are not allowed in numbers");
+    assert_eq!(err.index, 20);
+    assert_eq!(err.line, 2);
+    assert_eq!(err.column, 12);
+}
+
+#[test]
+fn should_reject_invalid_offsets() {
+    assert!(parse_json_at("[]", 3).is_err());
+    assert!(parse_json_at("é1", 1).is_err());
+    assert_eq!(parse_json_at("[]", 2).unwrap_err().index, 2);
+}
+
+#[test]
+fn should_only_skip_json_whitespace() {
+    assert_eq!(
+        parse_json(" \t\r\n[ \t\r\n1 \t\r\n] \t\r\n", None),
+        Ok(JsonValue::Array(vec![JsonValue::Number(1.0)]))
+    );
+    for input in ["[\u{c}1]", "\u{a0}1", "[1\u{3000}]", "\u{feff}{}"] {
+        assert!(parse_json(input, None).is_err(), "{input:?}");
+    }
+    assert!(parse_json("1\u{b}", None).is_err());
+}
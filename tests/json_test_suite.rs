@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::Path;
+
+use synson::model::JsonParseOptions;
+use synson::{parse_json, JsonValue};
+
+/// The parsing tests of JSONTestSuite (https://github.com/nst/JSONTestSuite): `y_` files
+/// must be accepted, `n_` files rejected, and `i_` files are left to the implementation.
+const SUITE: &str = "tests/assets/JSONTestSuite/test_parsing";
+
+/// Whether synson accepts each implementation-defined case, so that any change in
+/// behavior is noticed.
+///
+/// Numbers outside the range of `f64` are accepted and become infinite or zero. Unpaired
+/// UTF-16 surrogate escapes are rejected, since a Rust `String` cannot hold them, and so is
+/// input that is not UTF-8. Nesting 500 levels deep is within `MAX_DEPTH`, and a byte
+/// order mark is not JSON whitespace.
+const IMPLEMENTATION_DEFINED: [(&str, bool); 35] = [
+    ("i_number_double_huge_neg_exp.json", true),
+    ("i_number_huge_exp.json", true),
+    ("i_number_neg_int_huge_exp.json", true),
+    ("i_number_pos_double_huge_exp.json", true),
+    ("i_number_real_neg_overflow.json", true),
+    ("i_number_real_pos_overflow.json", true),
+    ("i_number_real_underflow.json", true),
+    ("i_number_too_big_neg_int.json", true),
+    ("i_number_too_big_pos_int.json", true),
+    ("i_number_very_big_negative_int.json", true),
+    ("i_object_key_lone_2nd_surrogate.json", false),
+    ("i_string_1st_surrogate_but_2nd_missing.json", false),
+    ("i_string_1st_valid_surrogate_2nd_invalid.json", false),
+    ("i_string_UTF-16LE_with_BOM.json", false),
+    ("i_string_UTF-8_invalid_sequence.json", false),
+    ("i_string_UTF8_surrogate_U+D800.json", false),
+    ("i_string_incomplete_surrogate_and_escape_valid.json", false),
+    ("i_string_incomplete_surrogate_pair.json", false),
+    ("i_string_incomplete_surrogates_escape_valid.json", false),
+    ("i_string_invalid_lonely_surrogate.json", false),
+    ("i_string_invalid_surrogate.json", false),
+    ("i_string_invalid_utf-8.json", false),
+    ("i_string_inverted_surrogates_U+1D11E.json", false),
+    ("i_string_iso_latin_1.json", false),
+    ("i_string_lone_second_surrogate.json", false),
+    ("i_string_lone_utf8_continuation_byte.json", false),
+    ("i_string_not_in_unicode_range.json", false),
+    ("i_string_overlong_sequence_2_bytes.json", false),
+    ("i_string_overlong_sequence_6_bytes.json", false),
+    ("i_string_overlong_sequence_6_bytes_null.json", false),
+    ("i_string_truncated-utf-8.json", false),
+    ("i_string_utf16BE_no_BOM.json", false),
+    ("i_string_utf16LE_no_BOM.json", false),
+    ("i_structure_500_nested_arrays.json", true),
+    ("i_structure_UTF-8_BOM_empty_object.json", false),
+];
+
+fn cases(prefix: &str) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(SUITE)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with(prefix))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Parses a case in strict mode. Files that are not valid UTF-8 cannot be passed to the
+/// parser and count as rejected.
+fn parse(name: &str) -> Option<JsonValue> {
+    let bytes = fs::read(Path::new(SUITE).join(name)).unwrap();
+    let input = std::str::from_utf8(&bytes).ok()?;
+    parse_json(input, Some(&JsonParseOptions::strict())).ok()
+}
+
+#[test]
+fn should_accept_every_y_case() {
+    let cases = cases("y_");
+    assert_eq!(cases.len(), 95);
+
+    let rejected: Vec<_> = cases.iter().filter(|name| parse(name).is_none()).collect();
+    assert!(rejected.is_empty(), "rejected: {rejected:#?}");
+}
+
+#[test]
+fn should_reject_every_n_case() {
+    let cases = cases("n_");
+    assert_eq!(cases.len(), 188);
+
+    let accepted: Vec<_> = cases.iter().filter(|name| parse(name).is_some()).collect();
+    assert!(accepted.is_empty(), "accepted: {accepted:#?}");
+}
+
+#[test]
+fn should_keep_the_recorded_behavior_for_i_cases() {
+    let recorded: Vec<_> = IMPLEMENTATION_DEFINED
+        .map(|(name, _)| name.to_string())
+        .into();
+    assert_eq!(cases("i_"), recorded);
+
+    let changed: Vec<_> = IMPLEMENTATION_DEFINED
+        .iter()
+        .filter(|(name, accepted)| parse(name).is_some() != *accepted)
+        .collect();
+    assert!(changed.is_empty(), "behavior changed: {changed:#?}");
+}
+
+#[test]
+fn should_decode_y_cases_exactly() {
+    let value = |name: &str| parse(name).unwrap();
+    let string = |name: &str| value(name)[0].as_str().unwrap().to_string();
+
+    assert_eq!(
+        string("y_string_accepted_surrogate_pairs.json"),
+        "\u{1f639}\u{1f48d}"
+    );
+    assert_eq!(
+        string("y_string_allowed_escapes.json"),
+        "\"\\/\u{8}\u{c}\n\r\t"
+    );
+    assert_eq!(string("y_string_null_escape.json"), "\0");
+    assert_eq!(
+        string("y_string_unicode_U+10FFFE_nonchar.json"),
+        "\u{10fffe}"
+    );
+    assert_eq!(string("y_string_with_del_character.json"), "a\u{7f}a");
+    assert_eq!(
+        value("y_number_real_capital_e_neg_exp.json")[0],
+        JsonValue::Number(0.01)
+    );
+    assert_eq!(
+        value("y_number_minus_zero.json")[0]
+            .as_f64()
+            .map(f64::is_sign_negative),
+        Some(true)
+    );
+    assert_eq!(
+        value("y_object_duplicated_key.json")["a"],
+        JsonValue::String("c".to_string())
+    );
+    assert_eq!(
+        value("y_structure_lonely_negative_real.json"),
+        JsonValue::Number(-0.1)
+    );
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use synson::{json, parse_json, JsonValue};
+
+#[test]
+fn should_build_literals() {
+    assert_eq!(json!(null), JsonValue::Null);
+    assert_eq!(json!(true), JsonValue::Bool(true));
+    assert_eq!(json!(false), JsonValue::Bool(false));
+    assert_eq!(json!(-12.5), JsonValue::Number(-12.5));
+    assert_eq!(json!("text"), JsonValue::String("text".to_string()));
+    assert_eq!(json!([]), JsonValue::Array(vec![]));
+    assert_eq!(json!({}), JsonValue::Object(HashMap::new()));
+}
+
+#[test]
+fn should_build_nested_structures() {
+    let value = json!({
+        "a": [1, [2, [3]], {"b": null}],
+        "c": {"d": {"e": false}},
+        "f": []
+    });
+
+    assert_eq!(
+        value,
+        parse_json(
+            r#"{"a": [1, [2, [3]], {"b": null}], "c": {"d": {"e": false}}, "f": []}"#,
+            None
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn should_accept_trailing_commas() {
+    assert_eq!(json!([1, 2,]), json!([1, 2]));
+    assert_eq!(json!({"a": 1,}), json!({"a": 1}));
+    assert_eq!(
+        json!({"a": [true,], "b": {"c": null,},}),
+        json!({"a": [true], "b": {"c": null}})
+    );
+}
+
+#[test]
+fn should_interpolate_expressions() {
+    let count = 3;
+    let name = String::from("synson");
+    let inner = json!({"x": 1});
+    let maybe: Option<bool> = None;
+
+    let value = json!({
+        "count": count * 2,
+        "name": name.clone(),
+        "len": name.len(),
+        "inner": inner,
+        "maybe": maybe,
+        "items": vec![1, 2],
+        "neg": -count,
+    });
+
+    assert_eq!(
+        value,
+        parse_json(
+            r#"{"count": 6, "name": "synson", "len": 6, "inner": {"x": 1},
+                "maybe": null, "items": [1, 2], "neg": -3}"#,
+            None
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn should_interpolate_keys() {
+    let key = "dynamic";
+    let prefix = String::from("p");
+
+    let value = json!({
+        key: 1,
+        (format!("{prefix}_{}", 2)): 2,
+        prefix: [key],
+    });
+
+    assert_eq!(
+        value,
+        parse_json(r#"{"dynamic": 1, "p_2": 2, "p": ["dynamic"]}"#, None).unwrap()
+    );
+}
+
+#[test]
+fn should_keep_last_duplicate_key() {
+    assert_eq!(json!({"a": 1, "a": 2}), json!({"a": 2}));
+}
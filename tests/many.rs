@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use synson::{parse_many, JsonValue};
+
+fn object(key: &str, value: f64) -> JsonValue {
+    let mut map = HashMap::new();
+    map.insert(key.to_string(), JsonValue::Number(value));
+    JsonValue::Object(map)
+}
+
+#[test]
+fn should_parse_concatenated_values_with_spans() {
+    let input = "{\"a\":1}{\"b\":2}\n  [true] \"s\"null";
+    let items: Vec<_> = parse_many(input).collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(
+        items,
+        vec![
+            (object("a", 1.0), 0..7),
+            (object("b", 2.0), 7..14),
+            (JsonValue::Array(vec![JsonValue::Bool(true)]), 17..23),
+            (JsonValue::String("s".to_string()), 24..27),
+            (JsonValue::Null, 27..31),
+        ]
+    );
+    assert_eq!(&input[17..23], "[true]");
+}
+
+#[test]
+fn should_yield_nothing_for_blank_input() {
+    assert_eq!(parse_many("").count(), 0);
+    assert_eq!(parse_many(" \n\t\u{1E}\n").count(), 0);
+}
+
+#[test]
+fn should_parse_rfc7464_sequences() {
+    let input = "\u{1E}{\"a\":1}\n\u{1E}42\n";
+    let values: Vec<_> = parse_many(input)
+        .map(|item| item.map(|(value, _)| value))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(values, vec![object("a", 1.0), JsonValue::Number(42.0)]);
+}
+
+#[test]
+fn should_resume_after_truncated_record_in_sequence() {
+    let input = "\u{1E}{\"a\":\n\u{1E}{\"b\":2}\n";
+    let items: Vec<_> = parse_many(input).collect();
+
+    assert_eq!(items.len(), 2);
+    assert!(items[0].is_err());
+    assert_eq!(items[1].as_ref().unwrap().0, object("b", 2.0));
+}
+
+#[test]
+fn should_stop_after_first_error_outside_sequences() {
+    let items: Vec<_> = parse_many("1 [2,] 3").collect();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0], Ok((JsonValue::Number(1.0), 0..1)));
+
+    let err = items[1].as_ref().unwrap_err();
+    assert_eq!(err.message, "Trailing comma not allowed before ']'");
+    assert_eq!(err.index, 5);
+}
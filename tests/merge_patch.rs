@@ -0,0 +1,71 @@
+use synson::patch::{create_merge_patch, merge_patch};
+use synson::{parse_json, JsonValue};
+
+fn json(input: &str) -> JsonValue {
+    parse_json(input, None).unwrap()
+}
+
+#[test]
+fn should_apply_rfc7396_test_cases() {
+    let cases = [
+        (r#"{"a":"b"}"#, r#"{"a":"c"}"#, r#"{"a":"c"}"#),
+        (r#"{"a":"b"}"#, r#"{"b":"c"}"#, r#"{"a":"b","b":"c"}"#),
+        (r#"{"a":"b"}"#, r#"{"a":null}"#, r#"{}"#),
+        (r#"{"a":"b","b":"c"}"#, r#"{"a":null}"#, r#"{"b":"c"}"#),
+        (r#"{"a":["b"]}"#, r#"{"a":"c"}"#, r#"{"a":"c"}"#),
+        (r#"{"a":"c"}"#, r#"{"a":["b"]}"#, r#"{"a":["b"]}"#),
+        (
+            r#"{"a":{"b":"c"}}"#,
+            r#"{"a":{"b":"d","c":null}}"#,
+            r#"{"a":{"b":"d"}}"#,
+        ),
+        (r#"{"a":[{"b":"c"}]}"#, r#"{"a":[1]}"#, r#"{"a":[1]}"#),
+        (r#"["a","b"]"#, r#"["c","d"]"#, r#"["c","d"]"#),
+        (r#"{"a":"b"}"#, r#"["c"]"#, r#"["c"]"#),
+        (r#"{"a":"foo"}"#, r#"null"#, r#"null"#),
+        (r#"{"a":"foo"}"#, r#""bar""#, r#""bar""#),
+        (r#"{"e":null}"#, r#"{"a":1}"#, r#"{"e":null,"a":1}"#),
+        (r#"[1,2]"#, r#"{"a":"b","c":null}"#, r#"{"a":"b"}"#),
+        (
+            r#"{}"#,
+            r#"{"a":{"bb":{"ccc":null}}}"#,
+            r#"{"a":{"bb":{}}}"#,
+        ),
+    ];
+
+    for (target, patch, expected) in cases {
+        let mut value = json(target);
+        merge_patch(&mut value, &json(patch));
+        assert_eq!(value, json(expected), "patch {patch} on {target}");
+    }
+}
+
+#[test]
+fn should_create_merge_patches() {
+    let old = json(r#"{"keep": 1, "drop": true, "nested": {"x": 1, "y": 2}, "list": [1, 2]}"#);
+    let new =
+        json(r#"{"keep": 1, "nested": {"x": 1, "y": 3, "z": {}}, "list": [1], "added": "v"}"#);
+
+    let patch = create_merge_patch(&old, &new);
+    assert_eq!(
+        patch,
+        json(r#"{"drop": null, "nested": {"y": 3, "z": {}}, "list": [1], "added": "v"}"#)
+    );
+
+    let mut patched = old.clone();
+    merge_patch(&mut patched, &patch);
+    assert_eq!(patched, new);
+}
+
+#[test]
+fn should_create_replacements_for_non_objects() {
+    assert_eq!(create_merge_patch(&json("[1]"), &json("[2]")), json("[2]"));
+    assert_eq!(
+        create_merge_patch(&json(r#"{"a": 1}"#), &json("3")),
+        json("3")
+    );
+    assert_eq!(
+        create_merge_patch(&json(r#"{"a": 1}"#), &json(r#"{"a": 1}"#)),
+        json("{}")
+    );
+}
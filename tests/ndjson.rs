@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::io::BufReader;
+use synson::model::JsonParseOptions;
+use synson::{parse_ndjson, serialize_ndjson, JsonValue, NdjsonReader, NdjsonWriter};
+
+#[test]
+fn should_read_one_value_per_line() {
+    let input = "{\"id\": 1}\n[1, 2]\n\"text\"\nnull\n";
+    let values: Vec<_> = parse_ndjson(input).collect::<Result<_, _>>().unwrap();
+
+    let mut object = HashMap::new();
+    object.insert("id".to_string(), JsonValue::Number(1.0));
+
+    assert_eq!(
+        values,
+        vec![
+            JsonValue::Object(object),
+            JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Number(2.0)]),
+            JsonValue::String("text".to_string()),
+            JsonValue::Null,
+        ]
+    );
+}
+
+#[test]
+fn should_skip_blank_lines_and_handle_crlf() {
+    let values: Vec<_> = parse_ndjson("\n  \r\ntrue\r\n\r\nfalse")
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(values, vec![JsonValue::Bool(true), JsonValue::Bool(false)]);
+}
+
+#[test]
+fn should_continue_past_bad_lines_with_line_numbers() {
+    let input = "1\n{\"a\" 1}\n\n[true,]\n2\n";
+    let results: Vec<_> = parse_ndjson(input).collect();
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0], Ok(JsonValue::Number(1.0)));
+
+    let err = results[1].as_ref().unwrap_err();
+    assert_eq!(err.message, "Expected ':' after key in object");
+    assert_eq!(err.line, 2);
+    assert_eq!(err.column, 6);
+    assert_eq!(err.index, 7);
+
+    let err = results[2].as_ref().unwrap_err();
+    assert_eq!(err.message, "Trailing comma not allowed before ']'");
+    assert_eq!(err.line, 4);
+
+    assert_eq!(results[3], Ok(JsonValue::Number(2.0)));
+}
+
+#[test]
+fn should_reject_multiple_values_on_one_line() {
+    let results: Vec<_> = parse_ndjson("1 2\n").collect();
+    assert_eq!(
+        results[0].as_ref().unwrap_err().message,
+        "Trailing characters after JSON value"
+    );
+
+    let results: Vec<_> =
+        NdjsonReader::with_options("1 2\n".as_bytes(), JsonParseOptions::tolerant()).collect();
+    assert_eq!(results, vec![Ok(JsonValue::Number(1.0))]);
+}
+
+#[test]
+fn should_report_invalid_utf8_and_continue() {
+    let input: &[u8] = b"\"\xff\"\n3\n";
+    let results: Vec<_> = NdjsonReader::new(BufReader::new(input)).collect();
+
+    assert_eq!(
+        results[0].as_ref().unwrap_err().message,
+        "Invalid UTF-8 sequence in line"
+    );
+    assert_eq!(results[1], Ok(JsonValue::Number(3.0)));
+}
+
+#[test]
+fn should_write_one_compact_value_per_line() {
+    let values = vec![
+        JsonValue::Array(vec![JsonValue::Number(1.5), JsonValue::Null]),
+        JsonValue::String("multi\nline".to_string()),
+    ];
+
+    let mut writer = NdjsonWriter::new(Vec::new());
+    for value in &values {
+        writer.write_value(value).unwrap();
+    }
+    writer.flush().unwrap();
+    let output = String::from_utf8(writer.into_inner()).unwrap();
+
+    assert_eq!(output, "[1.5,null]\n\"multi\\nline\"\n");
+    assert_eq!(serialize_ndjson(&values), output);
+
+    let round_trip: Vec<_> = parse_ndjson(&output).collect::<Result<_, _>>().unwrap();
+    assert_eq!(round_trip, values);
+}
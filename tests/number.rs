@@ -1,4 +1,5 @@
-use synson::{parse_number, JsonValue};
+use synson::number::{format_f64, parse_f64};
+use synson::{parse_number, serialize_json, JsonValue};
 
 #[test]
 fn should_parse_valid_numbers() {
@@ -47,3 +48,120 @@ fn should_reject_invalid_numbers() {
     assert!(parse_number("01").is_err());
     assert!(parse_number("00").is_err());
 }
+
+/// A xorshift generator, so that every run checks the same inputs.
+fn xorshift(mut state: u64) -> impl FnMut() -> u64 {
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }
+}
+
+#[test]
+fn should_round_numbers_correctly() {
+    let cases = [
+        ("9007199254740993", 9007199254740992.0),
+        ("9007199254740995", 9007199254740996.0),
+        (
+            "0.1000000000000000055511151231257827021181583404541015625",
+            0.1,
+        ),
+        (
+            "0.1000000000000000055511151231257827021181583404541015626",
+            0.1,
+        ),
+        ("2.2250738585072011e-308", 2.225073858507201e-308),
+        ("2.4703282292062327e-324", 0.0),
+        ("2.4703282292062328e-324", 5e-324),
+        ("1.7976931348623158e308", f64::MAX),
+        ("179769313486231580793728971405301e276", f64::MAX),
+        ("1.7976931348623159e308", f64::INFINITY),
+        ("-1e-400", -0.0),
+        ("0e99999999999999999999", 0.0),
+        ("123456789012345678901234567890e-10", 12345678901234567890.0),
+        ("1e23", 1e23),
+    ];
+    for (text, expected) in cases {
+        assert_eq!(
+            parse_f64(text).map(f64::to_bits),
+            Some(expected.to_bits()),
+            "{text}"
+        );
+    }
+
+    let mut next = xorshift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..100_000 {
+        let digits = (next() % 25) as usize;
+        let mut text = format!("{}", 1 + next() % 9);
+        for _ in 0..digits {
+            text.push(char::from(b'0' + (next() % 10) as u8));
+        }
+        let point = (next() % (digits as u64 + 2)) as usize;
+        if (1..=digits).contains(&point) {
+            text.insert(point, '.');
+        }
+        text.push_str(&format!("e{}", (next() % 700) as i64 - 350));
+
+        let expected: f64 = text.parse().unwrap();
+        assert_eq!(
+            parse_f64(&text).map(f64::to_bits),
+            Some(expected.to_bits()),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn should_format_the_shortest_round_trip_representation() {
+    assert_eq!(format_f64(1.0), "1");
+    assert_eq!(format_f64(-12.5), "-12.5");
+    assert_eq!(format_f64(0.000001), "0.000001");
+    assert_eq!(format_f64(1e-7), "1e-7");
+    assert_eq!(format_f64(123e18), "123000000000000000000");
+    assert_eq!(format_f64(1e21), "1e+21");
+    assert_eq!(format_f64(f64::MAX), "1.7976931348623157e+308");
+    assert_eq!(format_f64(f64::MIN_POSITIVE), "2.2250738585072014e-308");
+    assert_eq!(format_f64(5e-324), "5e-324");
+    // An exact tie between two 17-digit candidates rounds to the even one.
+    assert_eq!(
+        format_f64(165_793_407_361_858.0 + 0.125),
+        "165793407361858.12"
+    );
+
+    let mut next = xorshift(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..100_000 {
+        let n = f64::from_bits(next());
+        if !n.is_finite() {
+            continue;
+        }
+        let text = format_f64(n);
+        assert_eq!(
+            parse_f64(&text).map(f64::to_bits),
+            Some(n.to_bits()),
+            "{text}"
+        );
+
+        let digits = |s: &str| {
+            let mantissa = s.split(['e', 'E']).next().unwrap();
+            let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+            digits.trim_matches('0').len()
+        };
+        assert_eq!(digits(&text), digits(&format!("{n:e}")), "{text}");
+    }
+}
+
+#[test]
+fn should_serialize_numbers_in_ecmascript_layout() {
+    let value = JsonValue::Array(
+        [1e21, 1e-7, 0.5, 100.0, -0.0, 1.0 / 3.0]
+            .into_iter()
+            .map(JsonValue::Number)
+            .collect(),
+    );
+    assert_eq!(
+        serialize_json(&value),
+        "[1e+21,1e-7,0.5,100,-0,0.3333333333333333]"
+    );
+}
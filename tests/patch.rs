@@ -0,0 +1,232 @@
+use synson::model::JsonPatchError;
+use synson::patch::{apply_patch, create_patch, parse_patch, PatchOperation};
+use synson::{parse_json, JsonPointerError, JsonValue};
+
+fn json(input: &str) -> JsonValue {
+    parse_json(input, None).unwrap()
+}
+
+fn apply(doc: &str, patch: &str) -> Result<JsonValue, JsonPatchError> {
+    let mut target = json(doc);
+    apply_patch(&mut target, &parse_patch(&json(patch))?)?;
+    Ok(target)
+}
+
+#[test]
+fn should_apply_rfc6902_examples() {
+    let cases = [
+        (
+            r#"{"foo": "bar"}"#,
+            r#"[{"op": "add", "path": "/baz", "value": "qux"}]"#,
+            r#"{"baz": "qux", "foo": "bar"}"#,
+        ),
+        (
+            r#"{"foo": ["bar", "baz"]}"#,
+            r#"[{"op": "add", "path": "/foo/1", "value": "qux"}]"#,
+            r#"{"foo": ["bar", "qux", "baz"]}"#,
+        ),
+        (
+            r#"{"baz": "qux", "foo": "bar"}"#,
+            r#"[{"op": "remove", "path": "/baz"}]"#,
+            r#"{"foo": "bar"}"#,
+        ),
+        (
+            r#"{"foo": ["bar", "qux", "baz"]}"#,
+            r#"[{"op": "remove", "path": "/foo/1"}]"#,
+            r#"{"foo": ["bar", "baz"]}"#,
+        ),
+        (
+            r#"{"baz": "qux", "foo": "bar"}"#,
+            r#"[{"op": "replace", "path": "/baz", "value": "boo"}]"#,
+            r#"{"baz": "boo", "foo": "bar"}"#,
+        ),
+        (
+            r#"{"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}}"#,
+            r#"[{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}]"#,
+            r#"{"foo": {"bar": "baz"}, "qux": {"corge": "grault", "thud": "fred"}}"#,
+        ),
+        (
+            r#"{"foo": ["all", "grass", "cows", "eat"]}"#,
+            r#"[{"op": "move", "from": "/foo/1", "path": "/foo/3"}]"#,
+            r#"{"foo": ["all", "cows", "eat", "grass"]}"#,
+        ),
+        (
+            r#"{"foo": "bar"}"#,
+            r#"[{"op": "add", "path": "/child", "value": {"grandchild": {}}}]"#,
+            r#"{"foo": "bar", "child": {"grandchild": {}}}"#,
+        ),
+        (
+            r#"{"foo": "bar"}"#,
+            r#"[{"op": "add", "path": "/baz", "value": "qux", "xyz": 123}]"#,
+            r#"{"foo": "bar", "baz": "qux"}"#,
+        ),
+        (
+            r#"{"foo": ["bar"]}"#,
+            r#"[{"op": "add", "path": "/foo/-", "value": ["abc", "def"]}]"#,
+            r#"{"foo": ["bar", ["abc", "def"]]}"#,
+        ),
+        (
+            r#"{"/": 9, "~1": 10}"#,
+            r#"[{"op": "test", "path": "/~01", "value": 10}]"#,
+            r#"{"/": 9, "~1": 10}"#,
+        ),
+        (
+            r#"{"baz": "qux", "foo": ["a", 2, "c"]}"#,
+            r#"[{"op": "test", "path": "/baz", "value": "qux"}, {"op": "test", "path": "/foo/1", "value": 2}]"#,
+            r#"{"baz": "qux", "foo": ["a", 2, "c"]}"#,
+        ),
+        (
+            r#"{"a": {"b": 1}}"#,
+            r#"[{"op": "copy", "from": "/a", "path": "/c"}, {"op": "replace", "path": "/c/b", "value": 2}]"#,
+            r#"{"a": {"b": 1}, "c": {"b": 2}}"#,
+        ),
+    ];
+
+    for (doc, patch, expected) in cases {
+        assert_eq!(apply(doc, patch), Ok(json(expected)), "patch {patch}");
+    }
+}
+
+#[test]
+fn should_report_failing_operations() {
+    assert_eq!(
+        apply(
+            r#"{"baz": "qux"}"#,
+            r#"[{"op": "test", "path": "/baz", "value": "bar"}]"#
+        ),
+        Err(JsonPatchError::TestFailed {
+            operation: 0,
+            path: "/baz".to_string()
+        })
+    );
+    assert_eq!(
+        apply(
+            r#"{"foo": "bar"}"#,
+            r#"[{"op": "add", "path": "/baz/bat", "value": "qux"}]"#
+        ),
+        Err(JsonPatchError::Pointer {
+            operation: 0,
+            error: JsonPointerError::KeyNotFound {
+                path: "/baz".to_string(),
+                key: "baz".to_string()
+            }
+        })
+    );
+    assert!(matches!(
+        apply(
+            r#"{"a": {"b": 1}}"#,
+            r#"[{"op": "move", "from": "/a", "path": "/a/b/c"}]"#
+        ),
+        Err(JsonPatchError::MoveIntoChild { operation: 0, .. })
+    ));
+}
+
+#[test]
+fn should_roll_back_on_failure() {
+    let mut target = json(r#"{"a": 1, "list": [1, 2]}"#);
+    let original = target.clone();
+    let patch = parse_patch(&json(
+        r#"[
+            {"op": "remove", "path": "/a"},
+            {"op": "add", "path": "/list/-", "value": 3},
+            {"op": "replace", "path": "/missing", "value": 0}
+        ]"#,
+    ))
+    .unwrap();
+
+    let err = apply_patch(&mut target, &patch).unwrap_err();
+    assert!(matches!(err, JsonPatchError::Pointer { operation: 2, .. }));
+    assert_eq!(target, original);
+}
+
+#[test]
+fn should_reject_malformed_patch_documents() {
+    let invalid = [
+        r#"{"op": "add"}"#,
+        r#"["add"]"#,
+        r#"[{"path": "/a"}]"#,
+        r#"[{"op": "add", "path": "/a"}]"#,
+        r#"[{"op": "move", "path": "/a"}]"#,
+        r#"[{"op": "remove", "path": "a"}]"#,
+        r#"[{"op": "remove", "path": 1}]"#,
+        r#"[{"op": "frobnicate", "path": "/a"}]"#,
+    ];
+
+    for patch in invalid {
+        assert!(
+            matches!(
+                parse_patch(&json(patch)),
+                Err(JsonPatchError::InvalidPatch { .. })
+            ),
+            "patch {patch}"
+        );
+    }
+
+    // A null value is still a value.
+    let patch = parse_patch(&json(r#"[{"op": "add", "path": "/a", "value": null}]"#)).unwrap();
+    assert!(matches!(
+        patch.operations[0],
+        PatchOperation::Add {
+            value: JsonValue::Null,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn should_round_trip_patch_documents() {
+    let document = json(
+        r#"[
+            {"op": "add", "path": "/a", "value": [1]},
+            {"op": "copy", "from": "/a", "path": "/b"},
+            {"op": "test", "path": "/b/0", "value": 1}
+        ]"#,
+    );
+    assert_eq!(parse_patch(&document).unwrap().to_value(), document);
+}
+
+#[test]
+fn should_generate_patches_that_reproduce_the_target() {
+    let pairs = [
+        (r#"{"a": 1}"#, r#"{"a": 1}"#),
+        (r#"{"a": 1, "b": 2}"#, r#"{"b": 3, "c": {"d": [1]}}"#),
+        (r#"[1, 2, 3, 4, 5]"#, r#"[0, 1, 3, 5, 6]"#),
+        (r#"[1, 2, 3]"#, r#"[]"#),
+        (r#"[]"#, r#"["x", "y"]"#),
+        (
+            r#"[{"id": 1, "v": "a"}, {"id": 2}]"#,
+            r#"[{"id": 1, "v": "b"}, {"id": 2}, 3]"#,
+        ),
+        (r#"{"a": [1, [2, 3]]}"#, r#"{"a": [[2, 4], 1]}"#),
+        (r#"{"a": {"b": {}}}"#, r#"["now", "an", "array"]"#),
+        (r#"{"a/b": {"~": 1}}"#, r#"{"a/b": {"~": 2}}"#),
+    ];
+
+    for (from, to) in pairs {
+        let (from, to) = (json(from), json(to));
+        let patch = create_patch(&from, &to);
+        let mut patched = from.clone();
+        apply_patch(&mut patched, &patch).unwrap();
+        assert_eq!(patched, to);
+    }
+}
+
+#[test]
+fn should_generate_minimal_patches() {
+    assert!(create_patch(&json(r#"{"a": [1, 2]}"#), &json(r#"{"a": [1, 2]}"#)).is_empty());
+
+    let patch = create_patch(&json("[1, 2, 3, 4]"), &json("[1, 3, 4]"));
+    assert_eq!(
+        patch.to_value(),
+        json(r#"[{"op": "remove", "path": "/1"}]"#)
+    );
+
+    let patch = create_patch(
+        &json(r#"{"user": {"name": "a", "age": 30}}"#),
+        &json(r#"{"user": {"name": "a", "age": 31}}"#),
+    );
+    assert_eq!(
+        patch.to_value(),
+        json(r#"[{"op": "replace", "path": "/user/age", "value": 31}]"#)
+    );
+}
@@ -0,0 +1,294 @@
+use synson::{parse_json, serialize_json, JsonPath, JsonValue};
+
+fn bookstore() -> JsonValue {
+    parse_json(
+        r#"{ "store": {
+            "book": [
+              { "category": "reference", "author": "Nigel Rees",
+                "title": "Sayings of the Century", "price": 8.95 },
+              { "category": "fiction", "author": "Evelyn Waugh",
+                "title": "Sword of Honour", "price": 12.99 },
+              { "category": "fiction", "author": "Herman Melville",
+                "title": "Moby Dick", "isbn": "0-553-21311-3", "price": 8.99 },
+              { "category": "fiction", "author": "J. R. R. Tolkien",
+                "title": "The Lord of the Rings", "isbn": "0-395-19395-8", "price": 22.99 }
+            ],
+            "bicycle": { "color": "red", "price": 399 }
+        } }"#,
+        None,
+    )
+    .unwrap()
+}
+
+fn values(doc: &JsonValue, expression: &str) -> Vec<String> {
+    JsonPath::parse(expression)
+        .unwrap()
+        .query_values(doc)
+        .into_iter()
+        .map(serialize_json)
+        .collect()
+}
+
+fn paths(doc: &JsonValue, expression: &str) -> Vec<String> {
+    JsonPath::parse(expression)
+        .unwrap()
+        .query(doc)
+        .into_iter()
+        .map(|m| m.path.to_string())
+        .collect()
+}
+
+#[test]
+fn should_evaluate_rfc9535_bookstore_examples() {
+    let doc = bookstore();
+
+    assert_eq!(
+        values(&doc, "$.store.book[*].author"),
+        [
+            r#""Nigel Rees""#,
+            r#""Evelyn Waugh""#,
+            r#""Herman Melville""#,
+            r#""J. R. R. Tolkien""#
+        ]
+    );
+    assert_eq!(values(&doc, "$..author").len(), 4);
+    assert_eq!(values(&doc, "$.store.*").len(), 2);
+    assert_eq!(values(&doc, "$.store..price").len(), 5);
+    assert_eq!(values(&doc, "$..book[2].author"), [r#""Herman Melville""#]);
+    assert_eq!(values(&doc, "$..book[2].publisher"), Vec::<String>::new());
+    assert_eq!(
+        values(&doc, "$..book[-1].title"),
+        [r#""The Lord of the Rings""#]
+    );
+    assert_eq!(paths(&doc, "$..book[0,1]"), paths(&doc, "$..book[:2]"));
+    assert_eq!(
+        values(&doc, "$..book[?@.isbn].title"),
+        [r#""Moby Dick""#, r#""The Lord of the Rings""#]
+    );
+    assert_eq!(
+        values(&doc, "$..book[?@.price<10].title"),
+        [r#""Sayings of the Century""#, r#""Moby Dick""#]
+    );
+    assert_eq!(values(&doc, "$..*").len(), 27);
+}
+
+#[test]
+fn should_report_normalized_paths() {
+    let doc = parse_json(r#"{"a": [1, {"it's": 2}], "b\n": 3}"#, None).unwrap();
+
+    assert_eq!(paths(&doc, "$.a[1]['it\\'s']"), [r"$['a'][1]['it\'s']"]);
+    assert_eq!(paths(&doc, "$['b\\n']"), [r"$['b\n']"]);
+    assert_eq!(
+        paths(&doc, "$..*"),
+        [
+            "$['a']",
+            r"$['b\n']",
+            "$['a'][0]",
+            "$['a'][1]",
+            r"$['a'][1]['it\'s']"
+        ]
+    );
+
+    let matches = JsonPath::parse("$.a[1]").unwrap().query(&doc);
+    assert_eq!(matches[0].path.to_pointer().to_string(), "/a/1");
+}
+
+#[test]
+fn should_select_slices() {
+    let doc = parse_json(r#"["a", "b", "c", "d", "e", "f", "g"]"#, None).unwrap();
+
+    assert_eq!(values(&doc, "$[1:3]"), [r#""b""#, r#""c""#]);
+    assert_eq!(values(&doc, "$[5:]"), [r#""f""#, r#""g""#]);
+    assert_eq!(values(&doc, "$[1:5:2]"), [r#""b""#, r#""d""#]);
+    assert_eq!(values(&doc, "$[5:1:-2]"), [r#""f""#, r#""d""#]);
+    assert_eq!(values(&doc, "$[::-1]").len(), 7);
+    assert_eq!(values(&doc, "$[-2:]"), [r#""f""#, r#""g""#]);
+    assert!(values(&doc, "$[::0]").is_empty());
+    assert!(values(&doc, "$[10:20]").is_empty());
+}
+
+#[test]
+fn should_keep_duplicates_from_selector_lists() {
+    let doc = parse_json(r#"{"a": 1, "b": [5, 6]}"#, None).unwrap();
+
+    assert_eq!(values(&doc, "$['a', 'a']"), ["1", "1"]);
+    assert_eq!(values(&doc, "$.b[0, -1, 0]"), ["5", "6", "5"]);
+}
+
+#[test]
+fn should_apply_comparison_semantics() {
+    let doc = parse_json(
+        r#"[{"a": 1}, {"a": "1"}, {"a": null}, {"b": 2}, {"a": [1]}, {"a": {"x": 1}}]"#,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(paths(&doc, "$[?@.a == 1]"), ["$[0]"]);
+    assert_eq!(paths(&doc, "$[?@.a == '1']"), ["$[1]"]);
+    assert_eq!(paths(&doc, "$[?@.a == null]"), ["$[2]"]);
+    // A missing member only equals another missing member.
+    assert_eq!(paths(&doc, "$[?@.a == @.c]"), ["$[3]"]);
+    assert_eq!(paths(&doc, "$[?@.a == $[4].a]"), ["$[4]"]);
+    assert_eq!(paths(&doc, "$[?@.a == $[5].a]"), ["$[5]"]);
+    assert_eq!(paths(&doc, "$[?@.a <= 1]"), ["$[0]"]);
+    assert_eq!(paths(&doc, "$[?@.a > 'a']").len(), 0);
+    assert_eq!(paths(&doc, "$[?@.a != 1]").len(), 5);
+    assert_eq!(paths(&doc, "$[?!@.a]"), ["$[3]"]);
+    assert_eq!(paths(&doc, "$[?@.a == 1 || @.b == 2]"), ["$[0]", "$[3]"]);
+    assert_eq!(paths(&doc, "$[?(@.a || @.b) && !(@.a == 1)]").len(), 5);
+}
+
+#[test]
+fn should_evaluate_functions() {
+    let doc = parse_json(
+        r#"[
+            {"name": "café", "tags": ["a", "b"]},
+            {"name": "bob", "tags": []},
+            {"name": "Bobby", "tags": ["x"]}
+        ]"#,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(paths(&doc, "$[?length(@.name) == 4]"), ["$[0]"]);
+    assert_eq!(paths(&doc, "$[?length(@.tags) >= 1]"), ["$[0]", "$[2]"]);
+    assert_eq!(paths(&doc, "$[?count(@.tags[*]) == 0]"), ["$[1]"]);
+    assert_eq!(paths(&doc, "$[?value(@.tags[0]) == 'x']"), ["$[2]"]);
+    assert_eq!(paths(&doc, "$[?match(@.name, '[Bb]ob')]"), ["$[1]"]);
+    assert_eq!(
+        paths(&doc, "$[?search(@.name, '[Bb]ob')]"),
+        ["$[1]", "$[2]"]
+    );
+    assert_eq!(paths(&doc, "$[?match(@.name, 'caf.')]"), ["$[0]"]);
+    assert_eq!(paths(&doc, r"$[?match(@.name, '\\p{Lu}.*')]"), ["$[2]"]);
+    // An invalid pattern matches nothing instead of failing the query.
+    assert!(paths(&doc, "$[?match(@.name, '(')]").is_empty());
+}
+
+#[test]
+fn should_support_regex_quantifiers_and_classes() {
+    let doc = parse_json(r#"["aaa", "ab", "a1b2", "xyz", ""]"#, None).unwrap();
+
+    assert_eq!(paths(&doc, "$[?match(@, 'a{2,3}')]"), ["$[0]"]);
+    assert_eq!(paths(&doc, "$[?match(@, '(a|b)+')]"), ["$[0]", "$[1]"]);
+    assert_eq!(paths(&doc, "$[?match(@, '[a-z0-9]*')]").len(), 5);
+    assert_eq!(paths(&doc, "$[?match(@, '[^a]+')]"), ["$[3]"]);
+    assert_eq!(paths(&doc, r"$[?search(@, '\\p{Nd}')]"), ["$[2]"]);
+    assert_eq!(paths(&doc, "$[?match(@, 'a?')]"), ["$[4]"]);
+    // `\d` is not part of I-Regexp, so the pattern is invalid and matches nothing.
+    assert!(paths(&doc, r"$[?search(@, '\\d')]").is_empty());
+}
+
+#[test]
+fn should_match_nothing_with_patterns_that_compile_too_large() {
+    let doc = parse_json(
+        r#"[{"name": "aaa", "pattern": "((a{1000}){1000}){1000}"}]"#,
+        None,
+    )
+    .unwrap();
+
+    assert!(paths(&doc, "$[?match(@.name, '((a{1000}){1000}){1000}')]").is_empty());
+    assert!(paths(&doc, "$[?match(@.name, @.pattern)]").is_empty());
+    assert!(paths(&doc, "$[?search(@.name, '(a{1000}b{1000}){1000}')]").is_empty());
+    assert_eq!(paths(&doc, "$[?match(@.name, '(a{1,3}){1,3}')]"), ["$[0]"]);
+}
+
+#[test]
+fn should_reject_invalid_expressions() {
+    for expression in [
+        "",
+        "store",
+        " $",
+        "$ ",
+        "$.",
+        "$[",
+        "$[01]",
+        "$[-0]",
+        "$[9007199254740992]",
+        "$['a'",
+        "$['\u{1}']",
+        "$[\"\\z\"]",
+        "$..",
+        "$[?@.a == 1 ==]",
+        "$[?1]",
+        "$[?@.a == [1]]",
+        "$[?@.* == 1]",
+        "$[?length(@.*) == 1]",
+        "$[?length(@.a)]",
+        "$[?count(@.a) == 1 && count(1) == 1]",
+        "$[?match(@.a)]",
+        "$[?unknown(@.a)]",
+        "$[?match(@.a, 'x') == true]",
+    ] {
+        assert!(
+            JsonPath::parse(expression).is_err(),
+            "{expression:?} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn should_reject_expressions_nested_too_deeply() {
+    let doc = parse_json(r#"[{"a": "aa"}]"#, None).unwrap();
+    let nested = |open: &str, inner: &str, close: &str, depth: usize| {
+        format!("{}{inner}{}", open.repeat(depth), close.repeat(depth))
+    };
+
+    let parens = format!("$[?{}]", nested("(", "@.a", ")", 100));
+    assert_eq!(paths(&doc, &parens), ["$[0]"]);
+    for expression in [
+        format!("$[?{}]", nested("(", "@.a", ")", 30_000)),
+        format!("$[?{}]", nested("!(", "@.a", ")", 30_000)),
+        format!("${}", nested("[?@", ".a", "]", 30_000)),
+        format!("$[?{} == 1]", nested("length(", "@.a", ")", 30_000)),
+    ] {
+        let error = JsonPath::parse(&expression).unwrap_err();
+        assert_eq!(error.message, "Nesting is deeper than 128 levels");
+    }
+
+    let groups = format!("$[?match(@.a, '{}')]", nested("(", "a", ")", 500));
+    assert_eq!(paths(&doc, &groups.replace("a)", "a+)")), ["$[0]"]);
+    let groups = format!("$[?match(@.a, '{}')]", nested("(", "a", ")", 5_000));
+    assert!(paths(&doc, &groups.replace("a)", "a+)")).is_empty());
+}
+
+#[test]
+fn should_report_error_positions() {
+    let err = JsonPath::parse("$.a[?@.b = 1]").unwrap_err();
+    assert_eq!(err.index, 9);
+
+    let err = JsonPath::parse("$.a\n  [?foo(@)]").unwrap_err();
+    assert_eq!(err.message, "Unknown function 'foo'");
+    assert_eq!(err.line, 2);
+    assert_eq!(err.column, 5);
+}
+
+#[test]
+fn should_accept_blank_space_where_allowed() {
+    let doc = parse_json(r#"{"a": [{"b": 1}, {"b": 2}]}"#, None).unwrap();
+
+    assert_eq!(
+        values(&doc, "$ .a [ ? @.b == 2 ] .b"),
+        values(&doc, "$.a[?@.b==2].b")
+    );
+    assert_eq!(values(&doc, "$.a[ 0 , 1 ].b"), ["1", "2"]);
+    assert_eq!(values(&doc, "$.a[ 0 : 1 ].b"), ["1"]);
+}
+
+#[test]
+fn should_parse_from_str_and_display_source() {
+    let path: JsonPath = "$..b".parse().unwrap();
+    assert_eq!(path.to_string(), "$..b");
+}
+
+#[test]
+fn should_share_compiled_paths_across_threads() {
+    let path = JsonPath::parse("$[?match(@.name, '[Bb]ob')].name").unwrap();
+    let doc = parse_json(r#"[{"name": "Bob"}, {"name": "Alice"}]"#, None).unwrap();
+
+    std::thread::scope(|scope| {
+        for _ in 0..2 {
+            scope.spawn(|| assert_eq!(path.query(&doc).len(), 1));
+        }
+    });
+}
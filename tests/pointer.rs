@@ -0,0 +1,219 @@
+use synson::{parse_json, JsonPointer, JsonPointerError, JsonValue};
+
+fn rfc6901_document() -> JsonValue {
+    parse_json(
+        r#"{
+            "foo": ["bar", "baz"],
+            "": 0,
+            "a/b": 1,
+            "c%d": 2,
+            "e^f": 3,
+            "g|h": 4,
+            "i\\j": 5,
+            "k\"l": 6,
+            " ": 7,
+            "m~n": 8
+        }"#,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn should_resolve_rfc6901_examples() {
+    let doc = rfc6901_document();
+
+    assert_eq!(doc.pointer(""), Ok(&doc));
+    assert_eq!(
+        doc.pointer("/foo"),
+        Ok(&JsonValue::Array(vec![
+            JsonValue::String("bar".to_string()),
+            JsonValue::String("baz".to_string()),
+        ]))
+    );
+    assert_eq!(
+        doc.pointer("/foo/0"),
+        Ok(&JsonValue::String("bar".to_string()))
+    );
+
+    let cases = [
+        ("/", 0.0),
+        ("/a~1b", 1.0),
+        ("/c%d", 2.0),
+        ("/e^f", 3.0),
+        ("/g|h", 4.0),
+        ("/i\\j", 5.0),
+        ("/k\"l", 6.0),
+        ("/ ", 7.0),
+        ("/m~0n", 8.0),
+    ];
+    for (pointer, expected) in cases {
+        assert_eq!(doc.pointer(pointer), Ok(&JsonValue::Number(expected)));
+    }
+}
+
+#[test]
+fn should_reject_malformed_pointers() {
+    let doc = rfc6901_document();
+
+    assert!(matches!(
+        doc.pointer("foo"),
+        Err(JsonPointerError::Syntax { .. })
+    ));
+    assert!(matches!(
+        doc.pointer("/m~2n"),
+        Err(JsonPointerError::Syntax { .. })
+    ));
+    assert!(matches!(
+        doc.pointer("/foo~"),
+        Err(JsonPointerError::Syntax { .. })
+    ));
+}
+
+#[test]
+fn should_report_typed_resolution_errors() {
+    let doc = rfc6901_document();
+
+    assert_eq!(
+        doc.pointer("/foo/2"),
+        Err(JsonPointerError::IndexOutOfBounds {
+            path: "/foo/2".to_string(),
+            index: 2,
+            len: 2
+        })
+    );
+    assert_eq!(
+        doc.pointer("/foo/-"),
+        Err(JsonPointerError::IndexOutOfBounds {
+            path: "/foo/-".to_string(),
+            index: 2,
+            len: 2
+        })
+    );
+    assert_eq!(
+        doc.pointer("/foo/01"),
+        Err(JsonPointerError::InvalidIndex {
+            path: "/foo/01".to_string(),
+            token: "01".to_string()
+        })
+    );
+    assert_eq!(
+        doc.pointer("/a~1b/x"),
+        Err(JsonPointerError::NotAContainer {
+            path: "/a~1b/x".to_string()
+        })
+    );
+    assert_eq!(
+        doc.pointer("/nope/x"),
+        Err(JsonPointerError::KeyNotFound {
+            path: "/nope".to_string(),
+            key: "nope".to_string()
+        })
+    );
+}
+
+#[test]
+fn should_mutate_through_pointer_mut() {
+    let mut doc = rfc6901_document();
+    *doc.pointer_mut("/foo/1").unwrap() = JsonValue::Null;
+    assert_eq!(doc.pointer("/foo/1"), Ok(&JsonValue::Null));
+}
+
+#[test]
+fn should_insert_members_and_elements() {
+    let mut doc = parse_json(r#"{"a": {"list": [1, 3]}}"#, None).unwrap();
+
+    assert_eq!(
+        doc.pointer_insert("/a/list/1", JsonValue::Number(2.0)),
+        Ok(None)
+    );
+    assert_eq!(
+        doc.pointer_insert("/a/list/-", JsonValue::Number(4.0)),
+        Ok(None)
+    );
+    assert_eq!(
+        doc.pointer_insert("/a/list/4", JsonValue::Number(5.0)),
+        Ok(None)
+    );
+    assert_eq!(
+        doc.pointer_insert("/a/new", JsonValue::Bool(true)),
+        Ok(None)
+    );
+    assert_eq!(
+        doc.pointer_insert("/a/new", JsonValue::Bool(false)),
+        Ok(Some(JsonValue::Bool(true)))
+    );
+
+    assert_eq!(
+        doc,
+        parse_json(r#"{"a": {"list": [1, 2, 3, 4, 5], "new": false}}"#, None).unwrap()
+    );
+
+    assert!(matches!(
+        doc.pointer_insert("/a/list/7", JsonValue::Null),
+        Err(JsonPointerError::IndexOutOfBounds {
+            index: 7,
+            len: 5,
+            ..
+        })
+    ));
+    assert!(matches!(
+        doc.pointer_insert("/missing/child", JsonValue::Null),
+        Err(JsonPointerError::KeyNotFound { .. })
+    ));
+
+    let previous = doc.pointer_insert("", JsonValue::Null).unwrap();
+    assert!(matches!(previous, Some(JsonValue::Object(_))));
+    assert_eq!(doc, JsonValue::Null);
+}
+
+#[test]
+fn should_remove_and_replace_values() {
+    let mut doc = parse_json(r#"{"a": [1, 2, 3], "b": "x"}"#, None).unwrap();
+
+    assert_eq!(doc.pointer_remove("/a/0"), Ok(JsonValue::Number(1.0)));
+    assert_eq!(
+        doc.pointer_remove("/b"),
+        Ok(JsonValue::String("x".to_string()))
+    );
+    assert_eq!(
+        doc.pointer_remove(""),
+        Err(JsonPointerError::RootNotAllowed)
+    );
+    assert!(doc.pointer_remove("/b").is_err());
+
+    assert_eq!(
+        doc.pointer_replace("/a/1", JsonValue::Null),
+        Ok(JsonValue::Number(3.0))
+    );
+    let err = doc.pointer_replace("/a/-", JsonValue::Null).unwrap_err();
+    assert_eq!(
+        err,
+        JsonPointerError::IndexOutOfBounds {
+            path: "/a/-".to_string(),
+            index: 2,
+            len: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Index 2 out of bounds at '/a/-' (array length is 2)"
+    );
+    assert!(matches!(
+        doc.pointer_remove("/a/-"),
+        Err(JsonPointerError::IndexOutOfBounds { index: 2, .. })
+    ));
+
+    assert_eq!(doc, parse_json(r#"{"a": [2, null]}"#, None).unwrap());
+}
+
+#[test]
+fn should_format_and_build_pointers() {
+    let pointer: JsonPointer = "/a~1b/~0/0".parse().unwrap();
+    assert_eq!(pointer.tokens(), ["a/b", "~", "0"]);
+    assert_eq!(pointer.to_string(), "/a~1b/~0/0");
+    assert_eq!(pointer.parent().unwrap().to_string(), "/a~1b/~0");
+    assert!(pointer.starts_with(&JsonPointer::parse("/a~1b").unwrap()));
+    assert!(JsonPointer::root().is_root());
+    assert_eq!(JsonPointer::root().join("x").join("/").to_string(), "/x/~1");
+}
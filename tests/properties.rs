@@ -0,0 +1,132 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use synson::diff::diff_json;
+use synson::model::JsonParseOptions;
+use synson::{
+    apply_patch, create_patch, parse_json, parse_json_at, parse_json_prefix, parse_many,
+    serialize_canonical, serialize_json, serialize_json_pretty, stable_hash, JsonKey,
+    JsonPushParser, JsonValue,
+};
+
+/// How many generated inputs each property is checked against.
+const CASES: u64 = 300;
+
+/// A xorshift generator, so that every run checks the same inputs.
+struct Rng(u64);
+
+impl Rng {
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                self.0 as u8
+            })
+            .collect()
+    }
+}
+
+/// Checks `property` against values generated from pseudo-random bytes.
+fn check<T: for<'a> Arbitrary<'a>>(property: impl Fn(T)) {
+    for seed in 0..CASES {
+        let bytes = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1).bytes(4096);
+        if let Ok(value) = T::arbitrary(&mut Unstructured::new(&bytes)) {
+            property(value);
+        }
+    }
+}
+
+/// Parses a whole input with the push parser, feeding it in chunks of `chunk_size` bytes.
+fn push_parse(
+    input: &[u8],
+    chunk_size: usize,
+    options: JsonParseOptions,
+) -> Result<Vec<synson::JsonEvent>, synson::JsonParseError> {
+    let mut parser = JsonPushParser::with_options(options);
+    let mut events = Vec::new();
+    for chunk in input.chunks(chunk_size.max(1)) {
+        events.extend(parser.feed(chunk)?);
+    }
+    events.extend(parser.finish()?);
+    Ok(events)
+}
+
+#[test]
+fn should_round_trip_through_every_serializer() {
+    check(|value: JsonValue| {
+        let compact = serialize_json(&value);
+        assert_eq!(parse_json(&compact, None).as_ref(), Ok(&value), "{compact}");
+
+        let pretty = serialize_json_pretty(&value, 2);
+        assert_eq!(parse_json(&pretty, None).as_ref(), Ok(&value), "{pretty}");
+
+        let canonical = serialize_canonical(&value).unwrap();
+        let reparsed = parse_json(&canonical, None).unwrap();
+        assert_eq!(reparsed, value, "{canonical}");
+        assert_eq!(serialize_canonical(&reparsed).unwrap(), canonical);
+    });
+}
+
+#[test]
+fn should_hash_and_compare_round_tripped_values_equally() {
+    check(|value: JsonValue| {
+        let reparsed = parse_json(&serialize_json_pretty(&value, 0), None).unwrap();
+
+        assert_eq!(stable_hash(&value), stable_hash(&reparsed));
+        assert_eq!(JsonKey::new(value), JsonKey::new(reparsed));
+    });
+}
+
+#[test]
+fn should_create_patches_that_apply() {
+    check(|(from, to): (JsonValue, JsonValue)| {
+        assert!(diff_json(&from, &from, None).is_empty());
+
+        let mut patched = from.clone();
+        apply_patch(&mut patched, &create_patch(&from, &to)).unwrap();
+        assert_eq!(patched, to);
+    });
+}
+
+#[test]
+fn should_push_parse_independently_of_chunking() {
+    check(
+        |(value, options, chunk_size): (JsonValue, JsonParseOptions, u8)| {
+            let text = serialize_json_pretty(&value, 1);
+            let whole = push_parse(text.as_bytes(), text.len(), options);
+            assert!(whole.is_ok(), "{text}");
+            assert_eq!(
+                push_parse(text.as_bytes(), usize::from(chunk_size), options),
+                whole
+            );
+        },
+    );
+}
+
+#[test]
+fn should_never_panic_on_damaged_documents() {
+    check(
+        |(value, options, cut, byte): (JsonValue, JsonParseOptions, u16, u8)| {
+            let mut bytes = serialize_json(&value).into_bytes();
+            let cut = usize::from(cut) % (bytes.len() + 1);
+            bytes.truncate(cut);
+            bytes.push(byte);
+            let input = String::from_utf8_lossy(&bytes);
+
+            let result = parse_json(&input, Some(&options));
+            for offset in 0..=input.len() + 1 {
+                let _ = parse_json_at(&input, offset);
+            }
+            let _ = parse_json_prefix(&input);
+            parse_many(&input).take(input.len() + 1).for_each(drop);
+
+            let whole = push_parse(input.as_bytes(), input.len(), options);
+            assert_eq!(push_parse(input.as_bytes(), 1, options), whole);
+            if options.strict {
+                assert_eq!(whole.is_ok(), result.is_ok(), "{input:?}");
+            }
+        },
+    );
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use synson::model::JsonParseOptions;
+use synson::{parse_json, JsonEvent, JsonPushParser, JsonValue};
+
+fn feed_in_chunks(input: &[u8], chunk_size: usize) -> Vec<JsonEvent> {
+    let mut parser = JsonPushParser::new();
+    let mut events = Vec::new();
+    for chunk in input.chunks(chunk_size) {
+        events.extend(parser.feed(chunk).unwrap());
+    }
+    events.extend(parser.finish().unwrap());
+    events
+}
+
+fn document(events: &[JsonEvent]) -> Option<&JsonValue> {
+    events.iter().find_map(|event| match event {
+        JsonEvent::Document(value) => Some(value),
+        _ => None,
+    })
+}
+
+#[test]
+fn should_match_parse_json_for_every_chunk_size() {
+    let input = r#" {"user": {"id": -12.5e1, "tags": ["rust", "caf\u00e9 😀", "\ud83d\ude00"]}, "ok": [true, false, null], "empty": {}} "#;
+    let expected = parse_json(input, None).unwrap();
+
+    for chunk_size in 1..=input.len() {
+        let events = feed_in_chunks(input.as_bytes(), chunk_size);
+        assert_eq!(
+            document(&events),
+            Some(&expected),
+            "chunk size {chunk_size}"
+        );
+    }
+}
+
+#[test]
+fn should_emit_structural_events_in_order() {
+    let events = feed_in_chunks(br#"{"a": [1, "x"]}"#, 3);
+
+    let mut inner = HashMap::new();
+    inner.insert(
+        "a".to_string(),
+        JsonValue::Array(vec![
+            JsonValue::Number(1.0),
+            JsonValue::String("x".to_string()),
+        ]),
+    );
+
+    assert_eq!(
+        events,
+        vec![
+            JsonEvent::StartObject,
+            JsonEvent::Key("a".to_string()),
+            JsonEvent::StartArray,
+            JsonEvent::Value(JsonValue::Number(1.0)),
+            JsonEvent::Value(JsonValue::String("x".to_string())),
+            JsonEvent::EndArray,
+            JsonEvent::EndObject,
+            JsonEvent::Document(JsonValue::Object(inner)),
+        ]
+    );
+}
+
+#[test]
+fn should_emit_events_as_soon_as_they_are_available() {
+    let mut parser = JsonPushParser::new();
+
+    assert_eq!(parser.feed(b"[tr").unwrap(), vec![JsonEvent::StartArray]);
+    assert_eq!(parser.feed(b"ue").unwrap(), vec![]);
+    assert_eq!(
+        parser.feed(b",").unwrap(),
+        vec![JsonEvent::Value(JsonValue::Bool(true))]
+    );
+    assert!(!parser.is_complete());
+    assert_eq!(parser.feed(b" null").unwrap(), vec![]);
+    assert_eq!(parser.feed(b"]").unwrap().len(), 3);
+    assert!(parser.is_complete());
+}
+
+#[test]
+fn should_flush_top_level_number_on_finish() {
+    let mut parser = JsonPushParser::new();
+    assert_eq!(parser.feed(b"42").unwrap(), vec![]);
+    assert_eq!(
+        parser.finish().unwrap(),
+        vec![
+            JsonEvent::Value(JsonValue::Number(42.0)),
+            JsonEvent::Document(JsonValue::Number(42.0)),
+        ]
+    );
+}
+
+#[test]
+fn should_decode_utf8_split_across_chunks() {
+    let input = "\"é😀\"".as_bytes();
+    let events = feed_in_chunks(input, 1);
+    assert_eq!(
+        document(&events),
+        Some(&JsonValue::String("é😀".to_string()))
+    );
+}
+
+#[test]
+fn should_reject_invalid_utf8_in_strings() {
+    let mut parser = JsonPushParser::new();
+    let err = parser.feed(b"[\"\xff\"]").unwrap_err();
+    assert_eq!(err.message, "Invalid UTF-8 sequence in string");
+    assert_eq!(err.index, 1);
+}
+
+#[test]
+fn should_report_absolute_positions_across_chunks() {
+    let mut parser = JsonPushParser::new();
+    parser.feed(b"{\"a\": 1,\n").unwrap();
+    let err = parser.feed(b"  \"b\" 2}").unwrap_err();
+    assert_eq!(err.message, "Expected ':' after key in object");
+    assert_eq!(err.index, 15);
+    assert_eq!(err.line, 2);
+    assert_eq!(err.column, 7);
+
+    // The parser stays failed.
+    assert_eq!(parser.feed(b"").unwrap_err().index, 15);
+}
+
+#[test]
+fn should_report_malformed_tokens() {
+    let mut parser = JsonPushParser::new();
+    parser.feed(b"[1, 01").unwrap();
+    let err = parser.feed(b"]").unwrap_err();
+    assert_eq!(err.message, "Leading zeros are not allowed in numbers");
+    assert_eq!(err.index, 5);
+
+    let mut parser = JsonPushParser::new();
+    let err = parser.feed(b"[nul]").unwrap_err();
+    assert_eq!(err.message, "Expected 'null' literal");
+
+    let mut parser = JsonPushParser::new();
+    let err = parser.feed(b"\"\\ud83d x\"").unwrap_err();
+    assert_eq!(err.message, "Invalid unicode surrogate in string");
+
+    let mut parser = JsonPushParser::new();
+    let err = parser.feed(b"[\"a\tb\"]").unwrap_err();
+    assert_eq!(err.message, "Unescaped control character in string");
+    assert_eq!(err.index, 3);
+}
+
+#[test]
+fn should_reject_incomplete_documents_on_finish() {
+    let mut parser = JsonPushParser::new();
+    parser.feed(b"{\"a\": [1, 2").unwrap();
+    assert_eq!(
+        parser.finish().unwrap_err().message,
+        "Unexpected end of JSON input"
+    );
+
+    let mut parser = JsonPushParser::new();
+    parser.feed(b"\"open").unwrap();
+    assert_eq!(
+        parser.finish().unwrap_err().message,
+        "Unterminated string literal"
+    );
+}
+
+#[test]
+fn should_apply_parse_options() {
+    let mut parser = JsonPushParser::new();
+    assert_eq!(
+        parser.feed(b"[1,]").unwrap_err().message,
+        "Trailing comma not allowed before ']'"
+    );
+
+    let mut parser = JsonPushParser::new();
+    assert_eq!(
+        parser.feed(b"true false").unwrap_err().message,
+        "Trailing characters after JSON value"
+    );
+
+    let mut parser = JsonPushParser::with_options(JsonParseOptions::tolerant());
+    parser.feed(b"{\"a\": [1,],} trailing").unwrap();
+    assert!(parser.finish().is_ok());
+}
+
+#[test]
+fn should_agree_with_parse_json_on_trailing_commas() {
+    let options = JsonParseOptions::new(true, true);
+    for input in ["[1,]", "{\"a\": [1, {\"b\": 2,},],}", "[1,,]", "[,]", "{,}"] {
+        let mut parser = JsonPushParser::with_options(options);
+        let pushed = parser
+            .feed(input.as_bytes())
+            .and_then(|mut events| {
+                events.extend(parser.finish()?);
+                Ok(events)
+            })
+            .map(|events| document(&events).cloned());
+        let parsed = parse_json(input, Some(&options));
+        assert_eq!(pushed.is_ok(), parsed.is_ok(), "{input}");
+        if let (Ok(pushed), Ok(parsed)) = (pushed, parsed) {
+            assert_eq!(pushed, Some(parsed), "{input}");
+        }
+    }
+
+    assert!(parse_json("[1,]", None).is_err());
+}
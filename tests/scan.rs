@@ -0,0 +1,84 @@
+use synson::model::JsonValue;
+use synson::parser::scan::{digit_run, string_run, whitespace_run};
+use synson::parser::{parse_number, parse_string};
+
+/// Places `byte` at every offset of buffers of every length up to 100, so that each
+/// vector width and every tail length is covered.
+fn each_placement(filler: u8, byte: u8, mut check: impl FnMut(&[u8], usize)) {
+    for len in 0..100 {
+        check(&vec![filler; len], len);
+        for at in 0..len {
+            let mut buffer = vec![filler; len];
+            buffer[at] = byte;
+            check(&buffer, at);
+        }
+    }
+}
+
+#[test]
+fn should_find_the_first_special_string_byte() {
+    for special in [b'"', b'\\', 0x00, 0x0A, 0x1F] {
+        each_placement(b'a', special, |buffer, expected| {
+            assert_eq!(string_run(buffer), expected, "{special:#x} in {buffer:?}");
+        });
+    }
+    for plain in [b' ', b'!', b'#', b'[', b']', b'~', 0x7F, 0x80, 0xC3, 0xFF] {
+        each_placement(b'a', plain, |buffer, _| {
+            assert_eq!(string_run(buffer), buffer.len());
+        });
+    }
+
+    let mut buffer = vec![b'x'; 70];
+    buffer[40] = b'\\';
+    buffer[20] = b'"';
+    assert_eq!(string_run(&buffer), 20);
+}
+
+#[test]
+fn should_find_the_end_of_a_digit_run() {
+    for stop in [b'.', b'e', b'/', b':', b' ', b'-', 0x00, 0xB0, 0xFF] {
+        each_placement(b'7', stop, |buffer, expected| {
+            assert_eq!(digit_run(buffer), expected, "{stop:#x} in {buffer:?}");
+        });
+    }
+    assert_eq!(digit_run(b"0123456789"), 10);
+}
+
+#[test]
+fn should_find_the_end_of_a_whitespace_run() {
+    for filler in [b' ', b'\t', b'\n', b'\r'] {
+        for stop in [
+            b'"', b'{', b',', b'0', 0x0B, 0x0C, 0x00, 0x21, 0x2C, 0x8A, 0xA0, 0xFF,
+        ] {
+            each_placement(filler, stop, |buffer, expected| {
+                assert_eq!(whitespace_run(buffer), expected, "{stop:#x} in {buffer:?}");
+            });
+        }
+    }
+
+    let mixed: Vec<u8> = b" \t\r\n".iter().copied().cycle().take(75).collect();
+    assert_eq!(whitespace_run(&mixed), 75);
+    assert_eq!(whitespace_run("\n\u{a0}".as_bytes()), 1);
+}
+
+#[test]
+fn should_parse_long_strings_and_numbers() {
+    let text = "é\\n😀 ".repeat(50) + &"x".repeat(33);
+    let input = format!("\"{text}\" tail");
+    let (value, rest) = parse_string(&input).unwrap();
+    assert_eq!(value, JsonValue::String(text.replace("\\n", "\n")));
+    assert_eq!(rest, " tail");
+
+    let control = format!("\"{}\u{1}\"", "a".repeat(45));
+    assert_eq!(
+        parse_string(&control).unwrap_err().message,
+        "Unescaped control character in string"
+    );
+    assert_eq!(parse_string(&control).unwrap_err().column, 47);
+
+    let digits = "12345678901234567890123456789012345";
+    let input = format!("-{digits}.{digits}e-30]");
+    let (value, rest) = parse_number(&input).unwrap();
+    assert_eq!(value, JsonValue::Number(-12345.678901234567));
+    assert_eq!(rest, "]");
+}
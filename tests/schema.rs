@@ -0,0 +1,372 @@
+use synson::{json, parse_json, serialize_json, validate_json, JsonSchema, JsonValue};
+
+fn schema(source: &str) -> JsonSchema {
+    JsonSchema::compile(&parse_json(source, None).unwrap()).unwrap()
+}
+
+fn violations(schema: &JsonSchema, instance: &JsonValue) -> Vec<(String, String, String)> {
+    schema
+        .validate(instance)
+        .into_iter()
+        .map(|v| {
+            (
+                v.instance_path.to_string(),
+                v.schema_path.to_string(),
+                v.message,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn should_accept_valid_instances() {
+    let schema = schema(
+        r#"{
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "minimum": 1},
+                "name": {"type": "string", "minLength": 1, "maxLength": 10},
+                "tags": {"type": "array", "items": {"type": "string"}, "uniqueItems": true}
+            },
+            "required": ["id", "name"],
+            "additionalProperties": false
+        }"#,
+    );
+
+    assert!(schema.is_valid(&json!({"id": 1, "name": "a", "tags": ["x", "y"]})));
+    assert!(schema.is_valid(&json!({"id": 2.0, "name": "0123456789"})));
+}
+
+#[test]
+fn should_report_every_violation_with_paths() {
+    let schema = schema(
+        r#"{
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "minimum": 1},
+                "tags": {"type": "array", "items": {"type": "string"}}
+            },
+            "required": ["id", "name"],
+            "additionalProperties": false
+        }"#,
+    );
+    let instance = json!({"id": 0.5, "tags": ["a", 1, null], "extra": true});
+
+    assert_eq!(
+        violations(&schema, &instance),
+        [
+            (
+                "".into(),
+                "/required".into(),
+                "Missing required property 'name'".into()
+            ),
+            (
+                "/id".into(),
+                "/properties/id/type".into(),
+                "Expected integer, found number".into()
+            ),
+            (
+                "/id".into(),
+                "/properties/id/minimum".into(),
+                "0.5 is less than the minimum of 1".into()
+            ),
+            (
+                "/tags/1".into(),
+                "/properties/tags/items/type".into(),
+                "Expected string, found number".into()
+            ),
+            (
+                "/tags/2".into(),
+                "/properties/tags/items/type".into(),
+                "Expected string, found null".into()
+            ),
+            (
+                "/extra".into(),
+                "/additionalProperties".into(),
+                "Additional property 'extra' is not allowed".into()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn should_validate_numeric_and_string_constraints() {
+    let schema = schema(
+        r#"{
+            "properties": {
+                "n": {"multipleOf": 0.1, "exclusiveMaximum": 1},
+                "s": {"pattern": "^[a-z]+\\d*$"}
+            }
+        }"#,
+    );
+
+    assert!(schema.is_valid(&json!({"n": 0.3, "s": "abc12"})));
+
+    let messages: Vec<_> = violations(&schema, &json!({"n": 1, "s": "Abc"}))
+        .into_iter()
+        .map(|v| v.2)
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "1 is not less than the exclusive maximum of 1",
+            "String does not match the pattern '^[a-z]+\\d*$'",
+        ]
+    );
+}
+
+#[test]
+fn should_match_unanchored_patterns_anywhere() {
+    let schema = schema(r#"{"pattern": "b\\w"}"#);
+
+    assert!(schema.is_valid(&json!("abc")));
+    assert!(!schema.is_valid(&json!("ab")));
+    assert!(schema.is_valid(&json!(42)));
+}
+
+#[test]
+fn should_check_multiple_of_exactly_for_integers() {
+    let threes = schema(r#"{"multipleOf": 3}"#);
+    assert!(threes.is_valid(&json!(9)));
+    assert!(threes.is_valid(&json!(-3e15)));
+    assert!(threes.is_valid(&json!(1.2e17)));
+    assert!(!threes.is_valid(&json!(1e17)));
+    assert!(!threes.is_valid(&json!(4)));
+    assert!(!threes.is_valid(&json!(3.5)));
+
+    let tenths = schema(r#"{"multipleOf": 0.1}"#);
+    assert!(tenths.is_valid(&json!(0.3)));
+    assert!(tenths.is_valid(&json!(12345.6)));
+    assert!(!tenths.is_valid(&json!(0.35)));
+
+    assert!(!schema(r#"{"multipleOf": 1}"#).is_valid(&json!(1000000000000.5)));
+    assert!(!schema(r#"{"multipleOf": 0.01}"#).is_valid(&json!(123456789.015)));
+    assert!(!schema(r#"{"multipleOf": 0.5}"#).is_valid(&json!(10000000000.25)));
+    assert!(schema(r#"{"multipleOf": 0.01}"#).is_valid(&json!(123456789.01)));
+
+    let tiny = schema(r#"{"multipleOf": 1e-308}"#);
+    assert!(!tiny.is_valid(&json!(1e300)));
+}
+
+#[test]
+fn should_treat_anchors_as_assertions_anywhere_in_patterns() {
+    let matches = |pattern: &str, input: &str| {
+        schema(&format!(
+            "{{\"pattern\": {}}}",
+            serialize_json(&json!(pattern))
+        ))
+        .is_valid(&json!(input))
+    };
+
+    assert!(matches("^a|b$", "ab"));
+    assert!(matches("^a|b$", "xb"));
+    assert!(!matches("^a|b$", "xa"));
+    assert!(matches("^foo|bar", "xbar"));
+    assert!(matches("a|b$", "ax"));
+    assert!(matches("^a|^b", "bx"));
+    assert!(!matches("^a|^b", "xb"));
+    assert!(matches("(^|,)b", "a,b"));
+    assert!(matches("^$", ""));
+    assert!(!matches("^$", "a"));
+    assert!(matches("a$|c", "ba"));
+    assert!(matches("[$^]", "^"));
+    assert!(matches(r"\$\^", "$^"));
+    assert!(!matches("a^b", "a^b"));
+}
+
+#[test]
+fn should_validate_prefix_items_and_contains() {
+    let schema = schema(
+        r#"{
+            "prefixItems": [{"type": "string"}, {"type": "number"}],
+            "items": {"type": "boolean"},
+            "contains": {"const": true},
+            "maxContains": 2
+        }"#,
+    );
+
+    assert!(schema.is_valid(&json!(["a", 1, true, false])));
+    assert_eq!(
+        violations(&schema, &json!(["a", 1, false, 3])),
+        [
+            (
+                "".into(),
+                "/contains".into(),
+                "Array does not contain a matching item".into()
+            ),
+            (
+                "/3".into(),
+                "/items/type".into(),
+                "Expected boolean, found number".into()
+            ),
+        ]
+    );
+    assert!(!schema.is_valid(&json!(["a", 1, true, true, true])));
+}
+
+#[test]
+fn should_validate_enum_and_const() {
+    let schema = schema(r#"{"enum": [1, "two", [3]], "not": {"const": [3]}}"#);
+
+    assert!(schema.is_valid(&json!(1.0)));
+    assert!(schema.is_valid(&json!("two")));
+    assert!(!schema.is_valid(&json!([3])));
+    assert!(!schema.is_valid(&json!("three")));
+}
+
+#[test]
+fn should_apply_combinators() {
+    let schema = schema(
+        r#"{
+            "anyOf": [{"type": "string"}, {"type": "number"}],
+            "oneOf": [{"minimum": 0}, {"maximum": 10}]
+        }"#,
+    );
+
+    assert!(schema.is_valid(&json!(-1)));
+    assert!(!schema.is_valid(&json!("text")));
+    assert_eq!(
+        violations(&schema, &json!(5))[0].2,
+        "Value matches more than one of the 'oneOf' schemas"
+    );
+    assert_eq!(
+        violations(&schema, &json!(null))[0].2,
+        "Value does not match any of the 'anyOf' schemas"
+    );
+}
+
+#[test]
+fn should_apply_if_then_else() {
+    let schema = schema(
+        r#"{
+            "if": {"properties": {"kind": {"const": "circle"}}},
+            "then": {"required": ["radius"]},
+            "else": {"required": ["width"]}
+        }"#,
+    );
+
+    assert!(schema.is_valid(&json!({"kind": "circle", "radius": 1})));
+    assert!(schema.is_valid(&json!({"kind": "square", "width": 1})));
+    assert_eq!(
+        violations(&schema, &json!({"kind": "circle"})),
+        [(
+            "".into(),
+            "/then/required".into(),
+            "Missing required property 'radius'".into()
+        )]
+    );
+}
+
+#[test]
+fn should_resolve_local_references() {
+    let schema = schema(
+        r##"{
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "value": {"type": "number"},
+                        "next": {"anyOf": [{"type": "null"}, {"$ref": "#/$defs/node"}]}
+                    }
+                },
+                "name": {"$anchor": "name", "type": "string"}
+            },
+            "properties": {
+                "list": {"$ref": "#/$defs/node"},
+                "label": {"$ref": "#name"}
+            }
+        }"##,
+    );
+
+    let list = json!({"value": 1, "next": {"value": 2, "next": null}});
+    assert!(schema.is_valid(&json!({"list": list, "label": "x"})));
+    assert_eq!(
+        violations(&schema, &json!({"list": {"value": "1"}, "label": 1})),
+        [
+            (
+                "/label".into(),
+                "/$defs/name/type".into(),
+                "Expected string, found number".into()
+            ),
+            (
+                "/list/value".into(),
+                "/$defs/node/properties/value/type".into(),
+                "Expected number, found string".into()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn should_handle_boolean_schemas() {
+    let always = schema("true");
+    let never = schema("false");
+
+    assert!(always.is_valid(&json!({"any": "thing"})));
+    assert_eq!(
+        violations(&never, &json!(1)),
+        [(
+            "".into(),
+            "".into(),
+            "False schema does not allow any value".into()
+        )]
+    );
+}
+
+#[test]
+fn should_stop_on_reference_cycles() {
+    let schema = schema(r##"{"$ref": "#"}"##);
+
+    assert_eq!(
+        violations(&schema, &json!(1))[0].2,
+        "Reference cycle does not advance into the instance"
+    );
+}
+
+#[test]
+fn should_reject_invalid_schemas() {
+    let error = |source: &str| {
+        let err = JsonSchema::compile(&parse_json(source, None).unwrap()).unwrap_err();
+        (err.path.to_string(), err.message)
+    };
+
+    assert_eq!(
+        error(r#"{"type": "float"}"#),
+        ("/type".into(), "Unknown type 'float'".into())
+    );
+    assert_eq!(
+        error(r#"{"properties": {"a": 1}}"#),
+        (
+            "/properties/a".into(),
+            "A schema must be an object or a boolean".into()
+        )
+    );
+    assert_eq!(
+        error(r#"{"minLength": -1}"#),
+        (
+            "/minLength".into(),
+            "Expected a non-negative integer".into()
+        )
+    );
+    assert_eq!(
+        error(r#"{"$ref": "other.json#/a"}"#),
+        (
+            "/$ref".into(),
+            "Only local references ('#...') are supported, found 'other.json#/a'".into()
+        )
+    );
+    assert_eq!(error(r#"{"pattern": "(a"}"#).0, "/pattern");
+    assert_eq!(error(r#"{"pattern": "a^*"}"#).0, "/pattern");
+}
+
+#[test]
+fn should_validate_in_one_step() {
+    let schema = parse_json(r#"{"maxProperties": 1}"#, None).unwrap();
+
+    let violations = validate_json(&schema, &json!({"a": 1, "b": 2})).unwrap();
+    assert_eq!(
+        violations[0].to_string(),
+        "Object has more than 1 properties at ''"
+    );
+    assert!(validate_json(&json!([]), &json!(1)).is_err());
+}
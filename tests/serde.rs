@@ -0,0 +1,178 @@
+#![cfg(feature = "serde")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use synson::parser::MAX_DEPTH;
+use synson::{from_str, json, parse_json, serialize_json, to_string, JsonValue};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    port: u16,
+    #[serde(default)]
+    tags: Vec<String>,
+    limits: Option<Limits>,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Limits(u32, f64);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Fast,
+    Retry(u8),
+    Window { start: i64, end: i64 },
+    Pair(bool, bool),
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Tree {
+    Leaf,
+    Node(Box<Tree>),
+}
+
+#[test]
+fn should_deserialize_structs_without_intermediate_value() {
+    let config: Config = from_str(
+        r#"{"name": "svc", "port": 8080, "limits": [10, 0.5], "mode": {"Retry": 3}, "extra": [1, {"x": null}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config,
+        Config {
+            name: "svc".to_string(),
+            port: 8080,
+            tags: vec![],
+            limits: Some(Limits(10, 0.5)),
+            mode: Mode::Retry(3),
+        }
+    );
+}
+
+#[test]
+fn should_round_trip_through_to_string() {
+    let modes = vec![
+        Mode::Fast,
+        Mode::Retry(1),
+        Mode::Window { start: -1, end: 9 },
+        Mode::Pair(true, false),
+    ];
+    let text = to_string(&modes).unwrap();
+
+    assert_eq!(
+        text,
+        r#"["Fast",{"Retry":1},{"Window":{"start":-1,"end":9}},{"Pair":[true,false]}]"#
+    );
+    assert_eq!(from_str::<Vec<Mode>>(&text).unwrap(), modes);
+}
+
+#[test]
+fn should_keep_large_integers_exact() {
+    assert_eq!(from_str::<u64>("18446744073709551615").unwrap(), u64::MAX);
+    assert_eq!(from_str::<i64>("-9223372036854775808").unwrap(), i64::MIN);
+    assert_eq!(to_string(&i64::MIN).unwrap(), "-9223372036854775808");
+    assert_eq!(from_str::<f64>("1e3").unwrap(), 1000.0);
+    assert_eq!(to_string(&0.1f32).unwrap(), "0.1");
+    assert_eq!(to_string(&f64::NAN).unwrap(), "null");
+}
+
+#[test]
+fn should_keep_the_sign_of_negative_zero() {
+    let zero = from_str::<f64>("-0").unwrap();
+    assert!(zero == 0.0 && zero.is_sign_negative());
+    let limits = from_str::<Limits>("[1, -0]").unwrap();
+    assert!(limits.1.is_sign_negative());
+    assert!(from_str::<f64>(&to_string(&limits.1).unwrap())
+        .unwrap()
+        .is_sign_negative());
+    assert!(
+        matches!(from_str::<JsonValue>("-0"), Ok(JsonValue::Number(n)) if n.is_sign_negative())
+    );
+
+    assert_eq!(from_str::<i64>("-0").unwrap(), 0);
+    assert_eq!(from_str::<u8>(" -0 ").unwrap(), 0);
+    assert_eq!(from_str::<Vec<i32>>("[-0, -1]").unwrap(), vec![0, -1]);
+}
+
+#[test]
+fn should_handle_strings_and_maps() {
+    assert_eq!(from_str::<String>(r#""café \"q\"""#).unwrap(), "café \"q\"");
+
+    let map: BTreeMap<u32, Vec<Option<char>>> =
+        from_str(r#"{"2": ["a", null], "10": []}"#).unwrap();
+    assert_eq!(map[&2], vec![Some('a'), None]);
+    assert_eq!(to_string(&map).unwrap(), r#"{"2":["a",null],"10":[]}"#);
+
+    let err = to_string(&HashMap::from([((1, 2), 3)])).unwrap_err();
+    assert!(err.message.starts_with("Map keys must be"));
+}
+
+#[test]
+fn should_map_errors_to_positions() {
+    let err = from_str::<Config>("{\n  \"name\": \"svc\",\n  \"port\": 70000\n}").unwrap_err();
+    assert_eq!((err.line, err.column, err.index), (3, 11, 29));
+    assert!(err.message.contains("70000"));
+
+    let err = from_str::<Config>(r#"{"name": "svc", "port": 1}"#).unwrap_err();
+    assert_eq!(err.message, "missing field `mode`");
+    assert_eq!(err.index, 0);
+
+    let err = from_str::<Vec<u8>>("[1, 2,]").unwrap_err();
+    assert_eq!(err.message, "Trailing comma not allowed before ']'");
+    assert_eq!(err.index, 6);
+
+    let err = from_str::<Vec<u8>>("[1 2]").unwrap_err();
+    assert_eq!(err.message, "Expected ',' or ']' after array element");
+
+    let err = from_str::<(u8, u8)>("[1, 2, 3]").unwrap_err();
+    assert_eq!(err.index, 5);
+
+    let err = from_str::<Mode>(r#""Slow""#).unwrap_err();
+    assert!(err.message.starts_with("unknown variant `Slow`"));
+
+    let err = from_str::<bool>("true false").unwrap_err();
+    assert_eq!(err.message, "Trailing characters after JSON value");
+    assert_eq!(err.index, 5);
+
+    let err = from_str::<String>(r#""bad\q""#).unwrap_err();
+    assert_eq!(err.message, "Invalid escape sequence in string");
+    assert_eq!(err.index, 5);
+}
+
+#[test]
+fn should_serialize_and_deserialize_json_value() {
+    let input = r#"{"b": [1, 2.5, -0.0, null], "a": {"nested": true, "s": "x"}}"#;
+    let value: JsonValue = from_str(input).unwrap();
+
+    assert_eq!(value, parse_json(input, None).unwrap());
+    assert_eq!(to_string(&value).unwrap(), serialize_json(&value));
+    assert_eq!(
+        from_str::<JsonValue>(&to_string(&value).unwrap()).unwrap(),
+        value
+    );
+
+    let embedded: HashMap<String, JsonValue> = from_str(r#"{"k": [1, {"x": "y"}]}"#).unwrap();
+    assert_eq!(embedded["k"], json!([1, {"x": "y"}]));
+}
+
+#[test]
+fn should_reject_values_nested_too_deeply() {
+    let arrays = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+    assert!(from_str::<JsonValue>(&arrays(MAX_DEPTH)).is_ok());
+
+    let err = from_str::<JsonValue>(&arrays(MAX_DEPTH + 1)).unwrap_err();
+    assert_eq!(err.message, "Nesting is deeper than 512 levels");
+    assert_eq!(err.index, MAX_DEPTH);
+    let err = from_str::<JsonValue>(&"[".repeat(200_000)).unwrap_err();
+    assert_eq!(err.message, "Nesting is deeper than 512 levels");
+    let err = from_str::<JsonValue>(&"{\"a\":".repeat(200_000)).unwrap_err();
+    assert_eq!(err.message, "Nesting is deeper than 512 levels");
+
+    let trees = |depth: usize| "{\"Node\":".repeat(depth) + "\"Leaf\"" + &"}".repeat(depth);
+    assert!(from_str::<Tree>(&trees(10)).is_ok());
+    let err = from_str::<Tree>(&trees(200_000)).unwrap_err();
+    assert_eq!(err.message, "Nesting is deeper than 512 levels");
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use synson::{parse_json, serialize_json, serialize_json_pretty, JsonValue};
+
+#[test]
+fn should_serialize_scalars() {
+    assert_eq!(serialize_json(&JsonValue::Null), "null");
+    assert_eq!(serialize_json(&JsonValue::Bool(true)), "true");
+    assert_eq!(serialize_json(&JsonValue::Number(42.0)), "42");
+    assert_eq!(serialize_json(&JsonValue::Number(-0.25)), "-0.25");
+    assert_eq!(serialize_json(&JsonValue::Number(f64::INFINITY)), "null");
+    assert_eq!(
+        serialize_json(&JsonValue::String("ok".to_string())),
+        "\"ok\""
+    );
+}
+
+#[test]
+fn should_escape_strings() {
+    let value = JsonValue::String("q\" b\\ \n\t\u{0001} é".to_string());
+    assert_eq!(serialize_json(&value), "\"q\\\" b\\\\ \\n\\t\\u0001 é\"");
+}
+
+#[test]
+fn should_serialize_nested_structures_with_sorted_keys() {
+    let mut inner = HashMap::new();
+    inner.insert("z".to_string(), JsonValue::Array(vec![]));
+    inner.insert("a".to_string(), JsonValue::Object(HashMap::new()));
+
+    let mut map = HashMap::new();
+    map.insert("b".to_string(), JsonValue::Object(inner));
+    map.insert("a".to_string(), JsonValue::Number(1.0));
+
+    let value = JsonValue::Object(map);
+    assert_eq!(
+        serialize_json(&value),
+        "{\"a\":1,\"b\":{\"a\":{},\"z\":[]}}"
+    );
+    assert_eq!(value.to_string(), serialize_json(&value));
+}
+
+#[test]
+fn should_round_trip_through_the_parser() {
+    let input = r#"{"user": {"id": 1e-7, "name": "café", "tags": ["a\"b", null, false]}}"#;
+    let value = parse_json(input, None).unwrap();
+    assert_eq!(parse_json(&serialize_json(&value), None), Ok(value));
+}
+
+#[test]
+fn should_serialize_pretty_json() {
+    let value = parse_json(r#"{"b": [1, [], {"c": "x"}], "a": {}}"#, None).unwrap();
+    let expected =
+        "{\n  \"a\": {},\n  \"b\": [\n    1,\n    [],\n    {\n      \"c\": \"x\"\n    }\n  ]\n}";
+
+    assert_eq!(serialize_json_pretty(&value, 2), expected);
+    assert_eq!(format!("{value:#}"), expected);
+    assert_eq!(
+        serialize_json_pretty(&value, 0).replace('\n', ""),
+        serialize_json(&value).replace(':', ": ")
+    );
+    assert_eq!(serialize_json_pretty(&JsonValue::Bool(true), 4), "true");
+    assert_eq!(
+        parse_json(&serialize_json_pretty(&value, 3), None),
+        Ok(value)
+    );
+}
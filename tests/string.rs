@@ -54,3 +54,42 @@ fn should_reject_invalid_strings() {
     assert!(parse_string("\"unclosed").is_err());
     assert!(parse_string("\"bad\\escape\"").is_err());
 }
+
+#[test]
+fn should_parse_unicode_escapes() {
+    assert_eq!(
+        parse_string("\"caf\\u00E9\""),
+        Ok((JsonValue::String("café".to_string()), ""))
+    );
+    assert_eq!(
+        parse_string("\"\\u0000\""),
+        Ok((JsonValue::String("\u{0}".to_string()), ""))
+    );
+    assert_eq!(
+        parse_string("\"\\ud83d\\ude00 smile\""),
+        Ok((JsonValue::String("😀 smile".to_string()), ""))
+    );
+}
+
+#[test]
+fn should_reject_invalid_unicode_escapes() {
+    assert!(parse_string("\"\\u12\"").is_err());
+    assert!(parse_string("\"\\u12G4\"").is_err());
+    assert!(parse_string("\"\\ud83d\"").is_err());
+    assert!(parse_string("\"\\ud83d\\u0041\"").is_err());
+    assert!(parse_string("\"\\ude00\"").is_err());
+    assert!(parse_string("\"\\u00é\"").is_err());
+}
+
+#[test]
+fn should_reject_unescaped_control_characters() {
+    let err = parse_string("\"tab\there\"").unwrap_err();
+    assert_eq!(err.message, "Unescaped control character in string");
+    assert_eq!(err.index, 4);
+    assert!(parse_string("\"line\nbreak\"").is_err());
+    assert!(parse_string("\"nul\u{0}\"").is_err());
+    assert_eq!(
+        parse_string("\"del\u{7f} is fine\""),
+        Ok((JsonValue::String("del\u{7f} is fine".to_string()), ""))
+    );
+}